@@ -99,7 +99,8 @@ ignore_dst=False)""",
     W.LocalDateTime.replace: """\
 ($self, /, *, year=None, month=None, day=None, hour=None, \
 minute=None, second=None, nanosecond=None)""",
-    W.Date.replace: "($self, /, *, year=None, month=None, day=None)",
+    W.Date.replace: "($self, /, *, year=None, month=None, day=None, \
+on_invalid='error')",
     W.MonthDay.replace: "($self, /, *, month=None, day=None)",
     W.Time.replace: "($self, /, *, hour=None, minute=None, second=None, nanosecond=None)",
     W.YearMonth.replace: "($self, /, *, year=None, month=None)",
@@ -107,6 +108,9 @@ minute=None, second=None, nanosecond=None)""",
 ($self, delta=None, /, *, hours=0, minutes=0, seconds=0, \
 milliseconds=0, microseconds=0, nanoseconds=0)""",
     W.Date.add: "($self, delta=None, /, *, years=0, months=0, weeks=0, days=0)",
+    W.Date.business_days_between: "($self, other, /, *, weekend=None)",
+    W.Date.py_date: "($self, cls=None, /)",
+    W.Date.start_of_week: "($self, start=None, /)",
 }
 MANUALLY_DEFINED_SIGS.update(
     {
@@ -7,7 +7,16 @@ from datetime import (
     time as _time,
     timedelta as _timedelta,
 )
-from typing import Any, ClassVar, Iterator, Literal, TypeVar, final, overload
+from typing import (
+    Any,
+    ClassVar,
+    Iterable,
+    Iterator,
+    Literal,
+    TypeVar,
+    final,
+    overload,
+)
 
 __all__ = [
     "Date",
@@ -31,6 +40,7 @@ __all__ = [
     "SkippedTime",
     "RepeatedTime",
     "InvalidOffset",
+    "UnsupportedYear",
     "MONDAY",
     "TUESDAY",
     "WEDNESDAY",
@@ -45,7 +55,10 @@ __version__: str
 
 @final
 class Date:
+    @overload
     def __init__(self, year: int, month: int, day: int) -> None: ...
+    @overload
+    def __init__(self, s: str, /) -> None: ...
     MIN: ClassVar[Date]
     MAX: ClassVar[Date]
     @staticmethod
@@ -56,19 +69,66 @@ class Date:
     def month(self) -> int: ...
     @property
     def day(self) -> int: ...
+    @property
+    def century(self) -> int: ...
+    def proleptic_month(self) -> int: ...
     def year_month(self) -> YearMonth: ...
     def month_day(self) -> MonthDay: ...
     def day_of_week(self) -> Weekday: ...
+    def weekday_iso(self) -> int: ...
+    def weekday_sunday_based(self) -> int: ...
+    def days_in_year(self) -> int: ...
+    def season(
+        self, *, hemisphere: Literal["north", "south"] = "north"
+    ) -> Literal["winter", "spring", "summer", "autumn"]: ...
+    def weeks_in_year(self) -> int: ...
+    @classmethod
+    def iso_weeks_in_year(cls, year: int, /) -> int: ...
+    def ordinal_in_century(self) -> int: ...
+    def replace_ordinal_day(self, n: int, /) -> Date: ...
+    def weekdays_in_month(self, weekday: Weekday, /) -> list[Date]: ...
+    def first_weekday_of_month(self, weekday: Weekday, /) -> Date: ...
+    def last_weekday_of_month(self, weekday: Weekday, /) -> Date: ...
+    def iter_weekdays(
+        self, weekday: Weekday, count: int, /, *, inclusive: bool = True
+    ) -> Iterator[Date]: ...
+    def range(
+        self, end: Date, /, *, step_days: int = 1, inclusive: bool = True
+    ) -> list[Date]: ...
+    def range_months(
+        self, end: Date, /, *, step_months: int = 1, inclusive: bool = True
+    ) -> Iterator[Date]: ...
+    def start_of_week(self, start: Weekday = ..., /) -> Date: ...
+    def on_or_after_weekday(self, weekday: Weekday, /) -> Date: ...
+    def on_or_before_weekday(self, weekday: Weekday, /) -> Date: ...
     def at(self, t: Time, /) -> LocalDateTime: ...
+    @overload
     def py_date(self) -> _date: ...
+    @overload
+    def py_date(self, cls: type[_D], /) -> _D: ...
+    @classmethod
+    def from_ordinal(cls, n: int, /) -> Date: ...
+    @classmethod
+    def from_proleptic_month(cls, n: int, day: int, /) -> Date: ...
     @classmethod
     def from_py_date(cls, d: _date, /) -> Date: ...
     def format_common_iso(self) -> str: ...
+    def __format__(self, spec: str) -> str: ...
     @classmethod
     def parse_common_iso(cls, s: str, /) -> Date: ...
+    @classmethod
+    def parse_iso(cls, s: str, /) -> Date: ...
     def replace(
-        self, *, year: int = ..., month: int = ..., day: int = ...
+        self,
+        *,
+        year: int = ...,
+        month: int = ...,
+        day: int = ...,
+        on_invalid: Literal["error", "clamp"] = ...,
     ) -> Date: ...
+    def with_year(self, year: int, /) -> Date: ...
+    def with_month(self, month: int, /) -> Date: ...
+    def with_day(self, day: int, /) -> Date: ...
     @overload
     def add(
         self, *, years: int = 0, months: int = 0, weeks: int = 0, days: int = 0
@@ -81,11 +141,22 @@ class Date:
     ) -> Date: ...
     @overload
     def subtract(self, delta: DateDelta, /) -> Date: ...
+    def add_weeks(self, n: int, /) -> Date: ...
+    def next_day(self) -> Date: ...
+    def previous_day(self) -> Date: ...
     def days_since(self, other: Date, /) -> int: ...
     def days_until(self, other: Date, /) -> int: ...
-    def __add__(self, p: DateDelta) -> Date: ...
+    def leap_years_until(self, other: Date, /) -> int: ...
+    def business_days_between(
+        self,
+        other: Date,
+        /,
+        *,
+        weekend: Iterable[Weekday] = ...,
+    ) -> int: ...
+    def __add__(self, p: DateDelta | int) -> Date: ...
     @overload
-    def __sub__(self, d: DateDelta) -> Date: ...
+    def __sub__(self, d: DateDelta | int) -> Date: ...
     @overload
     def __sub__(self, d: Date) -> DateDelta: ...
     def __lt__(self, other: Date) -> bool: ...
@@ -93,6 +164,8 @@ class Date:
     def __gt__(self, other: Date) -> bool: ...
     def __ge__(self, other: Date) -> bool: ...
     def __hash__(self) -> int: ...
+    def __int__(self) -> int: ...
+    def __index__(self) -> int: ...
 
 @final
 class YearMonth:
@@ -158,8 +231,13 @@ class Time:
     def nanosecond(self) -> int: ...
     def on(self, d: Date, /) -> LocalDateTime: ...
     def py_time(self) -> _time: ...
+    def total_seconds_float(self) -> float: ...
     @classmethod
     def from_py_time(cls, t: _time, /) -> Time: ...
+    @classmethod
+    def now_in_tz(cls, tz: str, /) -> Time: ...
+    @classmethod
+    def now_in_system_tz(cls) -> Time: ...
     def format_common_iso(self) -> str: ...
     @classmethod
     def parse_common_iso(cls, s: str, /) -> Time: ...
@@ -186,6 +264,25 @@ class Time:
             "ceil", "floor", "half_ceil", "half_floor", "half_even"
         ] = "half_even",
     ) -> Time: ...
+    def round_with_carry(
+        self,
+        unit: Literal[
+            "hour",
+            "minute",
+            "second",
+            "millisecond",
+            "microsecond",
+            "nanosecond",
+        ] = "second",
+        increment: int = 1,
+        mode: Literal[
+            "ceil", "floor", "half_ceil", "half_floor", "half_even"
+        ] = "half_even",
+    ) -> tuple[Time, int]: ...
+    def add(self, delta: TimeDelta, /) -> tuple[Time, int]: ...
+    def subtract(self, delta: TimeDelta, /) -> tuple[Time, int]: ...
+    def __add__(self, delta: TimeDelta) -> Time: ...
+    def __sub__(self, delta: TimeDelta) -> Time: ...
     def __lt__(self, other: Time) -> bool: ...
     def __le__(self, other: Time) -> bool: ...
     def __gt__(self, other: Time) -> bool: ...
@@ -208,6 +305,7 @@ class TimeDelta:
     MAX: ClassVar[TimeDelta]
     MIN: ClassVar[TimeDelta]
     def in_days_of_24h(self) -> float: ...
+    def whole_days_of_24h(self) -> int: ...
     def in_hours(self) -> float: ...
     def in_minutes(self) -> float: ...
     def in_seconds(self) -> float: ...
@@ -215,9 +313,23 @@ class TimeDelta:
     def in_microseconds(self) -> float: ...
     def in_nanoseconds(self) -> int: ...
     def in_hrs_mins_secs_nanos(self) -> tuple[int, int, int, int]: ...
+    def as_tuple(self) -> tuple[int, int, int, int, int]: ...
     def py_timedelta(self) -> _timedelta: ...
     @classmethod
     def from_py_timedelta(cls, td: _timedelta, /) -> TimeDelta: ...
+    @classmethod
+    def from_units(
+        cls,
+        *,
+        weeks: float = 0,
+        days: float = 0,
+        hours: float = 0,
+        minutes: float = 0,
+        seconds: float = 0,
+        milliseconds: float = 0,
+        microseconds: float = 0,
+        nanoseconds: float = 0,
+    ) -> TimeDelta: ...
     def format_common_iso(self) -> str: ...
     @classmethod
     def parse_common_iso(cls, s: str, /) -> TimeDelta: ...
@@ -236,6 +348,8 @@ class TimeDelta:
             "ceil", "floor", "half_ceil", "half_floor", "half_even"
         ] = "half_even",
     ) -> TimeDelta: ...
+    def isclose(self, other: TimeDelta, /, *, abs_tol: TimeDelta) -> bool: ...
+    def clamp(self, lower: TimeDelta, upper: TimeDelta, /) -> TimeDelta: ...
     def __hash__(self) -> int: ...
     def __lt__(self, other: TimeDelta) -> bool: ...
     def __le__(self, other: TimeDelta) -> bool: ...
@@ -254,6 +368,7 @@ class TimeDelta:
     def __truediv__(self, other: TimeDelta) -> float: ...
     def __floordiv__(self, other: TimeDelta) -> int: ...
     def __mod__(self, other: TimeDelta) -> TimeDelta: ...
+    def __divmod__(self, other: TimeDelta) -> tuple[int, TimeDelta]: ...
     def __abs__(self) -> TimeDelta: ...
 
 @final
@@ -284,6 +399,10 @@ class DateDelta:
         days: int = ...,
     ) -> DateDelta: ...
     def __hash__(self) -> int: ...
+    def __lt__(self, other: DateDelta) -> bool: ...
+    def __le__(self, other: DateDelta) -> bool: ...
+    def __gt__(self, other: DateDelta) -> bool: ...
+    def __ge__(self, other: DateDelta) -> bool: ...
     def __bool__(self) -> bool: ...
     def __neg__(self) -> DateDelta: ...
     def __pos__(self) -> DateDelta: ...
@@ -342,6 +461,7 @@ class DateTimeDelta:
 Delta = DateTimeDelta | TimeDelta | DateDelta
 
 _T = TypeVar("_T")
+_D = TypeVar("_D", bound=_date)
 
 class _KnowsLocal(ABC):
     @property
@@ -381,6 +501,7 @@ class _KnowsInstant(ABC):
 
 class _KnowsInstantAndLocal(_KnowsInstant, _KnowsLocal, ABC):
     def instant(self) -> Instant: ...
+    def to_instant(self) -> Instant: ...
     def local(self) -> LocalDateTime: ...
     @property
     def offset(self) -> TimeDelta: ...
@@ -415,7 +536,9 @@ class Instant(_KnowsInstant):
     def format_rfc2822(self) -> str: ...
     @classmethod
     def parse_rfc2822(cls, s: str, /) -> Instant: ...
-    def format_rfc3339(self) -> str: ...
+    def format_rfc3339(
+        self, *, fractional_digits: int | None = None
+    ) -> str: ...
     @classmethod
     def parse_rfc3339(cls, s: str, /) -> Instant: ...
     @classmethod
@@ -457,6 +580,37 @@ class Instant(_KnowsInstant):
             "ceil", "floor", "half_ceil", "half_floor", "half_even"
         ] = "half_even",
     ) -> Instant: ...
+    def difference(
+        self,
+        other: Instant | OffsetDateTime | ZonedDateTime | SystemDateTime,
+        /,
+        *,
+        unit: Literal[
+            "hour",
+            "minute",
+            "second",
+            "millisecond",
+            "microsecond",
+            "nanosecond",
+        ] = "nanosecond",
+        increment: int = 1,
+        mode: Literal[
+            "ceil", "floor", "half_ceil", "half_floor", "half_even"
+        ] = "half_even",
+    ) -> TimeDelta: ...
+    def elapsed_since(
+        self,
+        other: Instant | OffsetDateTime | ZonedDateTime | SystemDateTime,
+        /,
+    ) -> TimeDelta: ...
+    def is_between(
+        self,
+        start: Instant | OffsetDateTime | ZonedDateTime | SystemDateTime,
+        end: Instant | OffsetDateTime | ZonedDateTime | SystemDateTime,
+        /,
+        *,
+        inclusive: Literal["[]", "[)", "(]", "()"] = "[)",
+    ) -> bool: ...
     def __add__(self, delta: TimeDelta) -> Instant: ...
     @overload
     def __sub__(self, other: _KnowsInstant) -> TimeDelta: ...
@@ -532,6 +686,7 @@ class OffsetDateTime(_KnowsInstantAndLocal):
     def replace_time(
         self, t: Time, /, *, ignore_dst: Literal[True]
     ) -> OffsetDateTime: ...
+    def round_offset_to_minutes(self) -> OffsetDateTime: ...
     @overload
     def add(
         self,
@@ -729,9 +884,14 @@ class ZonedDateTime(_KnowsInstantAndLocal):
         *,
         disambiguate: Literal["compatible", "raise", "earlier", "later"] = ...,
     ) -> ZonedDateTime: ...
+    def in_utc(self) -> OffsetDateTime: ...
     def is_ambiguous(self) -> bool: ...
     def hours_in_day(self) -> float: ...
-    def start_of_day(self) -> ZonedDateTime: ...
+    def start_of_day(
+        self,
+        *,
+        disambiguate: Literal["compatible", "raise", "earlier", "later"] = ...,
+    ) -> ZonedDateTime: ...
     def round(
         self,
         unit: Literal[
@@ -747,6 +907,10 @@ class ZonedDateTime(_KnowsInstantAndLocal):
         mode: Literal[
             "ceil", "floor", "half_ceil", "half_floor", "half_even"
         ] = "half_even",
+        *,
+        disambiguate: (
+            Literal["compatible", "raise", "earlier", "later"] | None
+        ) = None,
     ) -> ZonedDateTime: ...
     # FUTURE: disable date components in strict stubs version
     def __add__(self, delta: Delta) -> ZonedDateTime: ...
@@ -931,6 +1095,7 @@ class LocalDateTime(_KnowsLocal):
     def assume_fixed_offset(
         self, offset: int | TimeDelta, /
     ) -> OffsetDateTime: ...
+    def to_instant(self, offset: int | TimeDelta, /) -> Instant: ...
     def assume_tz(
         self,
         tz: str,
@@ -943,14 +1108,27 @@ class LocalDateTime(_KnowsLocal):
         *,
         disambiguate: Literal["compatible", "raise", "earlier", "later"] = ...,
     ) -> SystemDateTime: ...
+    def to_system_offset(
+        self,
+        *,
+        disambiguate: Literal["compatible", "raise", "earlier", "later"] = ...,
+    ) -> OffsetDateTime: ...
+    def is_valid_in_tz(self, tz: str, /) -> bool: ...
     @classmethod
     def from_py_datetime(cls, d: _datetime, /) -> LocalDateTime: ...
+    @classmethod
+    def combine(cls, date: Date, time: Time, /) -> LocalDateTime: ...
+    @classmethod
+    def from_timestamp(
+        cls, i: int | float, /, *, tz: str, nanosecond: int = 0
+    ) -> LocalDateTime: ...
     def py_datetime(self) -> _datetime: ...
     @classmethod
     def parse_common_iso(cls, s: str, /) -> LocalDateTime: ...
     def format_common_iso(self) -> str: ...
     @classmethod
     def strptime(cls, s: str, fmt: str, /) -> LocalDateTime: ...
+    def __format__(self, spec: str) -> str: ...
     def replace(
         self,
         *,
@@ -962,6 +1140,22 @@ class LocalDateTime(_KnowsLocal):
         second: int = ...,
         nanosecond: int = ...,
     ) -> LocalDateTime: ...
+    def as_tuple(self) -> tuple[int, int, int, int, int, int, int]: ...
+    def iso_year_week_day(
+        self,
+    ) -> tuple[int, int, int, int, int, int, int]: ...
+    @classmethod
+    def from_iso_week_datetime(
+        cls,
+        year: int,
+        week: int,
+        weekday: int,
+        hour: int = 0,
+        minute: int = 0,
+        second: int = 0,
+        *,
+        nanosecond: int = 0,
+    ) -> LocalDateTime: ...
     def replace_date(self, d: Date, /) -> LocalDateTime: ...
     def replace_time(self, t: Time, /) -> LocalDateTime: ...
     @overload
@@ -1016,6 +1210,8 @@ class LocalDateTime(_KnowsLocal):
     def subtract(
         self, d: TimeDelta | DateTimeDelta, /, *, ignore_dst: Literal[True]
     ) -> LocalDateTime: ...
+    def add_days(self, n: int, /) -> LocalDateTime: ...
+    def add_months(self, n: int, /) -> LocalDateTime: ...
     def difference(
         self, other: LocalDateTime, /, *, ignore_dst: Literal[True]
     ) -> TimeDelta: ...
@@ -1043,14 +1239,23 @@ class LocalDateTime(_KnowsLocal):
     def __ge__(self, other: LocalDateTime) -> bool: ...
 
 @final
-class RepeatedTime(Exception): ...
+class RepeatedTime(Exception):
+    date: Date
+    time: Time
+    tz: str | None
 
 @final
-class SkippedTime(Exception): ...
+class SkippedTime(Exception):
+    date: Date
+    time: Time
+    tz: str | None
 
 @final
 class InvalidOffset(ValueError): ...
 
+@final
+class UnsupportedYear(ValueError): ...
+
 @final
 class ImplicitlyIgnoringDST(TypeError): ...
 
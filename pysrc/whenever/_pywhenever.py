@@ -38,7 +38,7 @@ import enum
 import re
 import sys
 from abc import ABC, abstractmethod
-from calendar import monthrange
+from calendar import isleap, monthrange
 from datetime import (
     date as _date,
     datetime as _datetime,
@@ -47,7 +47,8 @@ from datetime import (
     timezone as _timezone,
 )
 from email.utils import format_datetime, parsedate_to_datetime
-from math import fmod
+from fractions import Fraction
+from math import fmod, isfinite
 from struct import pack, unpack
 from time import time_ns
 from typing import (
@@ -55,6 +56,8 @@ from typing import (
     Any,
     Callable,
     ClassVar,
+    Iterable,
+    Iterator,
     Literal,
     Mapping,
     TypeVar,
@@ -93,6 +96,7 @@ __all__ = [
     "SkippedTime",
     "RepeatedTime",
     "InvalidOffset",
+    "UnsupportedYear",
     "ImplicitlyIgnoringDST",
     # Constants
     "MONDAY",
@@ -133,7 +137,52 @@ _MAX_DELTA_MONTHS = 9999 * 12
 _MAX_DELTA_DAYS = 9999 * 366
 _MAX_DELTA_NANOS = _MAX_DELTA_DAYS * 24 * 3_600_000_000_000
 _UNSET = object()
+_MAX_DATE_ORD = _date.max.toordinal()
 _PY312 = sys.version_info >= (3, 12)
+# Meteorological seasons (northern hemisphere), starting with December
+_SEASONS: tuple[Literal["winter", "spring", "summer", "autumn"], ...] = (
+    "winter",
+    "spring",
+    "summer",
+    "autumn",
+)
+_WEEKDAY_NAMES = (
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+    "Sunday",
+)
+_MONTH_NAMES = (
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+)
+# Pickle data is prefixed with this byte, so that the format
+# can evolve without breaking existing pickles.
+_PICKLE_VERSION = 1
+
+
+def _strip_pickle_version(data: bytes, size: int) -> bytes:
+    # Data without a version byte (from older releases) is accepted as-is.
+    if len(data) == size:
+        return data
+    elif len(data) == size + 1:
+        if data[0] == _PICKLE_VERSION:
+            return data[1:]
+        raise ValueError(f"Unsupported pickle version: {data[0]}")
+    raise ValueError("Invalid pickle data")
 
 
 class _ImmutableBase:
@@ -170,6 +219,11 @@ class Date(_ImmutableBase):
     -------
     >>> d = Date(2021, 1, 2)
     Date(2021-01-02)
+
+    A single string argument is parsed like :meth:`parse_common_iso`:
+
+    >>> Date("2021-01-02")
+    Date(2021-01-02)
     """
 
     __slots__ = ("_py_date",)
@@ -179,8 +233,26 @@ class Date(_ImmutableBase):
     MAX: ClassVar[Date]
     """The maximum possible date"""
 
-    def __init__(self, year: int, month: int, day: int) -> None:
-        self._py_date = _date(year, month, day)
+    @overload
+    def __init__(self, year: int, month: int, day: int) -> None: ...
+
+    @overload
+    def __init__(self, s: str, /) -> None: ...
+
+    def __init__(
+        self, year: Any, month: Any = _UNSET, day: Any = _UNSET
+    ) -> None:
+        # A single string argument is parsed as an ISO date
+        if isinstance(year, str) and month is _UNSET and day is _UNSET:
+            self._py_date = Date.parse_common_iso(year)._py_date
+        elif month is _UNSET or day is _UNSET:
+            raise TypeError(
+                "function missing required argument "
+                f"{'month' if month is _UNSET else 'day'!r}"
+            )
+        else:
+            _check_common_era(year)
+            self._py_date = _date(year, month, day)
 
     @classmethod
     def today_in_system_tz(cls) -> Date:
@@ -208,6 +280,26 @@ class Date(_ImmutableBase):
     def day(self) -> int:
         return self._py_date.day
 
+    @property
+    def century(self) -> int:
+        """The century, counting from 1. The 20th century is 1901-2000"""
+        return (self._py_date.year - 1) // 100 + 1
+
+    def proleptic_month(self) -> int:
+        """The number of months since the start of year 0,
+        i.e. ``year * 12 + month - 1``.
+
+        Useful for grouping by month, or stepping through months
+        with plain integer arithmetic.
+        Inverse of :meth:`from_proleptic_month`.
+
+        Example
+        -------
+        >>> Date(2024, 3, 15).proleptic_month()
+        24290
+        """
+        return self._py_date.year * 12 + self._py_date.month - 1
+
     def year_month(self) -> YearMonth:
         """The year and month (without a day component)
 
@@ -242,6 +334,375 @@ class Date(_ImmutableBase):
         """
         return Weekday(self._py_date.isoweekday())
 
+    def weekday_iso(self) -> int:
+        """The ISO day of the week as an integer, from 1 (Monday)
+        to 7 (Sunday)
+
+        Example
+        -------
+        >>> Date(2021, 1, 2).weekday_iso()
+        6
+        >>> Date(2021, 1, 3).weekday_iso()
+        7
+        """
+        return self._py_date.isoweekday()
+
+    def weekday_sunday_based(self) -> int:
+        """The day of the week as an integer, from 0 (Sunday)
+        to 6 (Saturday).
+
+        This is the convention of C's ``tm_wday`` and JavaScript's
+        ``Date.getDay()``.
+
+        Example
+        -------
+        >>> Date(2021, 1, 2).weekday_sunday_based()
+        6
+        >>> Date(2021, 1, 3).weekday_sunday_based()
+        0
+        """
+        return self._py_date.toordinal() % 7
+
+    def days_in_year(self) -> int:
+        """The number of days in this date's year: 365, or 366 in leap years
+
+        Example
+        -------
+        >>> Date(2024, 3, 15).days_in_year()
+        366
+        >>> Date(2023, 3, 15).days_in_year()
+        365
+        """
+        return 365 + isleap(self._py_date.year)
+
+    def season(
+        self, *, hemisphere: Literal["north", "south"] = "north"
+    ) -> Literal["winter", "spring", "summer", "autumn"]:
+        """The meteorological season of this date.
+
+        Seasons start on the first of December, March, June,
+        and September. In the southern hemisphere, they're reversed.
+
+        Example
+        -------
+        >>> Date(2024, 7, 15).season()
+        'summer'
+        >>> Date(2024, 7, 15).season(hemisphere="south")
+        'winter'
+        """
+        if hemisphere == "north":
+            offset = 0
+        elif hemisphere == "south":
+            offset = 2
+        else:
+            raise ValueError("hemisphere must be 'north' or 'south'")
+        return _SEASONS[((self._py_date.month % 12) // 3 + offset) % 4]
+
+    def weeks_in_year(self) -> int:
+        """The number of ISO 8601 weeks in this date's year: 52 or 53
+
+        Equivalent to ``Date.iso_weeks_in_year(d.year)``.
+        Note that this uses the calendar year, which may differ from
+        the ISO week-numbering year for dates in late December
+        or early January.
+
+        Example
+        -------
+        >>> Date(2015, 6, 1).weeks_in_year()
+        53
+        >>> Date(2016, 6, 1).weeks_in_year()
+        52
+        """
+        return _iso_weeks_in(self._py_date.year)
+
+    @classmethod
+    def iso_weeks_in_year(cls, year: int, /) -> int:
+        """The number of ISO 8601 weeks in the given year: 52 or 53
+
+        Useful for validating week numbers, since only some years
+        have a week 53.
+
+        Example
+        -------
+        >>> Date.iso_weeks_in_year(2020)
+        53
+        >>> Date.iso_weeks_in_year(2021)
+        52
+        """
+        if not isinstance(year, int):
+            raise TypeError("year must be an integer")
+        _check_common_era(year)
+        if year > _date.max.year:
+            raise ValueError("year out of range")
+        return _iso_weeks_in(year)
+
+    def ordinal_in_century(self) -> int:
+        """The day of the century, starting at 1 on January 1st
+        of the century's first year (e.g. 2001 for the 21st century)
+
+        Example
+        -------
+        >>> Date(2001, 1, 1).ordinal_in_century()
+        1
+        >>> Date(2000, 12, 31).ordinal_in_century()
+        36525
+        """
+        start = _date((self._py_date.year - 1) // 100 * 100 + 1, 1, 1)
+        return (self._py_date - start).days + 1
+
+    def replace_ordinal_day(self, n: int, /) -> Date:
+        """The ``n``-th day of this date's year,
+        where January 1st is day 1.
+
+        Raises ``ValueError`` if the year doesn't have that many days.
+
+        Example
+        -------
+        >>> Date(2024, 6, 1).replace_ordinal_day(60)
+        Date(2024-02-29)
+        >>> Date(2023, 6, 1).replace_ordinal_day(60)
+        Date(2023-03-01)
+        """
+        if not isinstance(n, int):
+            raise TypeError("day of year must be an integer")
+        year = self._py_date.year
+        days_in_year = 365 + isleap(year)
+        if not 1 <= n <= days_in_year:
+            raise ValueError(
+                f"day of year must be in 1..{days_in_year} for year {year}"
+            )
+        return Date._from_py_unchecked(
+            _date.fromordinal(_date(year, 1, 1).toordinal() + n - 1)
+        )
+
+    def weekdays_in_month(self, weekday: Weekday, /) -> list[Date]:
+        """All dates in this date's month that fall on the given weekday
+
+        Example
+        -------
+        >>> Date(2024, 3, 15).weekdays_in_month(Weekday.MONDAY)
+        [Date(2024-03-04), Date(2024-03-11), Date(2024-03-18), Date(2024-03-25)]
+        """
+        if not isinstance(weekday, Weekday):
+            raise TypeError("weekday must be a whenever.Weekday")
+        first = self._py_date.replace(day=1)
+        first_day = 1 + (weekday.value - first.isoweekday()) % 7
+        return [
+            Date._from_py_unchecked(first.replace(day=day))
+            for day in range(
+                first_day, monthrange(first.year, first.month)[1] + 1, 7
+            )
+        ]
+
+    def first_weekday_of_month(self, weekday: Weekday, /) -> Date:
+        """The first date in this date's month that falls on the given weekday
+
+        Example
+        -------
+        >>> Date(2024, 3, 15).first_weekday_of_month(Weekday.MONDAY)
+        Date(2024-03-04)
+        """
+        if not isinstance(weekday, Weekday):
+            raise TypeError("weekday must be a whenever.Weekday")
+        first = self._py_date.replace(day=1)
+        return Date._from_py_unchecked(
+            first.replace(day=1 + (weekday.value - first.isoweekday()) % 7)
+        )
+
+    def last_weekday_of_month(self, weekday: Weekday, /) -> Date:
+        """The last date in this date's month that falls on the given weekday
+
+        Example
+        -------
+        >>> Date(2024, 3, 15).last_weekday_of_month(Weekday.FRIDAY)
+        Date(2024-03-29)
+        """
+        if not isinstance(weekday, Weekday):
+            raise TypeError("weekday must be a whenever.Weekday")
+        year, month = self._py_date.year, self._py_date.month
+        last = self._py_date.replace(day=monthrange(year, month)[1])
+        return Date._from_py_unchecked(
+            last.replace(
+                day=last.day - (last.isoweekday() - weekday.value) % 7
+            )
+        )
+
+    def iter_weekdays(
+        self, weekday: Weekday, count: int, /, *, inclusive: bool = True
+    ) -> Iterator[Date]:
+        """Iterate over the next ``count`` dates falling on the given weekday,
+        starting from this date.
+
+        If this date itself falls on the weekday, it's included
+        unless ``inclusive=False`` is passed.
+
+        Example
+        -------
+        >>> d = Date(2024, 3, 15)  # a Friday
+        >>> list(d.iter_weekdays(Weekday.FRIDAY, 3))
+        [Date(2024-03-15), Date(2024-03-22), Date(2024-03-29)]
+        >>> list(d.iter_weekdays(Weekday.FRIDAY, 2, inclusive=False))
+        [Date(2024-03-22), Date(2024-03-29)]
+        """
+        if not isinstance(weekday, Weekday):
+            raise TypeError("weekday must be a whenever.Weekday")
+        if not isinstance(count, int):
+            raise TypeError("count must be an integer")
+        if count < 0:
+            raise ValueError("count must not be negative")
+        days_ahead = (weekday.value - self._py_date.isoweekday()) % 7
+        if days_ahead == 0 and not inclusive:
+            days_ahead = 7
+        first_ord = self._py_date.toordinal() + days_ahead
+        if count and first_ord + (count - 1) * 7 > _MAX_DATE_ORD:
+            raise ValueError("Resulting date out of range")
+        return (
+            Date._from_py_unchecked(_date.fromordinal(first_ord + i * 7))
+            for i in range(count)
+        )
+
+    def range(
+        self, end: Date, /, *, step_days: int = 1, inclusive: bool = True
+    ) -> list[Date]:
+        """Create a list of dates from this date up to the given end date,
+        in steps of ``step_days``.
+
+        The end date is included (if reached) unless ``inclusive=False``
+        is passed. To go back in time, pass an earlier end date
+        and a negative step.
+
+        Example
+        -------
+        >>> d = Date(2024, 3, 1)
+        >>> d.range(Date(2024, 3, 3))
+        [Date(2024-03-01), Date(2024-03-02), Date(2024-03-03)]
+        >>> d.range(Date(2024, 3, 8), step_days=3)
+        [Date(2024-03-01), Date(2024-03-04), Date(2024-03-07)]
+        >>> d.range(Date(2024, 2, 28), step_days=-1, inclusive=False)
+        [Date(2024-03-01), Date(2024-02-29)]
+        """
+        if type(end) is not Date:
+            raise TypeError("end must be a whenever.Date")
+        if not isinstance(step_days, int):
+            raise TypeError("step_days must be an integer")
+        start = self._py_date.toordinal()
+        stop = end._py_date.toordinal()
+        if step_days == 0:
+            raise ValueError("step_days must not be zero")
+        elif stop > start and step_days < 0:
+            raise ValueError(
+                "step_days must be positive if end is after this date"
+            )
+        elif stop < start and step_days > 0:
+            raise ValueError(
+                "step_days must be negative if end is before this date"
+            )
+        if inclusive:
+            stop += 1 if step_days > 0 else -1
+        return [
+            Date._from_py_unchecked(_date.fromordinal(o))
+            for o in range(start, stop, step_days)
+        ]
+
+    def range_months(
+        self, end: Date, /, *, step_months: int = 1, inclusive: bool = True
+    ) -> Iterator[Date]:
+        """Iterate over dates from this date up to the given end date,
+        in steps of ``step_months`` calendar months.
+
+        As with :meth:`range`, the end date is included (if reached)
+        unless ``inclusive=False`` is passed, and a negative step
+        goes back in time.
+
+        Each date keeps this date's day, clamped to the end of the month
+        if needed. Dates are calculated from this date, so clamping
+        in a short month doesn't carry over to the following months.
+
+        Example
+        -------
+        >>> list(Date(2024, 1, 1).range_months(Date(2024, 3, 1)))
+        [Date(2024-01-01), Date(2024-02-01), Date(2024-03-01)]
+        >>> list(Date(2024, 1, 31).range_months(Date(2024, 3, 31)))
+        [Date(2024-01-31), Date(2024-02-29), Date(2024-03-31)]
+        >>> d = Date(2024, 1, 15)
+        >>> list(d.range_months(Date(2025, 1, 15), step_months=6))
+        [Date(2024-01-15), Date(2024-07-15), Date(2025-01-15)]
+        """
+        if type(end) is not Date:
+            raise TypeError("end must be a whenever.Date")
+        if not isinstance(step_months, int):
+            raise TypeError("step_months must be an integer")
+        if step_months == 0:
+            raise ValueError("step_months must not be zero")
+        elif end > self and step_months < 0:
+            raise ValueError(
+                "step_months must be positive if end is after this date"
+            )
+        elif end < self and step_months > 0:
+            raise ValueError(
+                "step_months must be negative if end is before this date"
+            )
+        return _iter_months(self, end, step_months, inclusive)
+
+    def start_of_week(self, start: Weekday = Weekday.MONDAY, /) -> Date:
+        """The date on which this date's week starts.
+        Weeks start on Monday by default.
+
+        Example
+        -------
+        >>> Date(2024, 3, 1).start_of_week()
+        Date(2024-02-26)
+        >>> Date(2024, 3, 1).start_of_week(Weekday.SUNDAY)
+        Date(2024-02-25)
+        """
+        if not isinstance(start, Weekday):
+            raise TypeError("weekday must be a whenever.Weekday")
+        days_back = (self._py_date.isoweekday() - start.value) % 7
+        try:
+            return self._add_days(-days_back)
+        except OverflowError:
+            raise ValueError("Resulting date out of range")
+
+    def on_or_after_weekday(self, weekday: Weekday, /) -> Date:
+        """The first date falling on the given weekday,
+        starting from this date.
+
+        Example
+        -------
+        >>> d = Date(2024, 3, 15)  # a Friday
+        >>> d.on_or_after_weekday(Weekday.MONDAY)
+        Date(2024-03-18)
+        >>> d.on_or_after_weekday(Weekday.FRIDAY)
+        Date(2024-03-15)
+        """
+        if not isinstance(weekday, Weekday):
+            raise TypeError("weekday must be a whenever.Weekday")
+        days_ahead = (weekday.value - self._py_date.isoweekday()) % 7
+        try:
+            return self._add_days(days_ahead)
+        except OverflowError:
+            raise ValueError("Resulting date out of range")
+
+    def on_or_before_weekday(self, weekday: Weekday, /) -> Date:
+        """The last date falling on the given weekday,
+        going back from this date.
+
+        Example
+        -------
+        >>> d = Date(2024, 3, 15)  # a Friday
+        >>> d.on_or_before_weekday(Weekday.MONDAY)
+        Date(2024-03-11)
+        >>> d.on_or_before_weekday(Weekday.FRIDAY)
+        Date(2024-03-15)
+        """
+        if not isinstance(weekday, Weekday):
+            raise TypeError("weekday must be a whenever.Weekday")
+        days_back = (self._py_date.isoweekday() - weekday.value) % 7
+        try:
+            return self._add_days(-days_back)
+        except OverflowError:
+            raise ValueError("Resulting date out of range")
+
     def at(self, t: Time, /) -> LocalDateTime:
         """Combine a date with a time to create a datetime
 
@@ -258,18 +719,77 @@ class Date(_ImmutableBase):
             _datetime.combine(self._py_date, t._py_time), t._nanos
         )
 
-    def py_date(self) -> _date:
-        """Convert to a standard library :class:`~datetime.date`"""
-        return self._py_date
+    def py_date(self, cls: type[_date] = _date, /) -> _date:
+        """Convert to a standard library :class:`~datetime.date`
+
+        Pass a subclass of :class:`~datetime.date` to create an instance
+        of it instead. It's called with the year, month, and day.
+
+        Example
+        -------
+        >>> class MyDate(date): ...
+        >>> Date(2024, 3, 15).py_date(MyDate)
+        MyDate(2024, 3, 15)
+        """
+        if cls is _date:
+            return self._py_date
+        if not (isinstance(cls, type) and issubclass(cls, _date)):
+            raise TypeError("cls must be a subclass of datetime.date")
+        d = self._py_date
+        return cls(d.year, d.month, d.day)
+
+    @classmethod
+    def from_ordinal(cls, n: int, /) -> Date:
+        """Create from a proleptic Gregorian ordinal,
+        where January 1 of year 1 has ordinal 1.
+
+        The inverse of ``int()``.
+
+        Example
+        -------
+        >>> Date.from_ordinal(738_000)
+        Date(2021-07-29)
+        """
+        return cls._from_py_unchecked(_date.fromordinal(n))
+
+    @classmethod
+    def from_proleptic_month(cls, n: int, day: int, /) -> Date:
+        """Create from a month index as returned by :meth:`proleptic_month`,
+        and a day of that month.
+
+        Raises ``ValueError`` if the day doesn't exist in the month.
+
+        Example
+        -------
+        >>> Date.from_proleptic_month(24290, 15)
+        Date(2024-03-15)
+        >>> Date.from_proleptic_month(24290 + 1, 15)
+        Date(2024-04-15)
+        """
+        if not isinstance(n, int):
+            raise TypeError("proleptic month must be an integer")
+        if not isinstance(day, int):
+            raise TypeError("day must be an integer")
+        if not 12 <= n <= _date.max.year * 12 + 11:
+            raise ValueError("proleptic month out of range")
+        year, month = divmod(n, 12)
+        if not 1 <= day <= monthrange(year, month + 1)[1]:
+            raise ValueError("day is out of range for month")
+        return cls._from_py_unchecked(_date(year, month + 1, day))
 
     @classmethod
     def from_py_date(cls, d: _date, /) -> Date:
         """Create from a :class:`~datetime.date`
 
+        A :class:`~datetime.datetime` is also accepted,
+        in which case its time (and timezone) is ignored.
+
         Example
         -------
         >>> Date.from_py_date(date(2021, 1, 2))
         Date(2021-01-02)
+        >>> Date.from_py_date(datetime(2021, 1, 2, 23, 59))
+        Date(2021-01-02)
         """
         self = _object_new(cls)
         if type(d) is _date:
@@ -316,17 +836,128 @@ class Date(_ImmutableBase):
         except ValueError:
             raise ValueError(f"Invalid format: {s!r}")
 
+    @classmethod
+    def parse_iso(cls, s: str, /) -> Date:
+        """Create from any of the ISO 8601 date formats:
+        calendar date (``YYYY-MM-DD``), week date (``YYYY-Www-D``),
+        or ordinal date (``YYYY-DDD``).
+
+        Use :meth:`parse_common_iso` to only accept calendar dates.
+
+        Example
+        -------
+        >>> Date.parse_iso("2024-03-04")
+        Date(2024-03-04)
+        >>> Date.parse_iso("2024-W10-1")
+        Date(2024-03-04)
+        >>> Date.parse_iso("2024-064")
+        Date(2024-03-04)
+        """
+        if not isinstance(s, str):
+            raise TypeError("argument must be str")
+        try:
+            if _match_date(s):
+                return cls._from_py_unchecked(_date.fromisoformat(s))
+            elif match := _match_week_date(s):
+                return cls._from_py_unchecked(
+                    _date.fromisocalendar(*map(int, match.groups()))
+                )
+            elif match := _match_ordinal_date(s):
+                year, day = map(int, match.groups())
+                if 1 <= day <= 365 + isleap(year):
+                    return cls._from_py_unchecked(
+                        _date(year, 1, 1) + _timedelta(day - 1)
+                    )
+        except (ValueError, OverflowError):
+            pass
+        raise ValueError(f"Invalid format: {s!r}")
+
     def replace(self, **kwargs: Any) -> Date:
         """Create a new instance with the given fields replaced
 
+        By default, a ``ValueError`` is raised if the resulting day doesn't
+        exist in the month. Pass ``on_invalid="clamp"`` to use the last day
+        of the month instead.
+
         Example
         -------
         >>> d = Date(2021, 1, 2)
         >>> d.replace(day=4)
         Date(2021-01-04)
+        >>> Date(2024, 2, 29).replace(year=2023, on_invalid="clamp")
+        Date(2023-02-28)
         """
+        on_invalid = kwargs.pop("on_invalid", "error")
+        if "year" in kwargs:
+            _check_common_era(kwargs["year"])
+        if on_invalid == "clamp":
+            year = kwargs.get("year", self._py_date.year)
+            month = kwargs.get("month", self._py_date.month)
+            day = kwargs.get("day", self._py_date.day)
+            if (
+                isinstance(year, int)
+                and isinstance(month, int)
+                and isinstance(day, int)
+                and 1 <= month <= 12
+                and 1 <= day <= 31
+            ):
+                kwargs["day"] = min(day, monthrange(year, month)[1])
+        elif on_invalid != "error":
+            raise ValueError("on_invalid must be 'error' or 'clamp'")
         return Date._from_py_unchecked(self._py_date.replace(**kwargs))
 
+    def with_year(self, year: int, /) -> Date:
+        """Create a new date with the year replaced.
+        Like ``replace(year=year)``, but faster.
+
+        Raises ``ValueError`` if the resulting date doesn't exist
+        (i.e. February 29th in a non-leap year).
+
+        Example
+        -------
+        >>> Date(2024, 3, 15).with_year(2025)
+        Date(2025-03-15)
+        """
+        if not isinstance(year, int):
+            raise TypeError("year must be an integer")
+        _check_common_era(year)
+        return Date._from_py_unchecked(self._py_date.replace(year=year))
+
+    def with_month(self, month: int, /) -> Date:
+        """Create a new date with the month replaced.
+        Like ``replace(month=month)``, but faster.
+
+        Raises ``ValueError`` if the day doesn't exist in the resulting
+        month (e.g. the 31st in April). Use
+        ``replace(month=month, on_invalid="clamp")`` to get
+        the last day of the month instead.
+
+        Example
+        -------
+        >>> Date(2024, 3, 15).with_month(4)
+        Date(2024-04-15)
+        >>> Date(2024, 3, 31).with_month(4)
+        ValueError  # April has 30 days
+        """
+        if not isinstance(month, int):
+            raise TypeError("month must be an integer")
+        return Date._from_py_unchecked(self._py_date.replace(month=month))
+
+    def with_day(self, day: int, /) -> Date:
+        """Create a new date with the day replaced.
+        Like ``replace(day=day)``, but faster.
+
+        Raises ``ValueError`` if the day doesn't exist in the month.
+
+        Example
+        -------
+        >>> Date(2024, 3, 15).with_day(1)
+        Date(2024-03-01)
+        """
+        if not isinstance(day, int):
+            raise TypeError("day must be an integer")
+        return Date._from_py_unchecked(self._py_date.replace(day=day))
+
     @no_type_check
     def add(self, *args, **kwargs) -> Date:
         """Add a components to a date.
@@ -359,6 +990,50 @@ class Date(_ImmutableBase):
         """
         return self._shift(-1, *args, **kwargs)
 
+    def add_weeks(self, n: int, /) -> Date:
+        """Add a number of weeks to a date.
+        Equivalent to ``add(weeks=n)``.
+
+        Example
+        -------
+        >>> Date(2021, 1, 2).add_weeks(3)
+        Date(2021-01-23)
+        >>> Date(2021, 1, 2).add_weeks(-1)
+        Date(2020-12-26)
+        """
+        if not isinstance(n, int):
+            raise TypeError("weeks must be an integer")
+        try:
+            return self._add_days(n * 7)
+        except OverflowError:
+            raise ValueError("Resulting date out of range")
+
+    def next_day(self) -> Date:
+        """The day after this date.
+        Raises ``ValueError`` if this is :attr:`MAX`.
+
+        Example
+        -------
+        >>> Date(2021, 12, 31).next_day()
+        Date(2022-01-01)
+        """
+        if self._py_date == _date.max:
+            raise ValueError("Resulting date out of range")
+        return self._add_days(1)
+
+    def previous_day(self) -> Date:
+        """The day before this date.
+        Raises ``ValueError`` if this is :attr:`MIN`.
+
+        Example
+        -------
+        >>> Date(2024, 3, 1).previous_day()
+        Date(2024-02-29)
+        """
+        if self._py_date == _date.min:
+            raise ValueError("Resulting date out of range")
+        return self._add_days(-1)
+
     @no_type_check
     def _shift(
         self, sign: int, delta: DateDelta | _UNSET = _UNSET, /, **kwargs
@@ -415,6 +1090,74 @@ class Date(_ImmutableBase):
         """
         return (self._py_date - other._py_date).days
 
+    def leap_years_until(self, other: Date, /) -> int:
+        """Count the leap days (February 29ths) strictly between
+        this date and another date.
+        If the other date is before this date, the result is negative.
+
+        Example
+        -------
+        >>> Date(1999, 6, 1).leap_years_until(Date(2004, 6, 1))
+        2
+        >>> Date(2004, 6, 1).leap_years_until(Date(1999, 6, 1))
+        -2
+        """
+        if self._py_date <= other._py_date:
+            start, end, sign = self._py_date, other._py_date, 1
+        else:
+            start, end, sign = other._py_date, self._py_date, -1
+        if start == end:
+            return 0
+        return sign * (
+            _leap_days_before(end)
+            - _leap_days_before(start)
+            - (start.month == 2 and start.day == 29)
+        )
+
+    def business_days_between(
+        self,
+        other: Date,
+        /,
+        *,
+        weekend: Iterable[Weekday] = (Weekday.SATURDAY, Weekday.SUNDAY),
+    ) -> int:
+        """Count the business days from this date up to (but not including)
+        another date. Days in ``weekend`` (Saturday and Sunday by default)
+        are not counted.
+        If the other date is before this date, the result is negative.
+
+        Example
+        -------
+        >>> d = Date(2024, 3, 15)  # a Friday
+        >>> d.business_days_between(Date(2024, 3, 19))
+        2
+        >>> d.business_days_between(
+        ...     Date(2024, 3, 19), weekend=[Weekday.FRIDAY, Weekday.SATURDAY]
+        ... )
+        2
+        >>> Date(2024, 3, 19).business_days_between(d)
+        -2
+        """
+        if not isinstance(other, Date):
+            raise TypeError("argument must be a whenever.Date")
+        mask = 0
+        for w in weekend:
+            if not isinstance(w, Weekday):
+                raise TypeError("weekday must be a whenever.Weekday")
+            mask |= 1 << (w.value - 1)
+        if self <= other:
+            start, end, sign = self._py_date, other._py_date, 1
+        else:
+            start, end, sign = other._py_date, self._py_date, -1
+        # Count whole weeks first, then the remaining days,
+        # which start on the same weekday as `start`
+        weeks, rest = divmod((end - start).days, 7)
+        first = start.weekday()
+        remainder = sum(
+            1 for i in range(first, first + rest) if not mask & (1 << (i % 7))
+        )
+        return sign * (weeks * (7 - bin(mask).count("1")) + remainder)
+
     def _add_months(self, mos: int) -> Date:
         year_overflow, month_new = divmod(self.month - 1 + mos, 12)
         month_new += 1
@@ -428,23 +1171,33 @@ class Date(_ImmutableBase):
     def _add_days(self, days: int) -> Date:
         return Date._from_py_unchecked(self._py_date + _timedelta(days))
 
-    def __add__(self, p: DateDelta) -> Date:
+    def __add__(self, p: DateDelta | int) -> Date:
         """Add a delta to a date.
         Behaves the same as :meth:`add`
+
+        An integer is interpreted as a number of days:
+
+        >>> Date(2021, 1, 2) + 3
+        Date(2021-01-05)
         """
-        return (  # type: ignore[no-any-return]
-            self.add(months=p._months, days=p._days)
-            if isinstance(p, DateDelta)
-            else NotImplemented
-        )
+        if isinstance(p, DateDelta):
+            return self.add(months=p._months, days=p._days)  # type: ignore[no-any-return]
+        elif type(p) is int:
+            try:
+                return self._add_days(p)
+            except OverflowError:
+                raise ValueError("Resulting date out of range")
+        elif isinstance(p, TimeDelta):
+            raise TypeError(SHIFT_DATE_MSG)
+        return NotImplemented
 
     @overload
-    def __sub__(self, d: DateDelta) -> Date: ...
+    def __sub__(self, d: DateDelta | int) -> Date: ...
 
     @overload
     def __sub__(self, d: Date) -> DateDelta: ...
 
-    def __sub__(self, d: DateDelta | Date) -> Date | DateDelta:
+    def __sub__(self, d: DateDelta | Date | int) -> Date | DateDelta:
         """Subtract a delta from a date, or subtract two dates
 
         Subtracting a delta works the same as :meth:`subtract`.
@@ -452,6 +1205,11 @@ class Date(_ImmutableBase):
         >>> Date(2021, 1, 2) - DateDelta(weeks=1, days=3)
         Date(2020-12-26)
 
+        An integer is interpreted as a number of days:
+
+        >>> Date(2021, 1, 2) - 5
+        Date(2020-12-28)
+
         The difference between two dates is calculated in months and days,
         such that:
 
@@ -483,6 +1241,13 @@ class Date(_ImmutableBase):
         """
         if isinstance(d, DateDelta):
             return self.subtract(months=d._months, days=d._days)  # type: ignore[no-any-return]
+        elif type(d) is int:
+            try:
+                return self._add_days(-d)
+            except OverflowError:
+                raise ValueError("Resulting date out of range")
+        elif isinstance(d, TimeDelta):
+            raise TypeError(SHIFT_DATE_MSG)
         elif isinstance(d, Date):
             mos = self.month - d.month + 12 * (self.year - d.year)
             shifted = d._add_months(mos)
@@ -515,6 +1280,51 @@ class Date(_ImmutableBase):
 
     __str__ = format_common_iso
 
+    def __format__(self, spec: str) -> str:
+        """Format with the date-related ``strftime`` directives,
+        e.g. in f-strings.
+        An empty format spec gives the same result as ``str()``.
+
+        Example
+        -------
+        >>> d = Date(2024, 3, 15)
+        >>> f"{d:%a %d %b %Y}"
+        'Fri 15 Mar 2024'
+        >>> f"{d}"
+        '2024-03-15'
+
+        Note
+        ----
+        The supported directives are ``%a %A %b %B %d %m %y %Y %j %w %u %%``.
+        Unlike ``strftime``, names are always in English,
+        regardless of the locale.
+        """
+        if not spec:
+            return str(self)
+        d = self._py_date
+        weekday = _WEEKDAY_NAMES[d.weekday()]
+        month = _MONTH_NAMES[d.month - 1]
+        values = {
+            "a": weekday[:3],
+            "A": weekday,
+            "b": month[:3],
+            "B": month,
+            "d": f"{d.day:02d}",
+            "m": f"{d.month:02d}",
+            "y": f"{d.year % 100:02d}",
+            "Y": f"{d.year:04d}",
+            "j": f"{d.timetuple().tm_yday:03d}",
+            "w": str(d.isoweekday() % 7),
+            "u": str(d.isoweekday()),
+            "%": "%",
+        }
+        try:
+            return re.sub(
+                r"%(.?)", lambda m: values[m.group(1)], spec, flags=re.DOTALL
+            )
+        except KeyError:
+            raise ValueError(f"Invalid format directive in {spec!r}")
+
     def __repr__(self) -> str:
         return f"Date({self})"
 
@@ -534,7 +1344,22 @@ class Date(_ImmutableBase):
         return self._py_date == other._py_date
 
     def __hash__(self) -> int:
-        return hash(self._py_date)
+        # Same as the Rust implementation, which is stable across runs
+        d = self._py_date
+        return d.year << 9 | d.month << 5 | d.day
+
+    def __int__(self) -> int:
+        """The proleptic Gregorian ordinal of the date,
+        where January 1 of year 1 has ordinal 1.
+
+        Example
+        -------
+        >>> int(Date(2021, 7, 29))
+        738000
+        """
+        return self._py_date.toordinal()
+
+    __index__ = __int__
 
     def __lt__(self, other: Date) -> bool:
         if not isinstance(other, Date):
@@ -564,14 +1389,16 @@ class Date(_ImmutableBase):
 
     @no_type_check
     def __reduce__(self):
-        return _unpkl_date, (pack("<HBB", self.year, self.month, self.day),)
+        return _unpkl_date, (
+            pack("<BHBB", _PICKLE_VERSION, self.year, self.month, self.day),
+        )
 
 
 # A separate unpickling function allows us to make backwards-compatible changes
 # to the pickling format in the future
 @no_type_check
 def _unpkl_date(data: bytes) -> Date:
-    return Date(*unpack("<HBB", data))
+    return Date(*unpack("<HBB", _strip_pickle_version(data, 4)))
 
 
 Date.MIN = Date._from_py_unchecked(_date.min)
@@ -830,6 +1657,7 @@ class MonthDay(_ImmutableBase):
         This method will raise a ``ValueError`` if the month-day is a leap day
         and the year is not a leap year.
         """
+        _check_common_era(year)
         return Date._from_py_unchecked(self._py_date.replace(year=year))
 
     def is_leap(self) -> bool:
@@ -943,9 +1771,7 @@ class Time(_ImmutableBase):
         nanosecond: int = 0,
     ) -> None:
         self._py_time = _time(hour, minute, second)
-        if nanosecond < 0 or nanosecond >= 1_000_000_000:
-            raise ValueError("Nanosecond out of range")
-        self._nanos = nanosecond
+        self._nanos = _check_nanos(nanosecond)
 
     @property
     def hour(self) -> int:
@@ -985,6 +1811,26 @@ class Time(_ImmutableBase):
         """Convert to a standard library :class:`~datetime.time`"""
         return self._py_time.replace(microsecond=self._nanos // 1_000)
 
+    def total_seconds_float(self) -> float:
+        """The number of seconds since midnight, as a float
+
+        Note
+        ----
+        A float can't represent every nanosecond value exactly.
+        The result is the nearest float, which is accurate
+        to within about 0.01 nanoseconds.
+
+        Example
+        -------
+        >>> Time(1, 2, 3, nanosecond=500_000_000).total_seconds_float()
+        3723.5
+        """
+        t = self._py_time
+        return (
+            (t.hour * 3_600 + t.minute * 60 + t.second) * 1_000_000_000
+            + self._nanos
+        ) / 1_000_000_000
+
     @classmethod
     def from_py_time(cls, t: _time, /) -> Time:
         """Create from a :class:`~datetime.time`
@@ -994,18 +1840,45 @@ class Time(_ImmutableBase):
         >>> Time.from_py_time(time(12, 30, 0))
         Time(12:30:00)
 
-        `fold` value is ignored.
+        `fold` value is ignored.
+        """
+        if type(t) is _time:
+            if t.tzinfo is not None:
+                raise ValueError("Time must be naive")
+        elif isinstance(t, _time):
+            # subclass-safe way to ensure we have exactly a datetime.time
+            t = _time(t.hour, t.minute, t.second, t.microsecond)
+        else:
+            raise TypeError(f"Expected datetime.time, got {type(t)!r}")
+        return cls._from_py_unchecked(
+            t.replace(microsecond=0), t.microsecond * 1_000
+        )
+
+    @classmethod
+    def now_in_tz(cls, tz: str, /) -> Time:
+        """The current time of day in the given timezone
+
+        Equivalent to ``ZonedDateTime.now(tz).time()``
+
+        Example
+        -------
+        >>> Time.now_in_tz("Europe/Paris")
+        Time(14:02:11.034903)
+        """
+        secs, nanos = divmod(time_ns(), 1_000_000_000)
+        return cls._from_py_unchecked(
+            _fromtimestamp(secs, ZoneInfo(tz)).time(), nanos
+        )
+
+    @classmethod
+    def now_in_system_tz(cls) -> Time:
+        """The current time of day in the system timezone
+
+        Equivalent to ``SystemDateTime.now().time()``
         """
-        if type(t) is _time:
-            if t.tzinfo is not None:
-                raise ValueError("Time must be naive")
-        elif isinstance(t, _time):
-            # subclass-safe way to ensure we have exactly a datetime.time
-            t = _time(t.hour, t.minute, t.second, t.microsecond)
-        else:
-            raise TypeError(f"Expected datetime.time, got {type(t)!r}")
+        secs, nanos = divmod(time_ns(), 1_000_000_000)
         return cls._from_py_unchecked(
-            t.replace(microsecond=0), t.microsecond * 1_000
+            _fromtimestamp(secs, _UTC).astimezone(None).time(), nanos
         )
 
     def format_common_iso(self) -> str:
@@ -1102,13 +1975,80 @@ class Time(_ImmutableBase):
         >>> Time(8, 9, 13).round("second", 5, mode="floor")
         Time(08:09:10)
         """
+        return self.round_with_carry(unit, increment, mode)[0]
+
+    def round_with_carry(
+        self,
+        unit: Literal[
+            "hour",
+            "minute",
+            "second",
+            "millisecond",
+            "microsecond",
+            "nanosecond",
+        ] = "second",
+        increment: int = 1,
+        mode: Literal[
+            "ceil", "floor", "half_ceil", "half_floor", "half_even"
+        ] = "half_even",
+    ) -> tuple[Time, int]:
+        """Round the time like :meth:`round`, and also return
+        whether the rounding carried over into the next day (0 or 1).
+
+        Useful for adjusting an accompanying date.
+
+        Example
+        -------
+        >>> Time(12, 39, 59).round_with_carry("minute", 15)
+        (Time(12:45:00), 0)
+        >>> Time(23, 59, 59, nanosecond=900_000_000).round_with_carry()
+        (Time(00:00:00), 1)
+        """
         if unit == "day":  # type: ignore[comparison-overlap]
             raise ValueError("Cannot round Time to day")
         return self._round_unchecked(
             increment_to_ns(unit, increment, any_hour_ok=False),
             mode,
             86_400_000_000_000,
-        )[0]
+        )
+
+    def add(self, delta: TimeDelta, /) -> tuple[Time, int]:
+        """Add a time delta, wrapping around midnight.
+        Returns the resulting time and the number of days carried over.
+
+        Use the ``+`` operator if you only need the resulting time.
+
+        Example
+        -------
+        >>> Time(23, 30).add(hours(2))
+        (Time(01:30:00), 1)
+        >>> Time(1, 30).add(hours(-50))
+        (Time(23:30:00), -3)
+        """
+        if not isinstance(delta, TimeDelta):
+            raise TypeError("argument must be a whenever.TimeDelta")
+        return self._shift_wrapping(delta._total_ns)
+
+    def subtract(self, delta: TimeDelta, /) -> tuple[Time, int]:
+        """Subtract a time delta, wrapping around midnight.
+        Returns the resulting time and the number of days carried over.
+
+        Use the ``-`` operator if you only need the resulting time.
+
+        Example
+        -------
+        >>> Time(1, 30).subtract(hours(2))
+        (Time(23:30:00), -1)
+        """
+        if not isinstance(delta, TimeDelta):
+            raise TypeError("argument must be a whenever.TimeDelta")
+        return self._shift_wrapping(-delta._total_ns)
+
+    def _shift_wrapping(self, ns: int) -> tuple[Time, int]:
+        days, ns = divmod(
+            self._to_ns_since_midnight() + ns, 86_400_000_000_000
+        )
+        return self._from_ns_since_midnight(ns), days
 
     def _round_unchecked(
         self,
@@ -1152,6 +2092,28 @@ class Time(_ImmutableBase):
     def __repr__(self) -> str:
         return f"Time({self})"
 
+    def __add__(self, delta: TimeDelta) -> Time:
+        """Add a time delta, wrapping around midnight.
+        Use :meth:`add` to also get the number of days carried over.
+
+        >>> Time(23, 30) + hours(2)
+        Time(01:30:00)
+        """
+        if isinstance(delta, TimeDelta):
+            return self._shift_wrapping(delta._total_ns)[0]
+        return NotImplemented
+
+    def __sub__(self, delta: TimeDelta) -> Time:
+        """Subtract a time delta, wrapping around midnight.
+        Use :meth:`subtract` to also get the number of days carried over.
+
+        >>> Time(1, 30) - hours(2)
+        Time(23:30:00)
+        """
+        if isinstance(delta, TimeDelta):
+            return self._shift_wrapping(-delta._total_ns)[0]
+        return NotImplemented
+
     def __eq__(self, other: object) -> bool:
         """Compare for equality
 
@@ -1162,6 +2124,13 @@ class Time(_ImmutableBase):
         True
         >>> t == Time(12, 30, 1)
         False
+
+        Note
+        ----
+        A :class:`Time` never equals a :class:`~datetime.time`,
+        and their hashes aren't aligned. In a set or dict they're
+        always separate entries. Convert explicitly with
+        :meth:`py_time` or :meth:`from_py_time` to mix them.
         """
         if not isinstance(other, Time):
             return NotImplemented
@@ -1242,6 +2211,13 @@ def increment_to_ns(unit: str, increment: int, any_hour_ok: bool) -> int:
         raise ValueError(f"Invalid unit: {unit}")
 
 
+def _check_nanos(nanos: int) -> int:
+    # same message format as the stdlib uses for the other time fields
+    if not 0 <= nanos < 1_000_000_000:
+        raise ValueError("nanosecond must be in 0..999999999")
+    return nanos
+
+
 # A separate unpickling function allows us to make backwards-compatible changes
 # to the pickling format in the future
 def _unpkl_time(data: bytes) -> Time:
@@ -1322,6 +2298,24 @@ class TimeDelta(_ImmutableBase):
         """
         return self._total_ns / 86_400_000_000_000
 
+    def whole_days_of_24h(self) -> int:
+        """The number of complete days (of exactly 24 hours each),
+        truncated toward zero.
+
+        Like :meth:`in_days_of_24h`, but as an integer.
+        Negative deltas are truncated toward zero as well,
+        so -1.5 days becomes -1.
+
+        Example
+        -------
+        >>> TimeDelta(hours=36).whole_days_of_24h()
+        1
+        >>> TimeDelta(hours=-36).whole_days_of_24h()
+        -1
+        """
+        days = abs(self._total_ns) // 86_400_000_000_000
+        return -days if self._total_ns < 0 else days
+
     def in_hours(self) -> float:
         """The total size in hours
 
@@ -1400,6 +2394,25 @@ class TimeDelta(_ImmutableBase):
             else (-hours, -mins, -secs, -ms)
         )
 
+    def as_tuple(self) -> tuple[int, int, int, int, int]:
+        """Convert to a tuple of
+        (sign, hours, minutes, seconds, nanoseconds).
+
+        The sign is -1, 0, or 1. All other components are non-negative,
+        which makes it easy to build strings like ``"2h 30m ago"``.
+
+        Example
+        -------
+        >>> d = TimeDelta(hours=-1, minutes=-30, microseconds=-5_000_090)
+        >>> d.as_tuple()
+        (-1, 1, 30, 5, 90000)
+        """
+        hours, rem = divmod(abs(self._total_ns), 3_600_000_000_000)
+        mins, rem = divmod(rem, 60_000_000_000)
+        secs, ns = divmod(rem, 1_000_000_000)
+        sign = (self._total_ns > 0) - (self._total_ns < 0)
+        return (sign, hours, mins, secs, ns)
+
     def py_timedelta(self) -> _timedelta:
         """Convert to a :class:`~datetime.timedelta`
 
@@ -1435,6 +2448,53 @@ class TimeDelta(_ImmutableBase):
             hours=td.days * 24,
         )
 
+    @classmethod
+    def from_units(
+        cls,
+        *,
+        weeks: float = 0,
+        days: float = 0,
+        hours: float = 0,
+        minutes: float = 0,
+        seconds: float = 0,
+        milliseconds: float = 0,
+        microseconds: float = 0,
+        nanoseconds: float = 0,
+    ) -> TimeDelta:
+        """Create from any combination of units,
+        like the :class:`~datetime.timedelta` constructor.
+
+        Floats are converted exactly, based on their shortest
+        decimal representation. Amounts that don't add up to
+        a whole number of nanoseconds raise a :exc:`ValueError`.
+
+        Example
+        -------
+        >>> TimeDelta.from_units(days=1.5, minutes=30)
+        TimeDelta(36:30:00)
+        >>> TimeDelta.from_units(hours=0.1)
+        TimeDelta(00:06:00)
+
+        Note
+        ----
+        Days and weeks are always exactly 24 and 7×24 hours long.
+        To add calendar days, which may be longer or shorter due to DST,
+        use :class:`DateDelta` instead.
+        """
+        ns = (
+            _unit_to_nanos(weeks, 604_800_000_000_000, "weeks")
+            + _unit_to_nanos(days, 86_400_000_000_000, "days")
+            + _unit_to_nanos(hours, 3_600_000_000_000, "hours")
+            + _unit_to_nanos(minutes, 60_000_000_000, "minutes")
+            + _unit_to_nanos(seconds, 1_000_000_000, "seconds")
+            + _unit_to_nanos(milliseconds, 1_000_000, "milliseconds")
+            + _unit_to_nanos(microseconds, 1_000, "microseconds")
+            + _unit_to_nanos(nanoseconds, 1, "nanoseconds")
+        )
+        if abs(ns) > _MAX_DELTA_NANOS:
+            raise ValueError("TimeDelta out of range")
+        return cls._from_nanos_unchecked(ns)
+
     def format_common_iso(self) -> str:
         """Format as the *popular interpretation* of the ISO 8601 duration format.
         May not strictly adhere to (all versions of) the standard.
@@ -1571,6 +2631,49 @@ class TimeDelta(_ImmutableBase):
             raise ValueError("Resulting TimeDelta out of range")
         return self._from_nanos_unchecked(rounded_ns)
 
+    def isclose(self, other: TimeDelta, /, *, abs_tol: TimeDelta) -> bool:
+        """Whether the delta differs from another by at most ``abs_tol``
+
+        Like :func:`math.isclose`, but for deltas and with only an
+        absolute tolerance.
+
+        Example
+        -------
+        >>> d = TimeDelta(seconds=1, nanoseconds=400)
+        >>> d.isclose(seconds(1), abs_tol=microseconds(1))
+        True
+        """
+        if not (
+            isinstance(other, TimeDelta) and isinstance(abs_tol, TimeDelta)
+        ):
+            raise TypeError("isclose() arguments must be TimeDelta instances")
+        if abs_tol._total_ns < 0:
+            raise ValueError("abs_tol must not be negative")
+        return abs(self._total_ns - other._total_ns) <= abs_tol._total_ns
+
+    def clamp(self, lower: TimeDelta, upper: TimeDelta, /) -> TimeDelta:
+        """Limit the delta to the range from ``lower`` to ``upper``
+        (inclusive). Raises :class:`ValueError` if ``lower > upper``.
+
+        Example
+        -------
+        >>> hours(3).clamp(minutes(1), hours(1))
+        TimeDelta(01:00:00)
+        >>> seconds(-5).clamp(TimeDelta.ZERO, hours(1))
+        TimeDelta(00:00:00)
+        """
+        if not (isinstance(lower, TimeDelta) and isinstance(upper, TimeDelta)):
+            raise TypeError("clamp() arguments must be TimeDelta instances")
+        if lower._total_ns > upper._total_ns:
+            raise ValueError(
+                "lower bound must not be greater than upper bound"
+            )
+        if self._total_ns < lower._total_ns:
+            return lower
+        elif self._total_ns > upper._total_ns:
+            return upper
+        return self
+
     def __add__(self, other: TimeDelta) -> TimeDelta:
         """Add two deltas together
 
@@ -1739,6 +2842,20 @@ class TimeDelta(_ImmutableBase):
             return NotImplemented
         return TimeDelta(nanoseconds=self._total_ns % other._total_ns)
 
+    def __divmod__(self, other: TimeDelta) -> tuple[int, TimeDelta]:
+        """Floor division and modulo by another delta, at once
+
+        Example
+        -------
+        >>> d = TimeDelta(hours=3, minutes=30)
+        >>> divmod(d, TimeDelta(hours=1))
+        (3, TimeDelta(00:30:00))
+        """
+        if not isinstance(other, TimeDelta):
+            return NotImplemented
+        quotient, remainder = divmod(self._total_ns, other._total_ns)
+        return quotient, TimeDelta._from_nanos_unchecked(remainder)
+
     def __abs__(self) -> TimeDelta:
         """The absolute value
 
@@ -1845,11 +2962,17 @@ class DateDelta(_ImmutableBase):
     def in_years_months_days(self) -> tuple[int, int, int]:
         """Convert to a tuple of years, months, and days.
 
+        The months are split into whole years and the remaining 0-11 months,
+        both with the same sign. Days are never carried over into months,
+        since months differ in length.
+
         Example
         -------
         >>> p = DateDelta(years=1, months=2, days=11)
         >>> p.in_years_months_days()
         (1, 2, 11)
+        >>> DateDelta(months=-14, days=-40).in_years_months_days()
+        (-1, -2, -40)
         """
         years = int(self._months / 12)
         months = int(fmod(self._months, 12))
@@ -2064,6 +3187,53 @@ class DateDelta(_ImmutableBase):
     def __hash__(self) -> int:
         return hash((self._months, self._days))
 
+    def _ordering_days(self, other: DateDelta) -> tuple[int, int]:
+        # Only the days can be ordered unambiguously,
+        # since the length of a month varies
+        if self._months != other._months:
+            raise TypeError(
+                "Cannot order DateDeltas with different months, "
+                "since the length of a month varies"
+            )
+        return self._days, other._days
+
+    def __lt__(self, other: DateDelta) -> bool:
+        """Compare two deltas, if they have the same months
+        (e.g. if they only consist of days and weeks).
+
+        Raises :class:`TypeError` if the months differ,
+        since the length of a month varies.
+
+        Example
+        -------
+        >>> DateDelta(weeks=1) < DateDelta(days=8)
+        True
+        >>> DateDelta(months=1) < DateDelta(days=31)
+        TypeError  # months differ
+        """
+        if not isinstance(other, DateDelta):
+            return NotImplemented
+        a, b = self._ordering_days(other)
+        return a < b
+
+    def __le__(self, other: DateDelta) -> bool:
+        if not isinstance(other, DateDelta):
+            return NotImplemented
+        a, b = self._ordering_days(other)
+        return a <= b
+
+    def __gt__(self, other: DateDelta) -> bool:
+        if not isinstance(other, DateDelta):
+            return NotImplemented
+        a, b = self._ordering_days(other)
+        return a > b
+
+    def __ge__(self, other: DateDelta) -> bool:
+        if not isinstance(other, DateDelta):
+            return NotImplemented
+        a, b = self._ordering_days(other)
+        return a >= b
+
     def __bool__(self) -> bool:
         """True if any contains any non-zero data
 
@@ -2310,19 +3480,18 @@ class DateTimeDelta(_ImmutableBase):
         >>> d + DateTimeDelta(months=2, days=3, minutes=90)
         DateTimeDelta(P1M1W14DT5H30M)
         """
-        new = _object_new(DateTimeDelta)
         if isinstance(other, DateTimeDelta):
-            new._date_part = self._date_part + other._date_part
-            new._time_part = self._time_part + other._time_part
+            d = self._date_part + other._date_part
+            t = self._time_part + other._time_part
         elif isinstance(other, TimeDelta):
-            new._date_part = self._date_part
-            new._time_part = self._time_part + other
+            d = self._date_part
+            t = self._time_part + other
         elif isinstance(other, DateDelta):
-            new._date_part = self._date_part + other
-            new._time_part = self._time_part
+            d = self._date_part + other
+            t = self._time_part
         else:
             return NotImplemented
-        return new
+        return self._from_parts(d, t)
 
     def __radd__(self, other: TimeDelta | DateDelta) -> DateTimeDelta:
         if isinstance(other, (TimeDelta, DateDelta)):
@@ -2354,16 +3523,15 @@ class DateTimeDelta(_ImmutableBase):
         return self._from_parts(d, t)
 
     def __rsub__(self, other: TimeDelta | DateDelta) -> DateTimeDelta:
-        new = _object_new(DateTimeDelta)
         if isinstance(other, TimeDelta):
-            new._date_part = -self._date_part
-            new._time_part = other - self._time_part
+            d = -self._date_part
+            t = other - self._time_part
         elif isinstance(other, DateDelta):
-            new._date_part = other - self._date_part
-            new._time_part = -self._time_part
+            d = other - self._date_part
+            t = -self._time_part
         else:
             return NotImplemented
-        return new
+        return self._from_parts(d, t)
 
     def __eq__(self, other: object) -> bool:
         """Compare for equality
@@ -2969,6 +4137,10 @@ class _KnowsInstant(_BasicConversions):
         If ``a.exact_eq(b)`` is true, then
         ``a == b`` is also true, but the converse is not necessarily true.
 
+        For :class:`ZonedDateTime`, the timezone and offset must match too.
+        This distinguishes the two occurrences of a time repeated
+        by a DST fold, as well as zones which happen to share an offset.
+
         Examples
         --------
 
@@ -2980,19 +4152,26 @@ class _KnowsInstant(_BasicConversions):
         False  # different values (hour and offset)
         >>> a.exact_eq(Instant.now())
         TypeError  # different types
+        >>> c = ZonedDateTime(2020, 8, 15, hour=12, tz="Europe/Paris")
+        >>> c == c.to_tz("Europe/Amsterdam")
+        True  # same moment
+        >>> c.exact_eq(c.to_tz("Europe/Amsterdam"))
+        False  # different timezones
         """
         if type(self) is not type(other):
             raise TypeError("Cannot compare different types")
+        # Note: the tzinfo itself is compared, not just the resulting
+        # offset, so different zones are never exactly equal.
         return (
             self._py_dt,  # type: ignore[attr-defined]
             self._py_dt.utcoffset(),  # type: ignore[attr-defined]
             self._nanos,  # type: ignore[attr-defined]
-            type(self._py_dt.tzinfo),  # type: ignore[attr-defined]
+            self._py_dt.tzinfo,  # type: ignore[attr-defined]
         ) == (
             other._py_dt,  # type: ignore[attr-defined]
             other._py_dt.utcoffset(),  # type: ignore[attr-defined]
             other._nanos,  # type: ignore[attr-defined]
-            type(other._py_dt.tzinfo),  # type: ignore[attr-defined]
+            other._py_dt.tzinfo,  # type: ignore[attr-defined]
         )
 
     def difference(
@@ -3180,6 +4359,20 @@ class _KnowsInstantAndLocal(_KnowsLocal, _KnowsInstant):
             self._py_dt.astimezone(_UTC), self._nanos
         )
 
+    def to_instant(self) -> Instant:
+        """Get the underlying instant in time.
+        Alias for :meth:`instant`, named consistently with
+        :meth:`~_KnowsInstant.to_tz` and :meth:`~_KnowsInstant.to_fixed_offset`.
+
+        Example
+        -------
+
+        >>> d = OffsetDateTime(2020, 8, 15, hour=23, offset=2)
+        >>> d.to_instant()
+        Instant(2020-08-15 21:00:00Z)
+        """
+        return self.instant()
+
     def local(self) -> LocalDateTime:
         """Get the underlying local date and time
 
@@ -3231,11 +4424,10 @@ class Instant(_KnowsInstant):
         nanosecond: int = 0,
     ) -> Instant:
         """Create an Instant defined by a UTC date and time."""
-        if nanosecond < 0 or nanosecond >= 1_000_000_000:
-            raise ValueError(f"nanosecond out of range: {nanosecond}")
+        _check_common_era(year)
         return cls._from_py_unchecked(
             _datetime(year, month, day, hour, minute, second, 0, _UTC),
-            nanosecond,
+            _check_nanos(nanosecond),
         )
 
     MIN: ClassVar[Instant]
@@ -3246,7 +4438,12 @@ class Instant(_KnowsInstant):
 
     @classmethod
     def now(cls) -> Instant:
-        """Create an Instant from the current time."""
+        """Create an Instant from the current time.
+
+        The system's realtime clock is read with the full
+        nanosecond precision it offers (i.e. not truncated to microseconds
+        like :meth:`datetime.datetime.now`).
+        """
         secs, nanos = divmod(time_ns(), 1_000_000_000)
         return cls._from_py_unchecked(_fromtimestamp(secs, _UTC), nanos)
 
@@ -3292,6 +4489,8 @@ class Instant(_KnowsInstant):
 
         The inverse of the ``py_datetime()`` method.
         """
+        if not isinstance(d, _datetime):
+            raise TypeError("Expected a datetime object")
         if d.tzinfo is None:
             raise ValueError(
                 "Cannot create Instant from a naive datetime. "
@@ -3401,23 +4600,39 @@ class Instant(_KnowsInstant):
                 )
         return cls._from_py_unchecked(parsed, 0)
 
-    def format_rfc3339(self) -> str:
+    def format_rfc3339(self, *, fractional_digits: int | None = None) -> str:
         """Format as an RFC 3339 string ``YYYY-MM-DD HH:MM:SSZ``
 
         If you prefer the ``T`` separator, use `format_common_iso()` instead.
 
+        By default, the fractional seconds are shown only as far as needed.
+        Pass ``fractional_digits`` (0, 3, 6, or 9) to always show
+        exactly that many digits. Extra digits are truncated, not rounded.
+
         The inverse of the ``parse_rfc3339()`` method.
 
         Example
         -------
         >>> Instant.from_utc(2020, 8, 15, hour=23, minute=12).format_rfc3339()
         "2020-08-15 23:12:00Z"
-        """
-        return (
-            self._py_dt.isoformat(sep=" ")[:-6]
-            + bool(self._nanos) * f".{self._nanos:09d}".rstrip("0")
-            + "Z"
-        )
+        >>> Instant.from_utc(
+        ...     2020, 8, 15, hour=23, minute=12, nanosecond=9_999
+        ... ).format_rfc3339(fractional_digits=6)
+        "2020-08-15 23:12:00.000009Z"
+        """
+        if fractional_digits is None:
+            fraction = bool(self._nanos) * f".{self._nanos:09d}".rstrip("0")
+        elif not isinstance(fractional_digits, int):
+            raise TypeError("fractional_digits must be an integer or None")
+        elif fractional_digits in (0, 3, 6, 9):
+            fraction = (
+                f".{self._nanos:09d}"[: fractional_digits + 1]
+                if fractional_digits
+                else ""
+            )
+        else:
+            raise ValueError("fractional_digits must be 0, 3, 6, or 9")
+        return self._py_dt.isoformat(sep=" ")[:-6] + fraction + "Z"
 
     @classmethod
     def parse_rfc3339(cls, s: str, /) -> Instant:
@@ -3502,45 +4717,168 @@ class Instant(_KnowsInstant):
 
     def round(
         self,
-        unit: Literal[
-            "hour",
-            "minute",
-            "second",
-            "millisecond",
-            "microsecond",
-            "nanosecond",
-        ] = "second",
-        increment: int = 1,
-        mode: Literal[
-            "ceil", "floor", "half_ceil", "half_floor", "half_even"
-        ] = "half_even",
-    ) -> Instant:
-        """Round the instant to the specified unit and increment.
-        Various rounding modes are available.
+        unit: Literal[
+            "hour",
+            "minute",
+            "second",
+            "millisecond",
+            "microsecond",
+            "nanosecond",
+        ] = "second",
+        increment: int = 1,
+        mode: Literal[
+            "ceil", "floor", "half_ceil", "half_floor", "half_even"
+        ] = "half_even",
+    ) -> Instant:
+        """Round the instant to the specified unit and increment.
+        Various rounding modes are available.
+
+        Examples
+        --------
+        >>> Instant.from_utc(2020, 1, 1, 12, 39, 59).round("minute", 15)
+        Instant(2020-01-01 12:45:00Z)
+        >>> Instant.from_utc(2020, 1, 1, 8, 9, 13).round("second", 5, mode="floor")
+        Instant(2020-01-01 08:09:10Z)
+        """
+        if unit == "day":  # type: ignore[comparison-overlap]
+            raise ValueError(CANNOT_ROUND_DAY_MSG)
+        rounded_time, next_day = Time._from_py_unchecked(
+            self._py_dt.time(), self._nanos
+        )._round_unchecked(
+            increment_to_ns(unit, increment, any_hour_ok=False),
+            mode,
+            86_400_000_000_000,
+        )
+        return self._from_py_unchecked(
+            _datetime.combine(
+                self._py_dt.date() + _timedelta(days=next_day),
+                rounded_time._py_time,
+                tzinfo=_UTC,
+            ),
+            rounded_time._nanos,
+        )
+
+    def difference(
+        self,
+        other: Instant | OffsetDateTime | ZonedDateTime | SystemDateTime,
+        /,
+        *,
+        unit: Literal[
+            "hour",
+            "minute",
+            "second",
+            "millisecond",
+            "microsecond",
+            "nanosecond",
+        ] = "nanosecond",
+        increment: int = 1,
+        mode: Literal[
+            "ceil", "floor", "half_ceil", "half_floor", "half_even"
+        ] = "half_even",
+    ) -> TimeDelta:
+        """Calculate the difference between two instants in time,
+        optionally rounded to the given unit and increment.
+
+        Without rounding, this is equivalent to :meth:`__sub__`.
+        With rounding, it's equivalent to ``(a - b).round(...)``.
+        As with :meth:`round`, ``unit="day"`` isn't accepted, since days
+        don't have a fixed length. Use ``unit="hour", increment=24``
+        to round to 24-hour days.
+
+        Example
+        -------
+        >>> a = Instant.from_utc(2020, 8, 15, 23, 12)
+        >>> b = Instant.from_utc(2020, 8, 14, 20, 50, 30)
+        >>> a.difference(b)
+        TimeDelta(26:21:30)
+        >>> a.difference(b, unit="hour", mode="floor")
+        TimeDelta(26:00:00)
+        >>> b.difference(a, unit="hour", mode="floor")
+        TimeDelta(-27:00:00)
+
+        See :ref:`the docs on arithmetic <arithmetic>` for more information.
+        """
+        if not isinstance(other, _KnowsInstant):
+            raise TypeError(
+                "difference() argument must be an OffsetDateTime, "
+                "Instant, ZonedDateTime, or SystemDateTime"
+            )
+        return (self - other).round(unit, increment, mode)
+
+    def elapsed_since(
+        self,
+        other: Instant | OffsetDateTime | ZonedDateTime | SystemDateTime,
+        /,
+    ) -> TimeDelta:
+        """Calculate the time elapsed since an earlier moment.
+
+        Like :meth:`difference`, but raises :class:`ValueError` if ``other``
+        is later than this instant, instead of returning a negative delta.
+        Use it for measurements that are ordered by design
+        (e.g. time since startup), so that swapped arguments
+        or clock issues are caught early.
+
+        Example
+        -------
+        >>> start = Instant.from_utc(2020, 8, 15, 23, 12)
+        >>> Instant.from_utc(2020, 8, 15, 23, 14).elapsed_since(start)
+        TimeDelta(00:02:00)
+        >>> start.elapsed_since(Instant.from_utc(2020, 8, 15, 23, 14))
+        ValueError  # argument is later
+        """
+        if not isinstance(other, _KnowsInstant):
+            raise TypeError(
+                "elapsed_since() argument must be an OffsetDateTime, "
+                "Instant, ZonedDateTime, or SystemDateTime"
+            )
+        delta = self - other
+        if delta < TimeDelta.ZERO:
+            raise ValueError(
+                "elapsed_since() argument is later than this instant"
+            )
+        return delta
+
+    def is_between(
+        self,
+        start: Instant | OffsetDateTime | ZonedDateTime | SystemDateTime,
+        end: Instant | OffsetDateTime | ZonedDateTime | SystemDateTime,
+        /,
+        *,
+        inclusive: Literal["[]", "[)", "(]", "()"] = "[)",
+    ) -> bool:
+        """Check whether this instant lies between two moments.
 
-        Examples
-        --------
-        >>> Instant.from_utc(2020, 1, 1, 12, 39, 59).round("minute", 15)
-        Instant(2020-01-01 12:45:00Z)
-        >>> Instant.from_utc(2020, 1, 1, 8, 9, 13).round("second", 5, mode="floor")
-        Instant(2020-01-01 08:09:10Z)
+        By default, ``start`` is included and ``end`` is excluded.
+        Use ``inclusive`` to set whether each endpoint is included:
+        ``"[]"``, ``"[)"``, ``"(]"``, or ``"()"``.
+        If ``start`` is after ``end``, the result is always ``False``.
+
+        Example
+        -------
+        >>> start = Instant.from_utc(2020, 8, 15, 12)
+        >>> end = Instant.from_utc(2020, 8, 15, 14)
+        >>> Instant.from_utc(2020, 8, 15, 13).is_between(start, end)
+        True
+        >>> end.is_between(start, end)
+        False
+        >>> end.is_between(start, end, inclusive="[]")
+        True
         """
-        if unit == "day":  # type: ignore[comparison-overlap]
-            raise ValueError(CANNOT_ROUND_DAY_MSG)
-        rounded_time, next_day = Time._from_py_unchecked(
-            self._py_dt.time(), self._nanos
-        )._round_unchecked(
-            increment_to_ns(unit, increment, any_hour_ok=False),
-            mode,
-            86_400_000_000_000,
-        )
-        return self._from_py_unchecked(
-            _datetime.combine(
-                self._py_dt.date() + _timedelta(days=next_day),
-                rounded_time._py_time,
-                tzinfo=_UTC,
-            ),
-            rounded_time._nanos,
+        if not isinstance(inclusive, str):
+            raise TypeError("inclusive must be a string")
+        if inclusive not in ("[]", "[)", "(]", "()"):
+            raise ValueError(
+                "inclusive must be one of '[]', '[)', '(]', or '()'"
+            )
+        if not (
+            isinstance(start, _KnowsInstant) and isinstance(end, _KnowsInstant)
+        ):
+            raise TypeError(
+                "is_between() arguments must be OffsetDateTime, "
+                "Instant, ZonedDateTime, or SystemDateTime"
+            )
+        return (start <= self if inclusive[0] == "[" else start < self) and (
+            self <= end if inclusive[1] == "]" else self < end
         )
 
     def __add__(self, delta: TimeDelta) -> Instant:
@@ -3599,7 +4937,8 @@ class Instant(_KnowsInstant):
             _unpkl_utc,
             (
                 pack(
-                    "<qL",
+                    "<BqL",
+                    _PICKLE_VERSION,
                     int(self._py_dt.timestamp()) + _UNIX_INSTANT,
                     self._nanos,
                 ),
@@ -3613,7 +4952,7 @@ _UNIX_INSTANT = -int(_datetime(1, 1, 1, tzinfo=_UTC).timestamp()) + 86_400
 # A separate unpickling function allows us to make backwards-compatible changes
 # to the pickling format in the future
 def _unpkl_utc(data: bytes) -> Instant:
-    secs, nanos = unpack("<qL", data)
+    secs, nanos = unpack("<qL", _strip_pickle_version(data, 12))
     return Instant._from_py_unchecked(
         _fromtimestamp(secs - _UNIX_INSTANT, _UTC), nanos
     )
@@ -3652,6 +4991,7 @@ class OffsetDateTime(_KnowsInstantAndLocal):
         nanosecond: int = 0,
         offset: int | TimeDelta,
     ) -> None:
+        _check_common_era(year)
         self._py_dt = _check_utc_bounds(
             _datetime(
                 year,
@@ -3664,9 +5004,7 @@ class OffsetDateTime(_KnowsInstantAndLocal):
                 _load_offset(offset),
             )
         )
-        if nanosecond < 0 or nanosecond >= 1_000_000_000:
-            raise ValueError(f"nanosecond out of range: {nanosecond}")
-        self._nanos = nanosecond
+        self._nanos = _check_nanos(nanosecond)
 
     @classmethod
     def now(
@@ -3869,6 +5207,7 @@ class OffsetDateTime(_KnowsInstantAndLocal):
             kwargs["tzinfo"] = _load_offset(kwargs.pop("offset"))
         except KeyError:
             pass
+        _check_common_era(kwargs.get("year"))
         nanos = _pop_nanos_kwarg(kwargs, self._nanos)
         return self._from_py_unchecked(
             _check_utc_bounds(self._py_dt.replace(**kwargs)), nanos
@@ -3908,6 +5247,35 @@ class OffsetDateTime(_KnowsInstantAndLocal):
             time._nanos,
         )
 
+    def round_offset_to_minutes(self) -> OffsetDateTime:
+        """Round the offset to the nearest whole minute,
+        adjusting the local time so that the exact time is preserved.
+
+        Seconds in offsets mostly occur in historical timezone data.
+        Exactly 30 seconds is rounded away from zero.
+
+        Example
+        -------
+        >>> offset = TimeDelta(minutes=19, seconds=32)
+        >>> d = OffsetDateTime(1900, 1, 1, 12, offset=offset)
+        >>> d.round_offset_to_minutes()
+        OffsetDateTime(1900-01-01 12:00:28+00:20)
+        """
+        secs = int(self._py_dt.utcoffset().total_seconds())  # type: ignore[union-attr]
+        mins, remainder = divmod(abs(secs), 60)
+        rounded = (mins + (remainder >= 30)) * 60 * (-1 if secs < 0 else 1)
+        if rounded == secs:
+            return self
+        elif abs(rounded) >= 86_400:
+            raise ValueError("Resulting offset out of range")
+        try:
+            py_dt = self._py_dt.astimezone(
+                _timezone(_timedelta(seconds=rounded))
+            )
+        except OverflowError:
+            raise ValueError("Resulting local date is out of range")
+        return self._from_py_unchecked(py_dt, self._nanos)
+
     def __hash__(self) -> int:
         return hash((self._py_dt, self._nanos))
 
@@ -4216,7 +5584,8 @@ class OffsetDateTime(_KnowsInstantAndLocal):
             _unpkl_offset,
             (
                 pack(
-                    "<HBBBBBIl",
+                    "<BHBBBBBIl",
+                    _PICKLE_VERSION,
                     *self._py_dt.timetuple()[:6],
                     self._nanos,
                     int(self._py_dt.utcoffset().total_seconds()),  # type: ignore[union-attr]
@@ -4230,7 +5599,9 @@ class OffsetDateTime(_KnowsInstantAndLocal):
 # required by __reduce__.
 # Also, it allows backwards-compatible changes to the pickling format.
 def _unpkl_offset(data: bytes) -> OffsetDateTime:
-    *args, nanos, offset_secs = unpack("<HBBBBBIl", data)
+    *args, nanos, offset_secs = unpack(
+        "<HBBBBBIl", _strip_pickle_version(data, 15)
+    )
     args += (0, _timezone(_timedelta(seconds=offset_secs)))
     return OffsetDateTime._from_py_unchecked(_datetime(*args), nanos)
 
@@ -4269,6 +5640,8 @@ class ZonedDateTime(_KnowsInstantAndLocal):
         tz: str,
         disambiguate: Disambiguate = "compatible",
     ) -> None:
+        _check_common_era(year)
+        self._nanos = _check_nanos(nanosecond)
         self._py_dt = _resolve_ambiguity(
             _datetime(
                 year,
@@ -4282,10 +5655,8 @@ class ZonedDateTime(_KnowsInstantAndLocal):
             ),
             zone,
             disambiguate,
+            self._nanos,
         )
-        if nanosecond < 0 or nanosecond >= 1_000_000_000:
-            raise ValueError(f"nanosecond out of range: {nanosecond}")
-        self._nanos = nanosecond
 
     @classmethod
     def now(cls, tz: str, /) -> ZonedDateTime:
@@ -4439,6 +5810,7 @@ class ZonedDateTime(_KnowsInstantAndLocal):
                 self._py_dt.tzinfo,  # type: ignore[arg-type]
                 # mypy doesn't know that offset is never None here
                 disambiguate or self._py_dt.utcoffset(),  # type: ignore[arg-type]
+                self._nanos,
             ),
             self._nanos,
         )
@@ -4459,6 +5831,7 @@ class ZonedDateTime(_KnowsInstantAndLocal):
                 self._py_dt.tzinfo,  # type: ignore[arg-type]
                 # mypy doesn't know that offset is never None here
                 disambiguate or self._py_dt.utcoffset(),  # type: ignore[arg-type]
+                time._nanos,
             ),
             time._nanos,
         )
@@ -4490,6 +5863,7 @@ class ZonedDateTime(_KnowsInstantAndLocal):
             kwargs["tzinfo"] = zoneinfo_new = ZoneInfo(tz)
             if zoneinfo_new is not self._py_dt.tzinfo:
                 disambiguate = disambiguate or "compatible"
+        _check_common_era(kwargs.get("year"))
         nanos = _pop_nanos_kwarg(kwargs, self._nanos)
 
         return self._from_py_unchecked(
@@ -4498,6 +5872,7 @@ class ZonedDateTime(_KnowsInstantAndLocal):
                 kwargs.get("tzinfo", self._py_dt.tzinfo),
                 # mypy doesn't know that offset is never None here
                 disambiguate or self._py_dt.utcoffset(),  # type: ignore[arg-type]
+                nanos,
             ),
             nanos,
         )
@@ -4648,6 +6023,21 @@ class ZonedDateTime(_KnowsInstantAndLocal):
             nanoseconds=nanoseconds,
         )
 
+    def in_utc(self) -> OffsetDateTime:
+        """Convert to an OffsetDateTime in UTC, representing
+        the same moment in time.
+
+        Equivalent to ``to_fixed_offset(0)``.
+
+        Example
+        -------
+        >>> ZonedDateTime(2020, 8, 15, 23, tz="Europe/London").in_utc()
+        OffsetDateTime(2020-08-15 22:00:00+00:00)
+        """
+        return OffsetDateTime._from_py_unchecked(
+            self._py_dt.astimezone(_UTC), self._nanos
+        )
+
     def is_ambiguous(self) -> bool:
         """Whether the local time is ambiguous, e.g. due to a DST transition.
 
@@ -4681,17 +6071,40 @@ class ZonedDateTime(_KnowsInstantAndLocal):
             next_midnight.astimezone(_UTC) - midnight.astimezone(_UTC)
         )
 
-    def start_of_day(self) -> ZonedDateTime:
+    def start_of_day(
+        self, *, disambiguate: Disambiguate = "compatible"
+    ) -> ZonedDateTime:
         """The start of the current calendar day.
 
         This is almost always at midnight the same day, but may be different
         for timezones which transition at—and thus skip over—midnight.
+
+        The ``disambiguate`` argument determines how a skipped or repeated
+        midnight is handled. It defaults to ``"compatible"``, which
+        moves a skipped midnight forward to the end of the gap
+        (e.g. 01:00), and picks the first of two repeated midnights.
+        Note that ``"earlier"`` resolves a skipped midnight to
+        a time on the *previous* day.
+        See :ref:`ambiguity <ambiguity>` for more information.
+
+        Example
+        -------
+        >>> d = ZonedDateTime(2018, 11, 4, 12, tz="America/Sao_Paulo")
+        >>> d.start_of_day()
+        ZonedDateTime(2018-11-04 01:00:00-02:00[America/Sao_Paulo])
+        >>> d.start_of_day(disambiguate="earlier")
+        ZonedDateTime(2018-11-03 23:00:00-03:00[America/Sao_Paulo])
         """
-        midnight = _datetime.combine(
-            self._py_dt.date(), _time(), self._py_dt.tzinfo
-        )
         return self._from_py_unchecked(
-            midnight.astimezone(_UTC).astimezone(self._py_dt.tzinfo), 0
+            _resolve_ambiguity(
+                _datetime.combine(
+                    self._py_dt.date(), _time(), self._py_dt.tzinfo
+                ),
+                # mypy doesn't know that tzinfo is always a ZoneInfo here
+                self._py_dt.tzinfo,  # type: ignore[arg-type]
+                disambiguate,
+            ),
+            0,
         )
 
     def round(
@@ -4709,6 +6122,8 @@ class ZonedDateTime(_KnowsInstantAndLocal):
         mode: Literal[
             "ceil", "floor", "half_ceil", "half_floor", "half_even"
         ] = "half_even",
+        *,
+        disambiguate: Disambiguate | None = None,
     ) -> ZonedDateTime:
         """Round the datetime to the specified unit and increment.
         Different rounding modes are available.
@@ -4723,8 +6138,13 @@ class ZonedDateTime(_KnowsInstantAndLocal):
 
         Notes
         -----
-        * In the rare case that rounding results in an ambiguous time,
-          the offset is preserved if possible.
+        * Rounding is done on the local (wall clock) time, not the exact time.
+          Around a DST transition, the rounded time may therefore not be
+          the nearest in exact time.
+        * In the rare case that rounding results in an ambiguous
+          or non-existent time, it is resolved according to
+          the ``disambiguate`` argument.
+          If not given, the offset is preserved if possible.
           Otherwise, the time is resolved according to the "compatible" strategy.
         * Rounding in "day" mode may be affected by DST transitions.
           i.e. on 23-hour days, 11:31 AM is rounded up.
@@ -4741,9 +6161,15 @@ class ZonedDateTime(_KnowsInstantAndLocal):
             increment_ns, mode, day_ns
         )
         return self._from_py_unchecked(
-            _resolve_ambiguity_using_prev_offset(
+            _resolve_ambiguity(
                 rounded_local._py_dt.replace(tzinfo=self._py_dt.tzinfo),
-                self._py_dt.utcoffset(),  # type: ignore[arg-type]
+                self._py_dt.tzinfo,  # type: ignore[arg-type]
+                (
+                    self._py_dt.utcoffset()  # type: ignore[arg-type]
+                    if disambiguate is None
+                    else disambiguate
+                ),
+                rounded_local._nanos,
             ),
             rounded_local._nanos,
         )
@@ -4757,7 +6183,8 @@ class ZonedDateTime(_KnowsInstantAndLocal):
             _unpkl_zoned,
             (
                 pack(
-                    "<HBBBBBIl",
+                    "<BHBBBBBIl",
+                    _PICKLE_VERSION,
                     *self._py_dt.timetuple()[:6],
                     self._nanos,
                     int(self._py_dt.utcoffset().total_seconds()),  # type: ignore[union-attr]
@@ -4775,7 +6202,9 @@ def _unpkl_zoned(
     data: bytes,
     tz: str,
 ) -> ZonedDateTime:
-    *args, nanos, offset_secs = unpack("<HBBBBBIl", data)
+    *args, nanos, offset_secs = unpack(
+        "<HBBBBBIl", _strip_pickle_version(data, 15)
+    )
     args += (0, ZoneInfo(tz))
     return ZonedDateTime._from_py_unchecked(
         _adjust_fold_to_offset(
@@ -4823,6 +6252,8 @@ class SystemDateTime(_KnowsInstantAndLocal):
         nanosecond: int = 0,
         disambiguate: Disambiguate = "compatible",
     ) -> None:
+        _check_common_era(year)
+        self._nanos = _check_nanos(nanosecond)
         self._py_dt = _resolve_system_ambiguity(
             _datetime(
                 year,
@@ -4834,10 +6265,8 @@ class SystemDateTime(_KnowsInstantAndLocal):
                 0,
             ),
             disambiguate,
+            self._nanos,
         )
-        if nanosecond < 0 or nanosecond >= 1_000_000_000:
-            raise ValueError("nanosecond out of range")
-        self._nanos = nanosecond
 
     @classmethod
     def now(cls) -> SystemDateTime:
@@ -4934,6 +6363,7 @@ class SystemDateTime(_KnowsInstantAndLocal):
                 _datetime.combine(date._py_date, self._py_dt.time()),
                 # mypy doesn't know that offset is never None here
                 disambiguate or self._py_dt.utcoffset(),  # type: ignore[arg-type]
+                self._nanos,
             ),
             self._nanos,
         )
@@ -4950,6 +6380,7 @@ class SystemDateTime(_KnowsInstantAndLocal):
                 _datetime.combine(self._py_dt, time._py_time),
                 # mypy doesn't know that offset is never None here
                 disambiguate or self._py_dt.utcoffset(),  # type: ignore[arg-type]
+                time._nanos,
             ),
             time._nanos,
         )
@@ -4969,12 +6400,14 @@ class SystemDateTime(_KnowsInstantAndLocal):
         for more information.
         """
         _check_invalid_replace_kwargs(kwargs)
+        _check_common_era(kwargs.get("year"))
         nanos = _pop_nanos_kwarg(kwargs, self._nanos)
         return self._from_py_unchecked(
             _resolve_system_ambiguity(
                 self._py_dt.replace(tzinfo=None, **kwargs),
                 # mypy doesn't know that offset is never None here
                 disambiguate or self._py_dt.utcoffset(),  # type: ignore[arg-type]
+                nanos,
             ),
             nanos,
         )
@@ -5234,7 +6667,8 @@ class SystemDateTime(_KnowsInstantAndLocal):
             _unpkl_system,
             (
                 pack(
-                    "<HBBBBBIl",
+                    "<BHBBBBBIl",
+                    _PICKLE_VERSION,
                     *self._py_dt.timetuple()[:6],
                     self._nanos,
                     int(self._py_dt.utcoffset().total_seconds()),  # type: ignore[union-attr]
@@ -5248,7 +6682,9 @@ class SystemDateTime(_KnowsInstantAndLocal):
 # required by __reduce__.
 # Also, it allows backwards-compatible changes to the pickling format.
 def _unpkl_system(data: bytes) -> SystemDateTime:
-    *args, nanos, offset_secs = unpack("<HBBBBBIl", data)
+    *args, nanos, offset_secs = unpack(
+        "<HBBBBBIl", _strip_pickle_version(data, 15)
+    )
     args += (0, _timezone(_timedelta(seconds=offset_secs)))
     return SystemDateTime._from_py_unchecked(_datetime(*args), nanos)
 
@@ -5283,8 +6719,9 @@ class LocalDateTime(_KnowsLocal):
         *,
         nanosecond: int = 0,
     ) -> None:
+        _check_common_era(year)
         self._py_dt = _datetime(year, month, day, hour, minute, second)
-        self._nanos = nanosecond
+        self._nanos = _check_nanos(nanosecond)
 
     def format_common_iso(self) -> str:
         """Convert to the popular ISO format ``YYYY-MM-DDTHH:MM:SS``
@@ -5328,15 +6765,146 @@ class LocalDateTime(_KnowsLocal):
             _strip_subclasses(d.replace(microsecond=0)), d.microsecond * 1_000
         )
 
+    @classmethod
+    def combine(cls, date: Date, time: Time, /) -> LocalDateTime:
+        """Create an instance from a date and a time.
+        Equivalent to ``date.at(time)``.
+
+        Example
+        -------
+        >>> LocalDateTime.combine(Date(2021, 1, 2), Time(12, 30))
+        LocalDateTime(2021-01-02 12:30:00)
+        """
+        if type(date) is not Date:
+            raise TypeError("date must be a whenever.Date instance")
+        if type(time) is not Time:
+            raise TypeError("time must be a whenever.Time instance")
+        return cls._from_py_unchecked(
+            _datetime.combine(date._py_date, time._py_time), time._nanos
+        )
+
+    @classmethod
+    def from_timestamp(
+        cls, i: int | float, /, *, tz: str, nanosecond: int = 0
+    ) -> LocalDateTime:
+        """Create an instance from a UNIX timestamp (in seconds),
+        as it would appear on the wall clock in the given timezone.
+
+        The timezone's offset at that moment (including DST) is applied,
+        after which the timezone is discarded.
+        The optional ``nanosecond`` is added to the timestamp,
+        for sub-second precision without the rounding of a float.
+
+        Example
+        -------
+        >>> LocalDateTime.from_timestamp(1_597_493_520, tz="Europe/Amsterdam")
+        LocalDateTime(2020-08-15 14:12:00)
+        >>> LocalDateTime.from_timestamp(0, tz="Asia/Tokyo", nanosecond=5)
+        LocalDateTime(1970-01-01 09:00:00.000000005)
+        """
+        if type(nanosecond) is not int:
+            raise TypeError("nanosecond must be an integer")
+        _check_nanos(nanosecond)
+        zoned = ZonedDateTime.from_timestamp(i, tz=tz)
+        if nanosecond:
+            try:
+                zoned = zoned + TimeDelta(nanoseconds=nanosecond)
+            except (ValueError, OverflowError):
+                raise ValueError("timestamp is out of range")
+        return zoned.local()
+
     def replace(self, /, **kwargs: Any) -> LocalDateTime:
         """Construct a new instance with the given fields replaced."""
         if not _no_tzinfo_fold_or_ms(kwargs):
             raise TypeError(
                 "tzinfo, fold, or microsecond are not allowed arguments"
             )
+        _check_common_era(kwargs.get("year"))
         nanos = _pop_nanos_kwarg(kwargs, self._nanos)
         return self._from_py_unchecked(self._py_dt.replace(**kwargs), nanos)
 
+    def as_tuple(self) -> tuple[int, int, int, int, int, int, int]:
+        """Convert to a tuple of
+        (year, month, day, hour, minute, second, nanosecond).
+
+        Example
+        -------
+        >>> LocalDateTime(2020, 8, 15, 23, 12, nanosecond=5).as_tuple()
+        (2020, 8, 15, 23, 12, 0, 5)
+        """
+        d = self._py_dt
+        return (
+            d.year,
+            d.month,
+            d.day,
+            d.hour,
+            d.minute,
+            d.second,
+            self._nanos,
+        )
+
+    def iso_year_week_day(self) -> tuple[int, int, int, int, int, int, int]:
+        """Convert to a tuple of (ISO year, ISO week, ISO weekday,
+        hour, minute, second, nanosecond).
+
+        The weekday counts from 1 (Monday) to 7 (Sunday).
+        Note that near New Year, the ISO year may differ
+        from the calendar year.
+        Inverse of :meth:`from_iso_week_datetime`.
+
+        Example
+        -------
+        >>> LocalDateTime(2021, 1, 2, 23, 12).iso_year_week_day()
+        (2020, 53, 6, 23, 12, 0, 0)
+        """
+        d = self._py_dt
+        year, week, weekday = d.isocalendar()
+        return (year, week, weekday, d.hour, d.minute, d.second, self._nanos)
+
+    @classmethod
+    def from_iso_week_datetime(
+        cls,
+        year: int,
+        week: int,
+        weekday: int,
+        hour: int = 0,
+        minute: int = 0,
+        second: int = 0,
+        *,
+        nanosecond: int = 0,
+    ) -> LocalDateTime:
+        """Create from an ISO 8601 week date and a time of day.
+
+        The weekday counts from 1 (Monday) to 7 (Sunday).
+        Inverse of :meth:`iso_year_week_day`.
+
+        Example
+        -------
+        >>> LocalDateTime.from_iso_week_datetime(2020, 53, 6, 23, 12)
+        LocalDateTime(2021-01-02 23:12:00)
+        """
+        _check_common_era(year)
+        if year > _date.max.year:
+            raise ValueError("year must be in 1..9999")
+        weeks = _iso_weeks_in(year)
+        if not 1 <= week <= weeks:
+            raise ValueError(f"week must be in 1..{weeks} for year {year}")
+        if not 1 <= weekday <= 7:
+            raise ValueError("weekday must be in 1..7")
+        try:
+            d = _date.fromisocalendar(year, week, weekday)
+        except ValueError:
+            raise ValueError("Resulting date out of range")
+        return cls(
+            d.year,
+            d.month,
+            d.day,
+            hour,
+            minute,
+            second,
+            nanosecond=nanosecond,
+        )
+
     def replace_date(self, d: Date, /) -> LocalDateTime:
         """Construct a new instance with the date replaced."""
         return self._from_py_unchecked(
@@ -5492,6 +7060,40 @@ class LocalDateTime(_KnowsLocal):
         """
         return self._shift(-1, *args, **kwargs)
 
+    def add_days(self, n: int, /) -> LocalDateTime:
+        """Add a number of days, keeping the time of day the same.
+        Equivalent to ``add(days=n)``.
+
+        Example
+        -------
+        >>> LocalDateTime(2020, 2, 28, 12).add_days(2)
+        LocalDateTime(2020-03-01 12:00:00)
+        """
+        if not isinstance(n, int):
+            raise TypeError("days must be an integer")
+        try:
+            return self.replace_date(self.date()._add_days(n))
+        except (ValueError, OverflowError):
+            raise ValueError("Resulting datetime out of range")
+
+    def add_months(self, n: int, /) -> LocalDateTime:
+        """Add a number of months, keeping the time of day the same.
+        Equivalent to ``add(months=n)``.
+        If the day doesn't exist in the resulting month,
+        it's clamped to the last day of that month.
+
+        Example
+        -------
+        >>> LocalDateTime(2020, 1, 31, 12).add_months(1)
+        LocalDateTime(2020-02-29 12:00:00)
+        """
+        if not isinstance(n, int):
+            raise TypeError("months must be an integer")
+        try:
+            return self.replace_date(self.date()._add_months(n))
+        except (ValueError, OverflowError):
+            raise ValueError("Resulting datetime out of range")
+
     @no_type_check
     def _shift(
         self,
@@ -5585,6 +7187,31 @@ class LocalDateTime(_KnowsLocal):
             parsed.replace(microsecond=0), parsed.microsecond * 1_000
         )
 
+    def __format__(self, spec: str) -> str:
+        """Format with ``strftime`` directives, e.g. in f-strings.
+        An empty format spec gives the same result as ``str()``.
+
+        Example
+        -------
+        >>> d = LocalDateTime(2020, 8, 15, 23, 12)
+        >>> f"{d:%Y-%m-%d %H:%M}"
+        '2020-08-15 23:12'
+        >>> f"{d}"
+        '2020-08-15T23:12:00'
+
+        Note
+        ----
+        Only the directives supported on all platforms are allowed.
+        Excluded are the locale's full formats (``%c %x %X``),
+        and the offset and timezone (``%z %Z``) which a local datetime
+        doesn't have.
+        Sub-microsecond precision is truncated by ``%f``.
+        """
+        if not spec:
+            return str(self)
+        _check_strftime_spec(spec)
+        return self.py_datetime().strftime(spec)
+
     def assume_utc(self) -> Instant:
         """Assume the datetime is in UTC, creating an ``Instant``.
 
@@ -5611,6 +7238,25 @@ class LocalDateTime(_KnowsLocal):
             self._py_dt.replace(tzinfo=_load_offset(offset)), self._nanos
         )
 
+    def to_instant(self, offset: int | TimeDelta, /) -> Instant:
+        """Get the ``Instant`` for this datetime at the given offset.
+
+        Equivalent to ``assume_fixed_offset(offset).to_instant()``,
+        but without creating an intermediate ``OffsetDateTime``.
+
+        Example
+        -------
+        >>> LocalDateTime(2020, 8, 15, 23, 12).to_instant(+2)
+        Instant(2020-08-15 21:12:00Z)
+        """
+        try:
+            py_dt = self._py_dt.replace(
+                tzinfo=_load_offset(offset)
+            ).astimezone(_UTC)
+        except OverflowError:
+            raise ValueError("Datetime out of range")
+        return Instant._from_py_unchecked(py_dt, self._nanos)
+
     def assume_tz(
         self, tz: str, /, disambiguate: Disambiguate = "compatible"
     ) -> ZonedDateTime:
@@ -5636,6 +7282,7 @@ class LocalDateTime(_KnowsLocal):
                 self._py_dt.replace(tzinfo=(zone := ZoneInfo(tz))),
                 zone,
                 disambiguate,
+                self._nanos,
             ),
             self._nanos,
         )
@@ -5662,10 +7309,54 @@ class LocalDateTime(_KnowsLocal):
         SystemDateTime(2020-08-15 23:12:00-04:00)
         """
         return SystemDateTime._from_py_unchecked(
-            _resolve_system_ambiguity(self._py_dt, disambiguate),
+            _resolve_system_ambiguity(
+                self._py_dt, disambiguate, self._nanos
+            ),
+            self._nanos,
+        )
+
+    def to_system_offset(
+        self, *, disambiguate: Disambiguate = "compatible"
+    ) -> OffsetDateTime:
+        """Assume the datetime is in the system timezone,
+        and return it as an ``OffsetDateTime`` with the resulting offset.
+
+        Equivalent to ``assume_system_tz(...).to_fixed_offset()``.
+        Ambiguity is handled the same way as in :meth:`assume_system_tz`.
+
+        Example
+        -------
+        >>> d = LocalDateTime(2020, 8, 15, 23, 12)
+        >>> # assuming system timezone is America/New_York
+        >>> d.to_system_offset(disambiguate="raise")
+        OffsetDateTime(2020-08-15 23:12:00-04:00)
+        """
+        dt = _resolve_system_ambiguity(self._py_dt, disambiguate, self._nanos)
+        return OffsetDateTime._from_py_unchecked(
+            # mypy doesn't know that offset is never None here
+            dt.replace(tzinfo=_timezone(dt.utcoffset())),  # type: ignore[arg-type]
             self._nanos,
         )
 
+    def is_valid_in_tz(self, tz: str, /) -> bool:
+        """Whether this datetime exists in the given timezone.
+
+        Returns ``False`` if the datetime falls in a gap
+        (e.g. when the clock is set forward for DST).
+        Ambiguous datetimes (in a fold) are considered valid.
+
+        Example
+        -------
+        >>> d = LocalDateTime(2023, 3, 26, 2, 15)
+        >>> d.is_valid_in_tz("Europe/Amsterdam")
+        False
+        >>> d.is_valid_in_tz("America/New_York")
+        True
+        """
+        dt = self._py_dt.replace(tzinfo=ZoneInfo(tz))
+        # In a gap, the offset before the transition is smaller
+        return dt.utcoffset() >= dt.replace(fold=1).utcoffset()  # type: ignore
+
     def round(
         self,
         unit: Literal[
@@ -5719,7 +7410,14 @@ class LocalDateTime(_KnowsLocal):
     def __reduce__(self) -> tuple[object, ...]:
         return (
             _unpkl_local,
-            (pack("<HBBBBBI", *self._py_dt.timetuple()[:6], self._nanos),),
+            (
+                pack(
+                    "<BHBBBBBI",
+                    _PICKLE_VERSION,
+                    *self._py_dt.timetuple()[:6],
+                    self._nanos,
+                ),
+            ),
         )
 
 
@@ -5727,50 +7425,137 @@ class LocalDateTime(_KnowsLocal):
 # to the pickling format in the future
 @no_type_check
 def _unpkl_local(data: bytes) -> LocalDateTime:
-    *args, nanos = unpack("<HBBBBBI", data)
+    *args, nanos = unpack("<HBBBBBI", _strip_pickle_version(data, 11))
     return LocalDateTime._from_py_unchecked(_datetime(*args), nanos)
 
 
 class RepeatedTime(Exception):
-    """A datetime is repeated in a timezone, e.g. because of DST"""
+    """A datetime is repeated in a timezone, e.g. because of DST
+
+    The repeated ``date`` and ``time`` are available as attributes,
+    as well as the ``tz`` key (``None`` for the system timezone).
+    """
+
+    date: Date
+    time: Time
+    tz: str | None
 
     @classmethod
-    def _for_tz(cls, d: _datetime, tz: ZoneInfo) -> RepeatedTime:
-        return cls(
-            f"{d.replace(tzinfo=None)} is repeated " f"in timezone {tz.key!r}"
-        )
+    def _for_tz(
+        cls, d: _datetime, nanos: int, tz: ZoneInfo
+    ) -> RepeatedTime:
+        return _dst_error(cls, "is repeated", d, nanos, tz.key)
 
     @classmethod
-    def _for_system_tz(cls, d: _datetime) -> RepeatedTime:
-        return cls(
-            f"{d.replace(tzinfo=None)} is repeated in the system timezone"
-        )
+    def _for_system_tz(cls, d: _datetime, nanos: int) -> RepeatedTime:
+        return _dst_error(cls, "is repeated", d, nanos, None)
 
 
 class SkippedTime(Exception):
-    """A datetime is skipped in a timezone, e.g. because of DST"""
+    """A datetime is skipped in a timezone, e.g. because of DST
+
+    The skipped ``date`` and ``time`` are available as attributes,
+    as well as the ``tz`` key (``None`` for the system timezone).
+    """
+
+    date: Date
+    time: Time
+    tz: str | None
 
     @classmethod
-    def _for_tz(cls, d: _datetime, tz: ZoneInfo) -> SkippedTime:
-        return cls(
-            f"{d.replace(tzinfo=None)} is skipped " f"in timezone {tz.key!r}"
-        )
+    def _for_tz(
+        cls, d: _datetime, nanos: int, tz: ZoneInfo
+    ) -> SkippedTime:
+        return _dst_error(cls, "is skipped", d, nanos, tz.key)
 
     @classmethod
-    def _for_system_tz(cls, d: _datetime) -> SkippedTime:
-        return cls(
-            f"{d.replace(tzinfo=None)} is skipped in the system timezone"
-        )
+    def _for_system_tz(cls, d: _datetime, nanos: int) -> SkippedTime:
+        return _dst_error(cls, "is skipped", d, nanos, None)
+
+
+_DSTError = TypeVar("_DSTError", RepeatedTime, SkippedTime)
+
+
+def _dst_error(
+    cls: type[_DSTError],
+    what: str,
+    d: _datetime,
+    nanos: int,
+    tz: str | None,
+) -> _DSTError:
+    date = Date._from_py_unchecked(d.date())
+    time = Time._from_py_unchecked(d.time().replace(fold=0), nanos)
+    exc = cls(
+        f"{date} {time} {what} "
+        + (f"in timezone {tz!r}" if tz else "in the system timezone")
+    )
+    exc.date = date
+    exc.time = time
+    exc.tz = tz
+    return exc
 
 
 class InvalidOffset(ValueError):
     """A string has an invalid offset for the given zone"""
 
 
+class UnsupportedYear(ValueError):
+    """A date before year 1 of the common era was requested.
+
+    Only the proleptic Gregorian years 1-9999 can be represented.
+    This is a deliberate limitation, not a bug.
+    """
+
+
 class ImplicitlyIgnoringDST(TypeError):
     """A calculation was performed that implicitly ignored DST"""
 
 
+def _leap_days_before(d: _date) -> int:
+    # The number of February 29ths strictly before the given date
+    y = d.year - 1
+    return y // 4 - y // 100 + y // 400 + (isleap(d.year) and d.month > 2)
+
+
+def _iso_weeks_in(year: int) -> int:
+    # A year has 53 ISO weeks if it starts on a Thursday,
+    # or if it's a leap year starting on a Wednesday
+    jan1 = _date(year, 1, 1).weekday()
+    return 52 + (jan1 == 3 or (jan1 == 2 and isleap(year)))
+
+
+def _iter_months(
+    start: Date, end: Date, step: int, inclusive: bool
+) -> Iterator[Date]:
+    # Each date is shifted from the start (not from the previous date),
+    # so the day is clamped per month without drifting.
+    for n in range(
+        start.proleptic_month(),
+        # one past the min/max proleptic month
+        _date.max.year * 12 + 12 if step > 0 else 11,
+        step,
+    ):
+        year, month = divmod(n, 12)
+        d = Date._from_py_unchecked(
+            _date(
+                year,
+                month + 1,
+                min(start.day, monthrange(year, month + 1)[1]),
+            )
+        )
+        if (d > end if step > 0 else d < end) or (d == end and not inclusive):
+            return
+        yield d
+
+
+def _check_common_era(year: object) -> None:
+    if isinstance(year, int) and year < 1:
+        raise UnsupportedYear(
+            f"Year {year} is not supported: only years 1-9999 "
+            "of the common era can be represented"
+        )
+
+
 _IGNORE_DST_SUGGESTION = (
     "To perform DST-safe operations, convert to a ZonedDateTime first. "
     "Or, if you don't know the timezone and accept potentially incorrect results "
@@ -5785,6 +7570,12 @@ SHIFT_LOCAL_MSG = (
     "changes. Use the `add` or `subtract` method instead."
 )
 
+SHIFT_DATE_MSG = (
+    "A Date has no time component, so it can't be shifted by a TimeDelta. "
+    "Use `.at(time)` to create a LocalDateTime first, "
+    "or use a DateDelta to shift by days."
+)
+
 DIFF_OPERATOR_LOCAL_MSG = (
     "The difference between two local datetimes implicitly ignores "
     "DST transitions and other timezone changes. "
@@ -5837,13 +7628,16 @@ ADJUST_LOCAL_DATETIME_MSG = (
 
 CANNOT_ROUND_DAY_MSG = (
     "Cannot round to day, because days do not have a fixed length. "
-    "Due to daylight saving time, some days have 23 or 25 hours."
-    "If you wish to round to exaxtly 24 hours, use `round('hour', increment=24)`."
+    "Due to daylight saving time, some days have 23 or 25 hours. "
+    "If you wish to round to exactly 24 hours, use `round('hour', increment=24)`."
 )
 
 
 def _resolve_ambiguity(
-    dt: _datetime, zone: ZoneInfo, disambiguate: Disambiguate | _timedelta
+    dt: _datetime,
+    zone: ZoneInfo,
+    disambiguate: Disambiguate | _timedelta,
+    nanos: int = 0,
 ) -> _datetime:
     if isinstance(disambiguate, _timedelta):
         return _resolve_ambiguity_using_prev_offset(dt, disambiguate)
@@ -5852,7 +7646,7 @@ def _resolve_ambiguity(
     # Non-existent times: they don't survive a UTC roundtrip
     if dt_utc.astimezone(zone) != dt:
         if disambiguate == "raise":
-            raise SkippedTime._for_tz(dt, zone)
+            raise SkippedTime._for_tz(dt, nanos, zone)
         elif disambiguate != "compatible":  # i.e. "earlier" or "later"
             # In gaps, the relationship between
             # fold and earlier/later is reversed
@@ -5861,7 +7655,7 @@ def _resolve_ambiguity(
         dt = dt.astimezone(_UTC).astimezone(zone)
     # Ambiguous times: they're never equal to other timezones
     elif disambiguate == "raise" and dt_utc != dt:
-        raise RepeatedTime._for_tz(dt, zone)
+        raise RepeatedTime._for_tz(dt, nanos, zone)
     return dt
 
 
@@ -5890,7 +7684,7 @@ _requires_flip: Callable[[Disambiguate], bool] = (
 
 # FUTURE: document that this isn't threadsafe (system tz may change)
 def _resolve_system_ambiguity(
-    dt: _datetime, disambiguate: Disambiguate | _timedelta
+    dt: _datetime, disambiguate: Disambiguate | _timedelta, nanos: int = 0
 ) -> _datetime:
     assert dt.tzinfo is None
     if isinstance(disambiguate, _timedelta):
@@ -5900,7 +7694,7 @@ def _resolve_system_ambiguity(
     # Non-existent times: they don't survive a UTC roundtrip
     if norm.replace(tzinfo=None) != dt:
         if disambiguate == "raise":
-            raise SkippedTime._for_system_tz(dt)
+            raise SkippedTime._for_system_tz(dt, nanos)
         elif _requires_flip(disambiguate):
             dt = dt.replace(fold=not dt.fold)
         # perform the normalisation, shifting away from non-existent times
@@ -5909,7 +7703,7 @@ def _resolve_system_ambiguity(
     elif disambiguate == "raise" and norm != dt.replace(fold=1).astimezone(
         _UTC
     ):
-        raise RepeatedTime._for_system_tz(dt)
+        raise RepeatedTime._for_system_tz(dt, nanos)
     return norm
 
 
@@ -5938,10 +7732,14 @@ def _resolve_system_ambiguity_using_prev_offset(
 
 def _load_offset(offset: int | TimeDelta, /) -> _timezone:
     if isinstance(offset, int):
+        if not -24 < offset < 24:
+            raise ValueError("offset must be strictly between -24 and 24 hours")
         return _timezone(_timedelta(hours=offset))
     elif isinstance(offset, TimeDelta):
         if offset._total_ns % 1_000_000_000:
             raise ValueError("Offset must be a whole number of seconds")
+        if abs(offset._total_ns) >= 24 * 3_600_000_000_000:
+            raise ValueError("offset must be strictly between -24 and 24 hours")
         return _timezone(offset.py_timedelta())
     else:
         raise TypeError(
@@ -5985,6 +7783,9 @@ _match_next_timedelta_component = re.compile(
 _match_next_datedelta_component = re.compile(
     r"^(\d{1,8})([YMWD])", re.ASCII
 ).match
+_match_date = re.compile(r"\d{4}-\d{2}-\d{2}", re.ASCII).fullmatch
+_match_week_date = re.compile(r"(\d{4})-W(\d{2})-(\d)", re.ASCII).fullmatch
+_match_ordinal_date = re.compile(r"(\d{4})-(\d{3})", re.ASCII).fullmatch
 _match_yearmonth = re.compile(r"\d{4}-\d{2}", re.ASCII).fullmatch
 _match_monthday = re.compile(r"--\d{2}-\d{2}", re.ASCII).fullmatch
 
@@ -5997,6 +7798,16 @@ def _check_utc_bounds(dt: _datetime) -> _datetime:
     return dt
 
 
+_STRFTIME_DIRECTIVES = frozenset("aAwdbBmyYHIpMSfjUWGuV%")
+
+
+def _check_strftime_spec(spec: str) -> None:
+    chars = iter(spec)
+    for c in chars:
+        if c == "%" and next(chars, None) not in _STRFTIME_DIRECTIVES:
+            raise ValueError(f"Invalid format directive in {spec!r}")
+
+
 def _check_invalid_replace_kwargs(kwargs: Any) -> None:
     if not _no_tzinfo_fold_or_ms(kwargs):
         raise TypeError(
@@ -6004,13 +7815,25 @@ def _check_invalid_replace_kwargs(kwargs: Any) -> None:
         )
 
 
+def _unit_to_nanos(value: float, factor: int, name: str) -> int:
+    if isinstance(value, int):
+        return value * factor
+    elif not isinstance(value, float):
+        raise TypeError(f"{name} must be an integer or float")
+    elif not isfinite(value):
+        raise ValueError(f"{name} out of range")
+    # The shortest decimal representation avoids binary float artifacts
+    ns = Fraction(repr(value)) * factor
+    if ns.denominator != 1:
+        raise ValueError(f"{name} must be a whole number of nanoseconds")
+    return int(ns)
+
+
 def _pop_nanos_kwarg(kwargs: Any, default: int) -> int:
     nanos = kwargs.pop("nanosecond", default)
     if type(nanos) is not int:
         raise TypeError("nanosecond must be an int")
-    elif not 0 <= nanos < 1_000_000_000:
-        raise ValueError("Invalid nanosecond value")
-    return nanos
+    return _check_nanos(nanos)
 
 
 # Use this to strip any incoming datetime classes down to instances
@@ -6071,10 +7894,19 @@ def _adjust_fold_to_offset(dt: _datetime, offset: _timedelta) -> _datetime:
     if offset != dt.utcoffset():  # offset/zone mismatch: try other fold
         dt = dt.replace(fold=1)
         if dt.utcoffset() != offset:
-            raise InvalidOffset()
+            raise _invalid_offset_for(dt)
+    # Skipped times don't survive a UTC roundtrip
+    if dt.astimezone(_UTC).astimezone(dt.tzinfo) != dt:
+        raise _invalid_offset_for(dt)
     return dt
 
 
+def _invalid_offset_for(dt: _datetime) -> InvalidOffset:
+    return InvalidOffset(
+        f"Invalid offset for timezone {dt.tzinfo.key}"  # type: ignore[union-attr]
+    )
+
+
 def _as_fold(s: str) -> Fold:
     try:
         return _disambiguate_to_fold[s]
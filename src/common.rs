@@ -1,4 +1,4 @@
-use core::ffi::{c_char, c_long, CStr};
+use core::ffi::{c_char, c_int, c_long, CStr};
 use core::mem;
 use core::ptr::null_mut as NULL;
 use pyo3_ffi::*;
@@ -408,6 +408,7 @@ pub(crate) trait PyObjectExt {
     unsafe fn to_str<'a>(self) -> PyResult<Option<&'a str>>;
     unsafe fn to_long(self) -> PyResult<Option<c_long>>;
     unsafe fn to_i64(self) -> PyResult<Option<i64>>;
+    unsafe fn to_i64_saturating(self) -> PyResult<Option<i64>>;
     unsafe fn to_i128(self) -> PyResult<Option<i128>>;
     unsafe fn to_f64(self) -> PyResult<Option<f64>>;
     unsafe fn repr(self) -> String;
@@ -491,6 +492,22 @@ impl PyObjectExt for *mut PyObject {
         Ok(Some(x))
     }
 
+    // Like to_i64(), but ints beyond the i64 range are clamped
+    // instead of raising OverflowError
+    unsafe fn to_i64_saturating(self) -> PyResult<Option<i64>> {
+        if PyLong_Check(self) == 0 {
+            return Ok(None);
+        }
+        let mut overflow: c_int = 0;
+        let x = PyLong_AsLongLongAndOverflow(self, &mut overflow);
+        match overflow {
+            0 if x == -1 && !PyErr_Occurred().is_null() => Err(PyErrOccurred()),
+            0 => Ok(Some(x)),
+            n if n > 0 => Ok(Some(i64::MAX)),
+            _ => Ok(Some(i64::MIN)),
+        }
+    }
+
     unsafe fn to_i128(self) -> PyResult<Option<i128>> {
         if PyLong_Check(self) == 0 {
             return Ok(None);
@@ -679,6 +696,12 @@ impl<T, U, V, W, X> ToPy for (T, U, V, W, X) {
     }
 }
 
+impl<T, U, V, W, X, Y, Z> ToPy for (T, U, V, W, X, Y, Z) {
+    unsafe fn to_py(self) -> PyReturn {
+        PyTuple_Pack(7, self.0, self.1, self.2, self.3, self.4, self.5, self.6).as_result()
+    }
+}
+
 pub(crate) unsafe fn identity1(slf: *mut PyObject) -> PyReturn {
     Ok(newref(slf))
 }
@@ -697,6 +720,28 @@ pub(crate) unsafe fn is_none(x: *mut PyObject) -> bool {
     x == Py_None()
 }
 
+// Collect newly created objects into a list.
+// The list (and any items created so far) is released if an item fails.
+pub(crate) unsafe fn to_list<I>(items: I) -> PyReturn
+where
+    I: IntoIterator<Item = PyReturn>,
+{
+    let list = PyList_New(0).as_result()?;
+    for item in items {
+        let Ok(obj) = item else {
+            Py_DECREF(list);
+            return Err(PyErrOccurred());
+        };
+        let result = PyList_Append(list, obj);
+        Py_DECREF(obj);
+        if result == -1 {
+            Py_DECREF(list);
+            return Err(PyErrOccurred());
+        }
+    }
+    Ok(list)
+}
+
 // NOTE: assumes it's an "aware" datetime object
 pub(crate) unsafe fn offset_from_py_dt(dt: *mut PyObject) -> PyResult<i32> {
     let delta = methcall0(dt, "utcoffset")?;
@@ -726,6 +771,23 @@ pub(crate) fn offset_fmt(secs: i32) -> String {
     }
 }
 
+// Pickle data is prefixed with this byte, so that the format
+// can evolve without breaking existing pickles.
+pub(crate) const PICKLE_VERSION: u8 = 1;
+
+// Check the pickle version and size, returning the data after the version byte.
+// Data without a version byte (from older releases) is accepted as-is.
+pub(crate) unsafe fn strip_pickle_version(data: &[u8], size: usize) -> PyResult<&[u8]> {
+    match *data {
+        _ if data.len() == size => Ok(data),
+        [PICKLE_VERSION, ref rest @ ..] if rest.len() == size => Ok(rest),
+        [version, ..] if data.len() == size + 1 => {
+            Err(value_err!("Unsupported pickle version: {}", version))
+        }
+        _ => Err(value_err!("Invalid pickle data")),
+    }
+}
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub(crate) enum Disambiguate {
     Compatible,
@@ -956,6 +1018,11 @@ pub(crate) unsafe fn generic_extract<T: Copy>(obj: *mut PyObject) -> T {
     (*obj.cast::<PyWrap<T>>()).data
 }
 
+#[inline]
+pub(crate) unsafe fn generic_extract_mut<'a, T>(obj: *mut PyObject) -> &'a mut T {
+    &mut (*obj.cast::<PyWrap<T>>()).data
+}
+
 macro_rules! type_spec {
     ($typ:ident, $slots:expr) => {
         pub(crate) static mut SPEC: PyType_Spec = PyType_Spec {
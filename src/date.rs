@@ -1,13 +1,14 @@
 use core::ffi::{c_int, c_long, c_void, CStr};
 use core::{mem, ptr::null_mut as NULL};
 use pyo3_ffi::*;
-use std::fmt::{self, Display, Formatter};
+use std::fmt::{self, Display, Formatter, Write};
 use std::ptr::NonNull;
 
 use crate::common::*;
 use crate::docstrings as doc;
 use crate::{
     date_delta::{handle_init_kwargs as handle_datedelta_kwargs, DateDelta},
+    date_iterator::DateIterator,
     instant::Instant,
     local_datetime::DateTime,
     monthday::MonthDay,
@@ -23,17 +24,44 @@ pub struct Date {
     pub(crate) day: u8,
 }
 
+const WEEKDAY_NAMES: [&str; 7] = [
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+    "Sunday",
+];
+
+const MONTH_NAMES: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+pub(crate) const MIN: Date = Date::new_unchecked(1, 1, 1);
 pub(crate) const MAX: Date = Date::new_unchecked(9999, 12, 31);
 
-pub(crate) const SINGLETONS: &[(&CStr, Date); 2] =
-    &[(c"MIN", Date::new_unchecked(1, 1, 1)), (c"MAX", MAX)];
+pub(crate) const SINGLETONS: &[(&CStr, Date); 2] = &[(c"MIN", MIN), (c"MAX", MAX)];
 
 impl Date {
-    pub(crate) const unsafe fn hash(self) -> i32 {
+    pub(crate) const fn hash(self) -> i32 {
         // Since the data already fits within an i32
         // we don't need to do any extra hashing. It may be counterintuitive,
         // but this is also what `int` does: `hash(6) == 6`.
-        mem::transmute(self)
+        // The fields are packed explicitly so that the hash doesn't
+        // depend on the memory layout of the struct.
+        (self.year as i32) << 9 | (self.month as i32) << 5 | self.day as i32
     }
 
     // OPTIMIZE: Let's use unix days instead of ordinal days since 1-1-1
@@ -43,6 +71,48 @@ impl Date {
             + self.day as u32
     }
 
+    // The number of February 29ths strictly before this date
+    const fn leap_days_before(self) -> u32 {
+        let y = (self.year - 1) as u32;
+        y / 4 - y / 100 + y / 400 + (is_leap(self.year) && self.month > 2) as u32
+    }
+
+    // Format with the date-related strftime directives. Names are always
+    // in English, regardless of the locale. Returns None on an invalid directive.
+    fn strftime(self, spec: &str) -> Option<String> {
+        let Date { year, month, day } = self;
+        let weekday = self.day_of_week_index();
+        let mut s = String::with_capacity(spec.len() * 2);
+        let mut chars = spec.chars();
+        while let Some(c) = chars.next() {
+            if c != '%' {
+                s.push(c);
+                continue;
+            }
+            match chars.next()? {
+                'a' => s.push_str(&WEEKDAY_NAMES[weekday as usize][..3]),
+                'A' => s.push_str(WEEKDAY_NAMES[weekday as usize]),
+                'b' => s.push_str(&MONTH_NAMES[month as usize - 1][..3]),
+                'B' => s.push_str(MONTH_NAMES[month as usize - 1]),
+                'd' => write!(s, "{:02}", day).ok()?,
+                'm' => write!(s, "{:02}", month).ok()?,
+                'y' => write!(s, "{:02}", year % 100).ok()?,
+                'Y' => write!(s, "{:04}", year).ok()?,
+                'j' => write!(s, "{:03}", days_before_month(year, month) + day as u16).ok()?,
+                'w' => write!(s, "{}", (weekday + 1) % 7).ok()?,
+                'u' => write!(s, "{}", weekday + 1).ok()?,
+                '%' => s.push('%'),
+                _ => return None,
+            }
+        }
+        Some(s)
+    }
+
+    // The day of the week, with Monday=0 and Sunday=6
+    pub(crate) const fn day_of_week_index(self) -> u8 {
+        ((self.ord() + 6) % 7) as u8
+    }
+
     pub(crate) fn from_ord(ord: i32) -> Option<Self> {
         (MIN_ORD..=MAX_ORD)
             .contains(&ord)
@@ -95,6 +165,16 @@ impl Date {
         Date::from_ord((self.ord() as i32).checked_add(days)?)
     }
 
+    unsafe fn shift_days_by_int(self, days: *mut PyObject, negate: bool) -> PyResult<Date> {
+        let days = days
+            .to_i64_saturating()?
+            .ok_or_type_err("days must be an integer")?;
+        i32::try_from(days)
+            .ok()
+            .and_then(|d| self.shift_days(if negate { d.checked_neg()? } else { d }))
+            .ok_or_value_err("Resulting date out of range")
+    }
+
     pub(crate) fn shift_months(self, months: i32) -> Option<Date> {
         let month = ((self.month as i32 + months - 1).rem_euclid(12)) as u8 + 1;
         let year = self.year as i32 + (self.month as i32 + months - 1).div_euclid(12);
@@ -133,6 +213,24 @@ impl Date {
         }
     }
 
+    /// Like `from_longs`, but with an error message naming the invalid field.
+    /// Years before the common era raise `UnsupportedYear`.
+    pub(crate) unsafe fn from_longs_or_err(
+        year: c_long,
+        month: c_long,
+        day: c_long,
+        state: &State,
+    ) -> PyResult<Self> {
+        check_common_era(state, year)?;
+        if year > MAX_YEAR {
+            Err(value_err!("year must be in 1..9999"))
+        } else if !(1..=12).contains(&month) {
+            Err(value_err!("month must be in 1..12"))
+        } else {
+            Date::from_longs(year, month, day).ok_or_value_err("day is out of range for month")
+        }
+    }
+
     pub(crate) const fn new(year: u16, month: u8, day: u8) -> Option<Self> {
         if year == 0
             || year > MAX_YEAR as _
@@ -170,6 +268,73 @@ impl Date {
         }
     }
 
+    // Parse the four-digit year at the start of an ISO 8601 date
+    fn parse_year(s: &[u8]) -> Option<u16> {
+        Some(
+            parse_digit(s, 0)? as u16 * 1000
+                + parse_digit(s, 1)? as u16 * 100
+                + parse_digit(s, 2)? as u16 * 10
+                + parse_digit(s, 3)? as u16,
+        )
+        .filter(|&y| y != 0)
+    }
+
+    // Parse an ISO 8601 week date: YYYY-Www-D
+    pub(crate) fn parse_week_date(s: &[u8]) -> Option<Self> {
+        if s.len() != 10 || s[4] != b'-' || s[5] != b'W' || s[8] != b'-' {
+            return None;
+        }
+        let year = Self::parse_year(s)?;
+        let week = parse_digit(s, 6)? * 10 + parse_digit(s, 7)?;
+        let weekday = parse_digit(s, 9)?;
+        Self::from_iso_week(year, week, weekday)
+    }
+
+    // Create from an ISO 8601 week date. Weekdays count from 1 (Monday).
+    // Returns None if the week doesn't exist, or the date is out of range.
+    pub(crate) fn from_iso_week(year: u16, week: u8, weekday: u8) -> Option<Self> {
+        if year == 0 || year > MAX_YEAR as _ || week < 1 || !(1..=7).contains(&weekday) {
+            return None;
+        }
+        // Week 1 is the week containing January 4th
+        let jan4 = Date::new_unchecked(year, 1, 4);
+        let monday = jan4.ord() as i32 - jan4.day_of_week_index() as i32 + (week as i32 - 1) * 7;
+        // A week belongs to the year in which its Thursday falls.
+        // This rules out week 53 in years with only 52 weeks.
+        if Date::from_ord(monday + 3)?.year != year {
+            return None;
+        }
+        Date::from_ord(monday + weekday as i32 - 1)
+    }
+
+    // The ISO 8601 week date: (ISO year, week, weekday from 1 (Monday))
+    pub(crate) fn iso_week_date(self) -> (u16, u8, u8) {
+        let weekday = self.day_of_week_index();
+        // The week's Thursday determines the ISO year.
+        // No overflow: Date::MAX is a Friday, so its Thursday is in range.
+        let thursday = Date::from_ord_unchecked(self.ord() + 3 - weekday as u32);
+        let jan1 = Date::new_unchecked(thursday.year, 1, 1);
+        let week = (thursday.ord() - jan1.ord()) / 7 + 1;
+        (thursday.year, week as u8, weekday + 1)
+    }
+
+    // Parse an ISO 8601 ordinal date: YYYY-DDD
+    pub(crate) fn parse_ordinal_date(s: &[u8]) -> Option<Self> {
+        if s.len() != 8 || s[4] != b'-' {
+            return None;
+        }
+        let year = Self::parse_year(s)?;
+        let day = parse_digit(s, 5)? as u16 * 100
+            + parse_digit(s, 6)? as u16 * 10
+            + parse_digit(s, 7)? as u16;
+        if day == 0 || day > 365 + is_leap(year) as u16 {
+            return None;
+        }
+        Some(Date::from_ord_unchecked(
+            days_before_year(year) + day as u32,
+        ))
+    }
+
     pub(crate) fn parse_partial(s: &mut &[u8]) -> Option<Self> {
         debug_assert!(s.len() >= 10);
         let result = Self::parse_all(&s[..10]);
@@ -179,7 +344,9 @@ impl Date {
 
     // Faster methods for small adjustments.
     // OPTIMIZE: actually determine if these are worth it
+    // The next day. The caller must ensure the date isn't MAX.
     pub(crate) const fn increment(self) -> Self {
+        debug_assert!(self.year < MAX.year || self.month < 12 || self.day < 31);
         let Date {
             mut year,
             mut month,
@@ -198,7 +365,9 @@ impl Date {
         Date { year, month, day }
     }
 
+    // The previous day. The caller must ensure the date isn't MIN.
     pub(crate) const fn decrement(self) -> Self {
+        debug_assert!(self.year > 1 || self.month > 1 || self.day > 1);
         let Date {
             mut year,
             mut month,
@@ -263,8 +432,36 @@ const fn days_in_month(year: u16, month: u8) -> u8 {
     MAX_MONTH_DAYS[is_leap(year) as usize][month as usize]
 }
 
+// A year has 53 ISO weeks if it starts on a Thursday,
+// or if it's a leap year starting on a Wednesday
+pub(crate) const fn iso_weeks_in(year: u16) -> u8 {
+    let jan1 = Date::new_unchecked(year, 1, 1).day_of_week_index();
+    52 + (jan1 == 3 || (jan1 == 2 && is_leap(year))) as u8
+}
+
+// Years before 1 CE can't be represented. This is a deliberate limitation,
+// so we raise a dedicated exception instead of a generic "invalid date".
+pub(crate) unsafe fn check_common_era(state: &State, year: c_long) -> PyResult<()> {
+    if year < 1 {
+        Err(py_err!(
+            state.exc_unsupported_year,
+            "Year {} is not supported: only years 1-9999 of the common era can be represented",
+            year
+        ))
+    } else {
+        Ok(())
+    }
+}
+
 unsafe fn __new__(cls: *mut PyTypeObject, args: *mut PyObject, kwargs: *mut PyObject) -> PyReturn {
     let nargs = PyTuple_GET_SIZE(args);
+    // A single string argument is parsed as an ISO date, e.g. Date("2024-03-10")
+    if nargs == 1 && DictItems::new(kwargs).is_none() {
+        let arg = PyTuple_GET_ITEM(args, 0);
+        if arg.is_str() {
+            return parse_common_iso(cls.cast(), arg);
+        }
+    }
     if nargs <= 3 {
         let mut arg_obj: [Option<NonNull<PyObject>>; 3] = [None, None, None];
         for i in 0..nargs {
@@ -292,12 +489,14 @@ unsafe fn __new__(cls: *mut PyTypeObject, args: *mut PyObject, kwargs: *mut PyOb
                 Ok(false)
             })?;
         };
+        let year = arg_obj[0]
+            .ok_or_type_err("function missing required argument 'year'")?
+            .as_ptr()
+            .to_long()?
+            .ok_or_type_err("year must be an integer")?;
+        check_common_era(State::for_type(cls), year)?;
         Date::from_longs(
-            arg_obj[0]
-                .ok_or_type_err("function missing required argument 'year'")?
-                .as_ptr()
-                .to_long()?
-                .ok_or_type_err("year must be an integer")?,
+            year,
             arg_obj[1]
                 .ok_or_type_err("function missing required argument 'month'")?
                 .as_ptr()
@@ -358,6 +557,8 @@ static mut SLOTS: &[PyType_Slot] = &[
     slotmethod!(Py_tp_richcompare, __richcmp__),
     slotmethod!(Py_nb_subtract, __sub__, 2),
     slotmethod!(Py_nb_add, __add__, 2),
+    slotmethod!(Py_nb_int, __int__, 1),
+    slotmethod!(Py_nb_index, __int__, 1),
     PyType_Slot {
         slot: Py_tp_doc,
         pfunc: doc::DATE.as_ptr() as *mut c_void,
@@ -384,17 +585,43 @@ static mut SLOTS: &[PyType_Slot] = &[
     },
 ];
 
-unsafe fn py_date(slf: *mut PyObject, _: *mut PyObject) -> PyReturn {
+unsafe fn py_date(slf: &mut PyObject, args: &[*mut PyObject]) -> PyReturn {
     let Date { year, month, day } = Date::extract(slf);
     let &PyDateTime_CAPI {
         Date_FromDate,
         DateType,
         ..
     } = State::for_obj(slf).py_api;
-    Date_FromDate(year.into(), month.into(), day.into(), DateType).as_result()
+    match *args {
+        [] => Date_FromDate(year.into(), month.into(), day.into(), DateType).as_result(),
+        [cls] if cls == DateType.cast() => {
+            Date_FromDate(year.into(), month.into(), day.into(), DateType).as_result()
+        }
+        [cls] => {
+            if PyType_Check(cls) == 0 || PyType_IsSubtype(cls.cast(), DateType) == 0 {
+                Err(type_err!("cls must be a subclass of datetime.date"))?
+            }
+            // Subclasses may override __new__ or __init__, so we call
+            // the type itself instead of allocating it directly.
+            let args = (
+                steal!(year.to_py()?),
+                steal!(month.to_py()?),
+                steal!(day.to_py()?),
+            )
+                .to_py()?;
+            defer_decref!(args);
+            PyObject_Call(cls, args, NULL()).as_result()
+        }
+        _ => Err(type_err!(
+            "py_date() takes at most 1 argument ({} given)",
+            args.len()
+        ))?,
+    }
 }
 
 unsafe fn from_py_date(cls: *mut PyObject, date: *mut PyObject) -> PyReturn {
+    // This deliberately also accepts `datetime.datetime` (a subclass of `date`),
+    // in which case the time part is dropped.
     if PyDate_Check(date) == 0 {
         Err(type_err!("argument must be a Date"))
     } else {
@@ -407,6 +634,45 @@ unsafe fn from_py_date(cls: *mut PyObject, date: *mut PyObject) -> PyReturn {
     }
 }
 
+unsafe fn from_ordinal(cls: *mut PyObject, n: *mut PyObject) -> PyReturn {
+    n.to_i64()?
+        .ok_or_type_err("ordinal must be an integer")?
+        .try_into()
+        .ok()
+        .and_then(Date::from_ord)
+        .ok_or_value_err("ordinal out of range")?
+        .to_obj(cls.cast())
+}
+
+unsafe fn __int__(slf: *mut PyObject) -> PyReturn {
+    Date::extract(slf).ord().to_py()
+}
+
+unsafe fn from_proleptic_month(cls: *mut PyObject, args: &[*mut PyObject]) -> PyReturn {
+    let &[n_obj, day_obj] = args else {
+        Err(type_err!(
+            "from_proleptic_month() takes exactly 2 arguments ({} given)",
+            args.len()
+        ))?
+    };
+    let n = n_obj
+        .to_long()?
+        .ok_or_type_err("proleptic month must be an integer")?;
+    let day = day_obj
+        .to_long()?
+        .ok_or_type_err("day must be an integer")?;
+    if !(MIN_YEAR * 12..=MAX_YEAR * 12 + 11).contains(&n) {
+        Err(value_err!("proleptic month out of range"))?
+    }
+    Date::from_longs_or_err(n / 12, n % 12 + 1, day, State::for_type(cls.cast()))?
+        .to_obj(cls.cast())
+}
+
+unsafe fn proleptic_month(slf: *mut PyObject, _: *mut PyObject) -> PyReturn {
+    let Date { year, month, .. } = Date::extract(slf);
+    (year as i32 * 12 + month as i32 - 1).to_py()
+}
+
 unsafe fn year_month(slf: *mut PyObject, _: *mut PyObject) -> PyReturn {
     let Date { year, month, .. } = Date::extract(slf);
     YearMonth::new_unchecked(year, month).to_obj(State::for_obj(slf).yearmonth_type)
@@ -421,6 +687,20 @@ unsafe fn __str__(slf: *mut PyObject) -> PyReturn {
     format!("{}", Date::extract(slf)).to_py()
 }
 
+unsafe fn __format__(slf: *mut PyObject, spec_obj: *mut PyObject) -> PyReturn {
+    let spec = spec_obj
+        .to_utf8()?
+        .ok_or_type_err("format spec must be a string")?;
+    if spec.is_empty() {
+        return __str__(slf);
+    }
+    // SAFETY: the bytes come from a Python str, so they're valid UTF-8
+    Date::extract(slf)
+        .strftime(std::str::from_utf8_unchecked(spec))
+        .ok_or_else(|| value_err!("Invalid format directive in {}", spec_obj.repr()))?
+        .to_py()
+}
+
 unsafe fn format_common_iso(slf: *mut PyObject, _: *mut PyObject) -> PyReturn {
     __str__(slf)
 }
@@ -431,6 +711,15 @@ unsafe fn parse_common_iso(cls: *mut PyObject, s: *mut PyObject) -> PyReturn {
         .to_obj(cls.cast())
 }
 
+unsafe fn parse_iso(cls: *mut PyObject, s: *mut PyObject) -> PyReturn {
+    let s_utf8 = s.to_utf8()?.ok_or_type_err("argument must be str")?;
+    Date::parse_all(s_utf8)
+        .or_else(|| Date::parse_week_date(s_utf8))
+        .or_else(|| Date::parse_ordinal_date(s_utf8))
+        .ok_or_else(|| value_err!("Invalid format: {}", s.repr()))?
+        .to_obj(cls.cast())
+}
+
 const fn days_before_year(year: u16) -> u32 {
     debug_assert!(year >= 1);
     let y = (year - 1) as u32;
@@ -445,17 +734,345 @@ const fn days_before_month(year: u16, month: u8) -> u16 {
 unsafe fn day_of_week(slf: *mut PyObject, _: *mut PyObject) -> PyReturn {
     let enum_members = State::for_obj(slf).weekday_enum_members;
     Ok(newref(
-        enum_members[((Date::extract(slf).ord() + 6) % 7) as usize]
+        enum_members[Date::extract(slf).day_of_week_index() as usize]
             .as_mut()
             .unwrap(),
     ))
 }
 
+unsafe fn weekday_iso(slf: *mut PyObject, _: *mut PyObject) -> PyReturn {
+    (Date::extract(slf).day_of_week_index() + 1).to_py()
+}
+
+unsafe fn weekday_sunday_based(slf: *mut PyObject, _: *mut PyObject) -> PyReturn {
+    // 0001-01-01 (ordinal 1) is a Monday, so Sundays are multiples of 7
+    (Date::extract(slf).ord() % 7).to_py()
+}
+
+unsafe fn days_in_year(slf: *mut PyObject, _: *mut PyObject) -> PyReturn {
+    (365 + is_leap(Date::extract(slf).year) as u16).to_py()
+}
+
+// Meteorological seasons (northern hemisphere), starting with December
+const SEASONS: [&str; 4] = ["winter", "spring", "summer", "autumn"];
+
+unsafe fn season(
+    slf: *mut PyObject,
+    cls: *mut PyTypeObject,
+    args: &[*mut PyObject],
+    kwargs: &mut KwargIter,
+) -> PyReturn {
+    if !args.is_empty() {
+        Err(type_err!("season() takes no positional arguments"))?
+    }
+    let &State { str_hemisphere, .. } = State::for_type(cls);
+    let mut south = false;
+    handle_kwargs("season", kwargs, |key, value, eq| {
+        if eq(key, str_hemisphere) {
+            south = match value
+                .to_utf8()?
+                .ok_or_type_err("hemisphere must be a string")?
+            {
+                b"north" => false,
+                b"south" => true,
+                _ => Err(value_err!("hemisphere must be 'north' or 'south'"))?,
+            };
+            Ok(true)
+        } else {
+            Ok(false)
+        }
+    })?;
+    let index = (Date::extract(slf).month as usize % 12) / 3;
+    SEASONS[if south { (index + 2) % 4 } else { index }].to_py()
+}
+
+unsafe fn weeks_in_year(slf: *mut PyObject, _: *mut PyObject) -> PyReturn {
+    iso_weeks_in(Date::extract(slf).year).to_py()
+}
+
+unsafe fn iso_weeks_in_year(cls: *mut PyObject, year_obj: *mut PyObject) -> PyReturn {
+    let year = year_obj
+        .to_long()?
+        .ok_or_type_err("year must be an integer")?;
+    check_common_era(State::for_type(cls.cast()), year)?;
+    if year > MAX_YEAR {
+        Err(value_err!("year out of range"))?
+    }
+    iso_weeks_in(year as u16).to_py()
+}
+
+unsafe fn replace_ordinal_day(slf: *mut PyObject, n_obj: *mut PyObject) -> PyReturn {
+    let n = n_obj
+        .to_i64_saturating()?
+        .ok_or_type_err("day of year must be an integer")?;
+    let year = Date::extract(slf).year;
+    let days_in_year = 365 + is_leap(year) as i64;
+    if !(1..=days_in_year).contains(&n) {
+        Err(value_err!(
+            "day of year must be in 1..{} for year {}",
+            days_in_year,
+            year
+        ))?
+    }
+    Date::from_ord_unchecked(days_before_year(year) + n as u32).to_obj(Py_TYPE(slf))
+}
+
+unsafe fn ordinal_in_century(slf: *mut PyObject, _: *mut PyObject) -> PyReturn {
+    let date = Date::extract(slf);
+    let start = Date {
+        year: (date.year - 1) / 100 * 100 + 1,
+        month: 1,
+        day: 1,
+    };
+    (date.ord() - start.ord() + 1).to_py()
+}
+
+// Get the index (Monday=0) of a Weekday enum member
+pub(crate) unsafe fn weekday_index(state: &State, obj: *mut PyObject) -> PyResult<u8> {
+    state
+        .weekday_enum_members
+        .iter()
+        .position(|&member| member == obj)
+        .map(|i| i as u8)
+        .ok_or_type_err("weekday must be a whenever.Weekday")
+}
+
+unsafe fn weekdays_in_month(slf: *mut PyObject, weekday_obj: *mut PyObject) -> PyReturn {
+    let cls = Py_TYPE(slf);
+    let weekday = weekday_index(State::for_type(cls), weekday_obj)?;
+    let Date { year, month, .. } = Date::extract(slf);
+    let first = Date::new_unchecked(year, month, 1);
+    let first_day = 1 + (weekday + 7 - first.day_of_week_index()) % 7;
+    to_list(
+        (first_day..=days_in_month(year, month))
+            .step_by(7)
+            .map(|day| Date::new_unchecked(year, month, day).to_obj(cls)),
+    )
+}
+
+unsafe fn first_weekday_of_month(slf: *mut PyObject, weekday_obj: *mut PyObject) -> PyReturn {
+    let cls = Py_TYPE(slf);
+    let weekday = weekday_index(State::for_type(cls), weekday_obj)?;
+    let Date { year, month, .. } = Date::extract(slf);
+    let first = Date::new_unchecked(year, month, 1);
+    let day = 1 + (weekday + 7 - first.day_of_week_index()) % 7;
+    Date::new_unchecked(year, month, day).to_obj(cls)
+}
+
+unsafe fn last_weekday_of_month(slf: *mut PyObject, weekday_obj: *mut PyObject) -> PyReturn {
+    let cls = Py_TYPE(slf);
+    let weekday = weekday_index(State::for_type(cls), weekday_obj)?;
+    let Date { year, month, .. } = Date::extract(slf);
+    let last = Date::new_unchecked(year, month, days_in_month(year, month));
+    let day = last.day - (last.day_of_week_index() + 7 - weekday) % 7;
+    Date::new_unchecked(year, month, day).to_obj(cls)
+}
+
+unsafe fn iter_weekdays(
+    slf: *mut PyObject,
+    cls: *mut PyTypeObject,
+    args: &[*mut PyObject],
+    kwargs: &mut KwargIter,
+) -> PyReturn {
+    let state = State::for_type(cls);
+    let &[weekday_obj, count_obj] = args else {
+        Err(type_err!(
+            "iter_weekdays() takes exactly 2 positional arguments ({} given)",
+            args.len()
+        ))?
+    };
+    let mut inclusive = true;
+    handle_kwargs("iter_weekdays", kwargs, |key, value, eq| {
+        if eq(key, state.str_inclusive) {
+            inclusive = match PyObject_IsTrue(value) {
+                -1 => Err(PyErrOccurred())?,
+                n => n == 1,
+            };
+            Ok(true)
+        } else {
+            Ok(false)
+        }
+    })?;
+    let weekday = weekday_index(state, weekday_obj)?;
+    let count = count_obj
+        .to_i64_saturating()?
+        .ok_or_type_err("count must be an integer")?;
+    if count < 0 {
+        Err(value_err!("count must not be negative"))?
+    }
+    let date = Date::extract(slf);
+    let days_ahead = match (weekday + 7 - date.day_of_week_index()) % 7 {
+        0 if !inclusive => 7,
+        n => n,
+    };
+    let first_ord = date.ord() as i64 + days_ahead as i64;
+    if count > 0 && count - 1 > (MAX_ORD as i64 - first_ord) / 7 {
+        Err(value_err!("Resulting date out of range"))?
+    }
+    // With a count of zero, an exclusive end at the start yields nothing
+    let (first, last) = match count {
+        0 => (date, date),
+        n => (
+            Date::from_ord_unchecked(first_ord as u32),
+            Date::from_ord_unchecked((first_ord + (n - 1) * 7) as u32),
+        ),
+    };
+    DateIterator::days(first, last, 7, count > 0).to_obj(state.date_iterator_type)
+}
+
+unsafe fn range(
+    slf: *mut PyObject,
+    cls: *mut PyTypeObject,
+    args: &[*mut PyObject],
+    kwargs: &mut KwargIter,
+) -> PyReturn {
+    let state = State::for_type(cls);
+    let &[end_obj] = args else {
+        Err(type_err!(
+            "range() takes exactly 1 positional argument ({} given)",
+            args.len()
+        ))?
+    };
+    if Py_TYPE(end_obj) != cls {
+        Err(type_err!("end must be a whenever.Date"))?
+    }
+    let mut step: i64 = 1;
+    let mut inclusive = true;
+    handle_kwargs("range", kwargs, |key, value, eq| {
+        if eq(key, state.str_step_days) {
+            // Steps beyond the i64 range are saturated: like any step
+            // larger than the range of dates, they only yield the start.
+            step = value
+                .to_i64_saturating()?
+                .ok_or_type_err("step_days must be an integer")?;
+        } else if eq(key, state.str_inclusive) {
+            inclusive = match PyObject_IsTrue(value) {
+                -1 => Err(PyErrOccurred())?,
+                n => n == 1,
+            };
+        } else {
+            return Ok(false);
+        }
+        Ok(true)
+    })?;
+    let start = Date::extract(slf).ord() as i64;
+    let span = Date::extract(end_obj).ord() as i64 - start;
+    if step == 0 {
+        Err(value_err!("step_days must not be zero"))?
+    } else if span > 0 && step < 0 {
+        Err(value_err!(
+            "step_days must be positive if end is after this date"
+        ))?
+    } else if span < 0 && step > 0 {
+        Err(value_err!(
+            "step_days must be negative if end is before this date"
+        ))?
+    }
+    let step_abs = step.unsigned_abs();
+    let span_abs = span.unsigned_abs();
+    let count = match (inclusive, span_abs) {
+        (true, _) => span_abs / step_abs + 1,
+        (false, 0) => 0,
+        (false, _) => (span_abs - 1) / step_abs + 1,
+    };
+    // No overflow: every step taken stays within the span
+    to_list(
+        (0..count as i64).map(|i| Date::from_ord_unchecked((start + i * step) as u32).to_obj(cls)),
+    )
+}
+
+unsafe fn range_months(
+    slf: *mut PyObject,
+    cls: *mut PyTypeObject,
+    args: &[*mut PyObject],
+    kwargs: &mut KwargIter,
+) -> PyReturn {
+    let state = State::for_type(cls);
+    let &[end_obj] = args else {
+        Err(type_err!(
+            "range_months() takes exactly 1 positional argument ({} given)",
+            args.len()
+        ))?
+    };
+    if Py_TYPE(end_obj) != cls {
+        Err(type_err!("end must be a whenever.Date"))?
+    }
+    let mut step: i64 = 1;
+    let mut inclusive = true;
+    handle_kwargs("range_months", kwargs, |key, value, eq| {
+        if eq(key, state.str_step_months) {
+            // Steps beyond the i64 range are saturated, as in range()
+            step = value
+                .to_i64_saturating()?
+                .ok_or_type_err("step_months must be an integer")?;
+        } else if eq(key, state.str_inclusive) {
+            inclusive = match PyObject_IsTrue(value) {
+                -1 => Err(PyErrOccurred())?,
+                n => n == 1,
+            };
+        } else {
+            return Ok(false);
+        }
+        Ok(true)
+    })?;
+    let start = Date::extract(slf);
+    let end = Date::extract(end_obj);
+    if step == 0 {
+        Err(value_err!("step_months must not be zero"))?
+    } else if end > start && step < 0 {
+        Err(value_err!(
+            "step_months must be positive if end is after this date"
+        ))?
+    } else if end < start && step > 0 {
+        Err(value_err!(
+            "step_months must be negative if end is before this date"
+        ))?
+    }
+    DateIterator::months(start, end, step, inclusive).to_obj(state.date_iterator_type)
+}
+
+unsafe fn on_or_after_weekday(slf: *mut PyObject, weekday_obj: *mut PyObject) -> PyReturn {
+    let cls = Py_TYPE(slf);
+    let weekday = weekday_index(State::for_type(cls), weekday_obj)?;
+    let date = Date::extract(slf);
+    let days_ahead = (weekday + 7 - date.day_of_week_index()) % 7;
+    date.shift_days(days_ahead as i32)
+        .ok_or_value_err("Resulting date out of range")?
+        .to_obj(cls)
+}
+
+unsafe fn on_or_before_weekday(slf: *mut PyObject, weekday_obj: *mut PyObject) -> PyReturn {
+    let cls = Py_TYPE(slf);
+    let weekday = weekday_index(State::for_type(cls), weekday_obj)?;
+    let date = Date::extract(slf);
+    let days_back = (date.day_of_week_index() + 7 - weekday) % 7;
+    date.shift_days(-(days_back as i32))
+        .ok_or_value_err("Resulting date out of range")?
+        .to_obj(cls)
+}
+
+unsafe fn start_of_week(slf: &mut PyObject, args: &[*mut PyObject]) -> PyReturn {
+    let cls = Py_TYPE(slf);
+    let start = match *args {
+        [] => 0,
+        [weekday_obj] => weekday_index(State::for_type(cls), weekday_obj)?,
+        _ => Err(type_err!(
+            "start_of_week() takes at most 1 argument ({} given)",
+            args.len()
+        ))?,
+    };
+    let date = Date::extract(slf);
+    let days_back = (date.day_of_week_index() + 7 - start) % 7;
+    date.shift_days(-(days_back as i32))
+        .ok_or_value_err("Resulting date out of range")?
+        .to_obj(cls)
+}
+
 unsafe fn __reduce__(slf: *mut PyObject, _: *mut PyObject) -> PyReturn {
     let Date { year, month, day } = Date::extract(slf);
     (
         State::for_obj(slf).unpickle_date,
-        steal!((steal!(pack![year, month, day].to_py()?),).to_py()?),
+        steal!((steal!(pack![PICKLE_VERSION, year, month, day].to_py()?),).to_py()?),
     )
         .to_py()
 }
@@ -497,6 +1114,11 @@ unsafe fn __sub__(obj_a: *mut PyObject, obj_b: *mut PyObject) -> PyReturn {
     // Other cases are more difficult, as they can be triggered
     // by reflexive operations with arbitrary types.
     // We need to eliminate them carefully.
+    // Date - int: only exact ints, so that `int - Date` is never accepted
+    } else if PyLong_CheckExact(obj_b) != 0 {
+        Date::extract(obj_a)
+            .shift_days_by_int(obj_b, true)?
+            .to_obj(type_a)
     } else {
         let mod_a = PyType_GetModule(type_a);
         let mod_b = PyType_GetModule(type_b);
@@ -507,6 +1129,8 @@ unsafe fn __sub__(obj_a: *mut PyObject, obj_b: *mut PyObject) -> PyReturn {
                 .and_then(|date| date.shift_days(-days))
                 .ok_or_value_err("Resulting date out of range")?
                 .to_obj(type_a)
+        } else if mod_a == mod_b && type_b == State::for_type(type_a).time_delta_type {
+            Err(type_err!(doc::SHIFT_DATE_MSG))?
         } else {
             // FUTURE: do we unnecessarily eliminate classes implementing __rsub__?
             // We can safely discount other types within our module
@@ -522,6 +1146,12 @@ unsafe fn __add__(obj_a: *mut PyObject, obj_b: *mut PyObject) -> PyReturn {
     // We need to be careful since this method can be called reflexively
     let type_a = Py_TYPE(obj_a);
     let type_b = Py_TYPE(obj_b);
+    // Date + int: only exact ints, and only with the date on the left
+    if PyLong_CheckExact(obj_b) != 0 {
+        return Date::extract(obj_a)
+            .shift_days_by_int(obj_b, false)?
+            .to_obj(type_a);
+    }
     let mod_a = PyType_GetModule(type_a);
     let mod_b = PyType_GetModule(type_b);
     if mod_a == mod_b && type_b == State::for_mod(mod_a).date_delta_type {
@@ -531,6 +1161,8 @@ unsafe fn __add__(obj_a: *mut PyObject, obj_b: *mut PyObject) -> PyReturn {
             .and_then(|date| date.shift_days(days))
             .ok_or_value_err("Resulting date out of range")?
             .to_obj(type_a)
+    } else if mod_a == mod_b && type_b == State::for_mod(mod_a).time_delta_type {
+        Err(type_err!(doc::SHIFT_DATE_MSG))?
     } else {
         // We can safely discount other types within our module
         Err(type_err!(
@@ -606,6 +1238,34 @@ unsafe fn _shift_method(
         .to_obj(cls)
 }
 
+unsafe fn add_weeks(slf: *mut PyObject, n: *mut PyObject) -> PyReturn {
+    let weeks = n
+        .to_i64_saturating()?
+        .ok_or_type_err("weeks must be an integer")?;
+    i32::try_from(weeks)
+        .ok()
+        .and_then(|w| w.checked_mul(7))
+        .and_then(|days| Date::extract(slf).shift_days(days))
+        .ok_or_value_err("Resulting date out of range")?
+        .to_obj(Py_TYPE(slf))
+}
+
+unsafe fn next_day(slf: *mut PyObject, _: *mut PyObject) -> PyReturn {
+    let date = Date::extract(slf);
+    if date == MAX {
+        Err(value_err!("Resulting date out of range"))?
+    }
+    date.increment().to_obj(Py_TYPE(slf))
+}
+
+unsafe fn previous_day(slf: *mut PyObject, _: *mut PyObject) -> PyReturn {
+    let date = Date::extract(slf);
+    if date == MIN {
+        Err(value_err!("Resulting date out of range"))?
+    }
+    date.decrement().to_obj(Py_TYPE(slf))
+}
+
 unsafe fn days_since(a: *mut PyObject, b: *mut PyObject) -> PyReturn {
     if Py_TYPE(b) != Py_TYPE(a) {
         Err(type_err!("argument must be a whenever.Date"))?
@@ -617,6 +1277,78 @@ unsafe fn days_until(a: *mut PyObject, b: *mut PyObject) -> PyReturn {
     days_since(b, a)
 }
 
+unsafe fn leap_years_until(slf: *mut PyObject, other: *mut PyObject) -> PyReturn {
+    if Py_TYPE(other) != Py_TYPE(slf) {
+        Err(type_err!("argument must be a whenever.Date"))?
+    }
+    let a = Date::extract(slf);
+    let b = Date::extract(other);
+    let (start, end) = if a <= b { (a, b) } else { (b, a) };
+    let count = if start == end {
+        0
+    } else {
+        // Feb 29ths before `end`, minus those on or before `start`
+        end.leap_days_before()
+            - start.leap_days_before()
+            - (start.month == 2 && start.day == 29) as u32
+    } as i32;
+    (if a <= b { count } else { -count }).to_py()
+}
+
+unsafe fn business_days_between(
+    slf: *mut PyObject,
+    cls: *mut PyTypeObject,
+    args: &[*mut PyObject],
+    kwargs: &mut KwargIter,
+) -> PyReturn {
+    let state = State::for_type(cls);
+    let &[other] = args else {
+        Err(type_err!(
+            "business_days_between() takes exactly 1 positional argument ({} given)",
+            args.len()
+        ))?
+    };
+    if Py_TYPE(other) != cls {
+        Err(type_err!("argument must be a whenever.Date"))?
+    }
+    // Bitmask of weekend days, indexed by weekday (Monday=0)
+    let mut weekend: u8 = 0b110_0000;
+    handle_kwargs("business_days_between", kwargs, |key, value, eq| {
+        if eq(key, state.str_weekend) {
+            weekend = 0;
+            let iter = PyObject_GetIter(value).as_result()?;
+            defer_decref!(iter);
+            loop {
+                let item = PyIter_Next(iter);
+                if item.is_null() {
+                    if !PyErr_Occurred().is_null() {
+                        Err(PyErrOccurred())?
+                    }
+                    break;
+                }
+                defer_decref!(item);
+                weekend |= 1 << weekday_index(state, item)?;
+            }
+            Ok(true)
+        } else {
+            Ok(false)
+        }
+    })?;
+    let a = Date::extract(slf);
+    let b = Date::extract(other);
+    let (start, end) = if a <= b { (a, b) } else { (b, a) };
+    // Count over the half-open range [start, end): whole weeks first,
+    // then the remaining days, which start on the same weekday as `start`
+    let days = end.ord() - start.ord();
+    let workdays_per_week = 7 - weekend.count_ones();
+    let first = start.day_of_week_index() as u32;
+    let remainder = (first..first + days % 7)
+        .filter(|i| weekend & (1 << (i % 7)) == 0)
+        .count() as u32;
+    let count = (days / 7 * workdays_per_week + remainder) as i32;
+    (if a <= b { count } else { -count }).to_py()
+}
+
 unsafe fn replace(
     slf: *mut PyObject,
     cls: *mut PyTypeObject,
@@ -627,6 +1359,7 @@ unsafe fn replace(
         str_year,
         str_month,
         str_day,
+        str_on_invalid,
         ..
     } = State::for_type(cls);
     if !args.is_empty() {
@@ -636,6 +1369,7 @@ unsafe fn replace(
         let mut year = date.year.into();
         let mut month = date.month.into();
         let mut day = date.day.into();
+        let mut clamp = false;
         handle_kwargs("replace", kwargs, |key, value, eq| {
             if eq(key, str_year) {
                 year = value.to_long()?.ok_or_type_err("year must be an integer")?;
@@ -645,17 +1379,64 @@ unsafe fn replace(
                     .ok_or_type_err("month must be an integer")?;
             } else if eq(key, str_day) {
                 day = value.to_long()?.ok_or_type_err("day must be an integer")?;
+            } else if eq(key, str_on_invalid) {
+                clamp = match value
+                    .to_utf8()?
+                    .ok_or_type_err("on_invalid must be a string")?
+                {
+                    b"error" => false,
+                    b"clamp" => true,
+                    _ => Err(value_err!("on_invalid must be 'error' or 'clamp'"))?,
+                };
             } else {
                 return Ok(false);
             }
             Ok(true)
         })?;
+        check_common_era(State::for_type(cls), year)?;
+        // Snap days which don't exist in the month (e.g. Feb 29) to its last day
+        if clamp
+            && (MIN_YEAR..=MAX_YEAR).contains(&year)
+            && (1..=12).contains(&month)
+            && (1..=31).contains(&day)
+        {
+            day = day.min(days_in_month(year as u16, month as u8).into());
+        }
         Date::from_longs(year, month, day)
             .ok_or_value_err("Invalid date components")?
             .to_obj(cls)
     }
 }
 
+unsafe fn _with_fields(slf: *mut PyObject, year: c_long, month: c_long, day: c_long) -> PyReturn {
+    let cls = Py_TYPE(slf);
+    Date::from_longs_or_err(year, month, day, State::for_type(cls))?.to_obj(cls)
+}
+
+unsafe fn with_year(slf: *mut PyObject, year_obj: *mut PyObject) -> PyReturn {
+    let Date { month, day, .. } = Date::extract(slf);
+    let year = year_obj
+        .to_long()?
+        .ok_or_type_err("year must be an integer")?;
+    _with_fields(slf, year, month.into(), day.into())
+}
+
+unsafe fn with_month(slf: *mut PyObject, month_obj: *mut PyObject) -> PyReturn {
+    let Date { year, day, .. } = Date::extract(slf);
+    let month = month_obj
+        .to_long()?
+        .ok_or_type_err("month must be an integer")?;
+    _with_fields(slf, year.into(), month, day.into())
+}
+
+unsafe fn with_day(slf: *mut PyObject, day_obj: *mut PyObject) -> PyReturn {
+    let Date { year, month, .. } = Date::extract(slf);
+    let day = day_obj
+        .to_long()?
+        .ok_or_type_err("day must be an integer")?;
+    _with_fields(slf, year.into(), month.into(), day)
+}
+
 unsafe fn at(slf: *mut PyObject, time_obj: *mut PyObject) -> PyReturn {
     let &State {
         time_type,
@@ -691,39 +1472,83 @@ unsafe fn today_in_system_tz(cls: *mut PyObject, _: *mut PyObject) -> PyReturn {
 }
 
 static mut METHODS: &[PyMethodDef] = &[
-    method!(py_date, doc::DATE_PY_DATE),
+    method_vararg!(py_date, doc::DATE_PY_DATE),
     method!(
         today_in_system_tz,
         doc::DATE_TODAY_IN_SYSTEM_TZ,
         METH_CLASS | METH_NOARGS
     ),
     method!(format_common_iso, doc::DATE_FORMAT_COMMON_ISO),
+    method!(__format__, c"", METH_O),
     method!(
         parse_common_iso,
         doc::DATE_PARSE_COMMON_ISO,
         METH_O | METH_CLASS
     ),
+    method!(parse_iso, doc::DATE_PARSE_ISO, METH_O | METH_CLASS),
     method!(from_py_date, doc::DATE_FROM_PY_DATE, METH_O | METH_CLASS),
+    method!(from_ordinal, doc::DATE_FROM_ORDINAL, METH_O | METH_CLASS),
+    method_vararg!(
+        from_proleptic_month,
+        doc::DATE_FROM_PROLEPTIC_MONTH,
+        METH_CLASS
+    ),
     method!(identity2 named "__copy__", c""),
     method!(identity2 named "__deepcopy__", c"", METH_O),
     method!(day_of_week, doc::DATE_DAY_OF_WEEK),
+    method!(weekday_iso, doc::DATE_WEEKDAY_ISO),
+    method!(weekday_sunday_based, doc::DATE_WEEKDAY_SUNDAY_BASED),
+    method!(days_in_year, doc::DATE_DAYS_IN_YEAR),
+    method!(weeks_in_year, doc::DATE_WEEKS_IN_YEAR),
+    method_kwargs!(season, doc::DATE_SEASON),
+    method!(
+        iso_weeks_in_year,
+        doc::DATE_ISO_WEEKS_IN_YEAR,
+        METH_O | METH_CLASS
+    ),
+    method!(ordinal_in_century, doc::DATE_ORDINAL_IN_CENTURY),
+    method!(replace_ordinal_day, doc::DATE_REPLACE_ORDINAL_DAY, METH_O),
+    method!(weekdays_in_month, doc::DATE_WEEKDAYS_IN_MONTH, METH_O),
+    method!(
+        first_weekday_of_month,
+        doc::DATE_FIRST_WEEKDAY_OF_MONTH,
+        METH_O
+    ),
+    method!(
+        last_weekday_of_month,
+        doc::DATE_LAST_WEEKDAY_OF_MONTH,
+        METH_O
+    ),
+    method_kwargs!(iter_weekdays, doc::DATE_ITER_WEEKDAYS),
+    method_kwargs!(range, doc::DATE_RANGE),
+    method_kwargs!(range_months, doc::DATE_RANGE_MONTHS),
+    method_vararg!(start_of_week, doc::DATE_START_OF_WEEK),
+    method!(on_or_after_weekday, doc::DATE_ON_OR_AFTER_WEEKDAY, METH_O),
+    method!(on_or_before_weekday, doc::DATE_ON_OR_BEFORE_WEEKDAY, METH_O),
     method!(at, doc::DATE_AT, METH_O),
+    method!(proleptic_month, doc::DATE_PROLEPTIC_MONTH),
     method!(year_month, doc::DATE_YEAR_MONTH),
     method!(month_day, doc::DATE_MONTH_DAY),
     method!(__reduce__, c""),
     method_kwargs!(add, doc::DATE_ADD),
     method_kwargs!(subtract, doc::DATE_SUBTRACT),
+    method!(add_weeks, doc::DATE_ADD_WEEKS, METH_O),
+    method!(next_day, doc::DATE_NEXT_DAY),
+    method!(previous_day, doc::DATE_PREVIOUS_DAY),
     method!(days_since, doc::DATE_DAYS_SINCE, METH_O),
     method!(days_until, doc::DATE_DAYS_UNTIL, METH_O),
+    method!(leap_years_until, doc::DATE_LEAP_YEARS_UNTIL, METH_O),
+    method_kwargs!(business_days_between, doc::DATE_BUSINESS_DAYS_BETWEEN),
     method_kwargs!(replace, doc::DATE_REPLACE),
+    method!(with_year, doc::DATE_WITH_YEAR, METH_O),
+    method!(with_month, doc::DATE_WITH_MONTH, METH_O),
+    method!(with_day, doc::DATE_WITH_DAY, METH_O),
     PyMethodDef::zeroed(),
 ];
 
 pub(crate) unsafe fn unpickle(module: *mut PyObject, arg: *mut PyObject) -> PyReturn {
-    let mut packed = arg.to_bytes()?.ok_or_type_err("Invalid pickle data")?;
-    if packed.len() != 4 {
-        Err(value_err!("Invalid pickle data"))?
-    }
+    let mut packed =
+        strip_pickle_version(arg.to_bytes()?.ok_or_type_err("Invalid pickle data")?, 4)?;
     Date {
         year: unpack_one!(packed, u16),
         month: unpack_one!(packed, u8),
@@ -744,6 +1569,10 @@ unsafe fn get_day(slf: *mut PyObject) -> PyReturn {
     Date::extract(slf).day.to_py()
 }
 
+unsafe fn get_century(slf: *mut PyObject) -> PyReturn {
+    ((Date::extract(slf).year - 1) / 100 + 1).to_py()
+}
+
 static mut GETSETTERS: &[PyGetSetDef] = &[
     getter!(
         get_year named "year",
@@ -757,6 +1586,10 @@ static mut GETSETTERS: &[PyGetSetDef] = &[
         get_day named "day",
         "The day component"
     ),
+    getter!(
+        get_century named "century",
+        "The century, counting from 1. The 20th century is 1901-2000"
+    ),
     PyGetSetDef {
         name: NULL(),
         get: None,
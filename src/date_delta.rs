@@ -272,7 +272,17 @@ unsafe fn richcmp(a_obj: *mut PyObject, b_obj: *mut PyObject, op: c_int) -> PyRe
         match op {
             pyo3_ffi::Py_EQ => (a == b).to_py()?,
             pyo3_ffi::Py_NE => (a != b).to_py()?,
-            _ => newref(Py_NotImplemented()),
+            // Only the days can be ordered unambiguously,
+            // since the length of a month varies
+            _ if a.months != b.months => Err(type_err!(
+                "Cannot order DateDeltas with different months, \
+                 since the length of a month varies"
+            ))?,
+            pyo3_ffi::Py_LT => (a.days < b.days).to_py()?,
+            pyo3_ffi::Py_LE => (a.days <= b.days).to_py()?,
+            pyo3_ffi::Py_GT => (a.days > b.days).to_py()?,
+            pyo3_ffi::Py_GE => (a.days >= b.days).to_py()?,
+            _ => unreachable!(),
         }
     } else {
         newref(Py_NotImplemented())
@@ -0,0 +1,111 @@
+use core::ffi::c_void;
+use core::{mem, ptr::null_mut as NULL};
+use pyo3_ffi::*;
+
+use crate::common::*;
+use crate::date::{Date, MAX_YEAR};
+use crate::State;
+
+/// A lazy sequence of dates, each shifted from the start by a multiple
+/// of the step. It ends at the first date beyond the end date
+/// (or beyond the supported range).
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct DateIterator {
+    start: Date,
+    end: Date,
+    step: i64,
+    by_months: bool,
+    inclusive: bool,
+    index: i64,
+}
+
+impl DateIterator {
+    pub(crate) fn days(start: Date, end: Date, step: i64, inclusive: bool) -> Self {
+        DateIterator {
+            start,
+            end,
+            step,
+            by_months: false,
+            inclusive,
+            index: 0,
+        }
+    }
+
+    pub(crate) fn months(start: Date, end: Date, step: i64, inclusive: bool) -> Self {
+        DateIterator {
+            start,
+            end,
+            step,
+            by_months: true,
+            inclusive,
+            index: 0,
+        }
+    }
+}
+
+impl Iterator for DateIterator {
+    type Item = Date;
+
+    fn next(&mut self) -> Option<Date> {
+        let offset = self.index.checked_mul(self.step)?;
+        // Shifting from the start (not from the previous date) ensures
+        // the day is clamped per month without drifting.
+        let date = if self.by_months {
+            (offset.unsigned_abs() <= (MAX_YEAR * 12) as u64)
+                .then(|| self.start.shift_months(offset as i32))
+                .flatten()
+        } else {
+            i32::try_from(offset)
+                .ok()
+                .and_then(|d| self.start.shift_days(d))
+        }?;
+        match (self.step > 0, self.inclusive) {
+            (true, true) => date <= self.end,
+            (true, false) => date < self.end,
+            (false, true) => date >= self.end,
+            (false, false) => date > self.end,
+        }
+        .then(|| {
+            self.index += 1;
+            date
+        })
+    }
+}
+
+impl PyWrapped for DateIterator {}
+
+unsafe fn __new__(_: *mut PyTypeObject, _: *mut PyObject, _: *mut PyObject) -> PyReturn {
+    Err(type_err!("cannot create 'whenever.DateIterator' instances"))
+}
+
+unsafe extern "C" fn __next__(slf: *mut PyObject) -> *mut PyObject {
+    match generic_extract_mut::<DateIterator>(slf).next() {
+        // Returning NULL without an exception set ends the iteration
+        Some(date) => date
+            .to_obj(State::for_obj(slf).date_type)
+            .map_or(NULL(), |d| d),
+        None => NULL(),
+    }
+}
+
+static mut SLOTS: &[PyType_Slot] = &[
+    slotmethod!(Py_tp_new, __new__),
+    PyType_Slot {
+        slot: Py_tp_iter,
+        pfunc: PyObject_SelfIter as *mut c_void,
+    },
+    PyType_Slot {
+        slot: Py_tp_iternext,
+        pfunc: __next__ as *mut c_void,
+    },
+    PyType_Slot {
+        slot: Py_tp_dealloc,
+        pfunc: generic_dealloc as *mut c_void,
+    },
+    PyType_Slot {
+        slot: 0,
+        pfunc: NULL(),
+    },
+];
+
+type_spec!(DateIterator, SLOTS);
@@ -9,6 +9,11 @@ Example
 -------
 >>> d = Date(2021, 1, 2)
 Date(2021-01-02)
+
+A single string argument is parsed like :meth:`parse_common_iso`:
+
+>>> Date(\"2021-01-02\")
+Date(2021-01-02)
 ";
 pub(crate) const DATEDELTA: &CStr = c"\
 A duration of time consisting of calendar units
@@ -79,9 +84,17 @@ and account for DST, convert to a ``ZonedDateTime`` first,
 This class knows when the offset changes.
 ";
 pub(crate) const REPEATEDTIME: &CStr = c"\
-A datetime is repeated in a timezone, e.g. because of DST";
+A datetime is repeated in a timezone, e.g. because of DST
+
+The repeated ``date`` and ``time`` are available as attributes,
+as well as the ``tz`` key (``None`` for the system timezone).
+";
 pub(crate) const SKIPPEDTIME: &CStr = c"\
-A datetime is skipped in a timezone, e.g. because of DST";
+A datetime is skipped in a timezone, e.g. because of DST
+
+The skipped ``date`` and ``time`` are available as attributes,
+as well as the ``tz`` key (``None`` for the system timezone).
+";
 pub(crate) const SYSTEMDATETIME: &CStr = c"\
 Represents a time in the system timezone.
 It is similar to ``OffsetDateTime``,
@@ -131,6 +144,12 @@ Note
 A shorter way to instantiate a timedelta is to use the helper functions
 :func:`~whenever.hours`, :func:`~whenever.minutes`, etc.
 
+";
+pub(crate) const UNSUPPORTEDYEAR: &CStr = c"\
+A date before year 1 of the common era was requested.
+
+Only the proleptic Gregorian years 1-9999 can be represented.
+This is a deliberate limitation, not a bug.
 ";
 pub(crate) const YEARMONTH: &CStr = c"\
 A year and month without a day component
@@ -215,6 +234,17 @@ Date(2022-03-05)
 >>> Date(2020, 2, 29).add(years=1)
 Date(2021-02-28)
 ";
+pub(crate) const DATE_ADD_WEEKS: &CStr = c"\
+Add a number of weeks to a date.
+Equivalent to ``add(weeks=n)``.
+
+Example
+-------
+>>> Date(2021, 1, 2).add_weeks(3)
+Date(2021-01-23)
+>>> Date(2021, 1, 2).add_weeks(-1)
+Date(2020-12-26)
+";
 pub(crate) const DATE_AT: &CStr = c"\
 Combine a date with a time to create a datetime
 
@@ -227,6 +257,27 @@ LocalDateTime(2021-01-02 12:30:00)
 You can use methods like :meth:`~LocalDateTime.assume_utc`
 or :meth:`~LocalDateTime.assume_tz` to make the result aware.
 ";
+pub(crate) const DATE_BUSINESS_DAYS_BETWEEN: &CStr = c"\
+business_days_between($self, other, /, *, weekend=None)
+--
+
+Count the business days from this date up to (but not including)
+another date. Days in ``weekend`` (Saturday and Sunday by default)
+are not counted.
+If the other date is before this date, the result is negative.
+
+Example
+-------
+>>> d = Date(2024, 3, 15)  # a Friday
+>>> d.business_days_between(Date(2024, 3, 19))
+2
+>>> d.business_days_between(
+...     Date(2024, 3, 19), weekend=[Weekday.FRIDAY, Weekday.SATURDAY]
+... )
+2
+>>> Date(2024, 3, 19).business_days_between(d)
+-2
+";
 pub(crate) const DATE_DAY_OF_WEEK: &CStr = c"\
 The day of the week
 
@@ -237,6 +288,16 @@ Weekday.SATURDAY
 >>> Weekday.SATURDAY.value
 6  # the ISO value
 ";
+pub(crate) const DATE_DAYS_IN_YEAR: &CStr = c"\
+The number of days in this date's year: 365, or 366 in leap years
+
+Example
+-------
+>>> Date(2024, 3, 15).days_in_year()
+366
+>>> Date(2023, 3, 15).days_in_year()
+365
+";
 pub(crate) const DATE_DAYS_SINCE: &CStr = c"\
 Calculate the number of days this day is after another date.
 If the other date is after this date, the result is negative.
@@ -265,6 +326,14 @@ Note
 If you're interested in calculating the difference
 in terms of days **and** months, use the subtraction operator instead.
 ";
+pub(crate) const DATE_FIRST_WEEKDAY_OF_MONTH: &CStr = c"\
+The first date in this date's month that falls on the given weekday
+
+Example
+-------
+>>> Date(2024, 3, 15).first_weekday_of_month(Weekday.MONDAY)
+Date(2024-03-04)
+";
 pub(crate) const DATE_FORMAT_COMMON_ISO: &CStr = c"\
 Format as the common ISO 8601 date format.
 
@@ -275,13 +344,96 @@ Example
 >>> Date(2021, 1, 2).format_common_iso()
 '2021-01-02'
 ";
+pub(crate) const DATE_FROM_ORDINAL: &CStr = c"\
+Create from a proleptic Gregorian ordinal,
+where January 1 of year 1 has ordinal 1.
+
+The inverse of ``int()``.
+
+Example
+-------
+>>> Date.from_ordinal(738_000)
+Date(2021-07-29)
+";
+pub(crate) const DATE_FROM_PROLEPTIC_MONTH: &CStr = c"\
+from_proleptic_month(n, day, /)
+--
+
+Create from a month index as returned by :meth:`proleptic_month`,
+and a day of that month.
+
+Raises ``ValueError`` if the day doesn't exist in the month.
+
+Example
+-------
+>>> Date.from_proleptic_month(24290, 15)
+Date(2024-03-15)
+>>> Date.from_proleptic_month(24290 + 1, 15)
+Date(2024-04-15)
+";
 pub(crate) const DATE_FROM_PY_DATE: &CStr = c"\
 Create from a :class:`~datetime.date`
 
+A :class:`~datetime.datetime` is also accepted,
+in which case its time (and timezone) is ignored.
+
 Example
 -------
 >>> Date.from_py_date(date(2021, 1, 2))
 Date(2021-01-02)
+>>> Date.from_py_date(datetime(2021, 1, 2, 23, 59))
+Date(2021-01-02)
+";
+pub(crate) const DATE_ISO_WEEKS_IN_YEAR: &CStr = c"\
+The number of ISO 8601 weeks in the given year: 52 or 53
+
+Useful for validating week numbers, since only some years
+have a week 53.
+
+Example
+-------
+>>> Date.iso_weeks_in_year(2020)
+53
+>>> Date.iso_weeks_in_year(2021)
+52
+";
+pub(crate) const DATE_ITER_WEEKDAYS: &CStr = c"\
+iter_weekdays($self, weekday, count, /, *, inclusive=True)
+--
+
+Iterate over the next ``count`` dates falling on the given weekday,
+starting from this date.
+
+If this date itself falls on the weekday, it's included
+unless ``inclusive=False`` is passed.
+
+Example
+-------
+>>> d = Date(2024, 3, 15)  # a Friday
+>>> list(d.iter_weekdays(Weekday.FRIDAY, 3))
+[Date(2024-03-15), Date(2024-03-22), Date(2024-03-29)]
+>>> list(d.iter_weekdays(Weekday.FRIDAY, 2, inclusive=False))
+[Date(2024-03-22), Date(2024-03-29)]
+";
+pub(crate) const DATE_LAST_WEEKDAY_OF_MONTH: &CStr = c"\
+The last date in this date's month that falls on the given weekday
+
+Example
+-------
+>>> Date(2024, 3, 15).last_weekday_of_month(Weekday.FRIDAY)
+Date(2024-03-29)
+";
+pub(crate) const DATE_LEAP_YEARS_UNTIL: &CStr = c"\
+Count the leap days (February 29ths) strictly between
+this date and another date.
+If the other date is before this date, the result is negative.
+
+Example
+-------
+>>> Date(1999, 6, 1).leap_years_until(Date(2004, 6, 1))
+2
+>>> Date(2004, 6, 1).leap_years_until(Date(1999, 6, 1))
+-2
 ";
 pub(crate) const DATE_MONTH_DAY: &CStr = c"\
 The month and day (without a year component)
@@ -291,6 +443,50 @@ Example
 >>> Date(2021, 1, 2).month_day()
 MonthDay(--01-02)
 ";
+pub(crate) const DATE_NEXT_DAY: &CStr = c"\
+The day after this date.
+Raises ``ValueError`` if this is :attr:`MAX`.
+
+Example
+-------
+>>> Date(2021, 12, 31).next_day()
+Date(2022-01-01)
+";
+pub(crate) const DATE_ON_OR_AFTER_WEEKDAY: &CStr = c"\
+The first date falling on the given weekday,
+starting from this date.
+
+Example
+-------
+>>> d = Date(2024, 3, 15)  # a Friday
+>>> d.on_or_after_weekday(Weekday.MONDAY)
+Date(2024-03-18)
+>>> d.on_or_after_weekday(Weekday.FRIDAY)
+Date(2024-03-15)
+";
+pub(crate) const DATE_ON_OR_BEFORE_WEEKDAY: &CStr = c"\
+The last date falling on the given weekday,
+going back from this date.
+
+Example
+-------
+>>> d = Date(2024, 3, 15)  # a Friday
+>>> d.on_or_before_weekday(Weekday.MONDAY)
+Date(2024-03-11)
+>>> d.on_or_before_weekday(Weekday.FRIDAY)
+Date(2024-03-15)
+";
+pub(crate) const DATE_ORDINAL_IN_CENTURY: &CStr = c"\
+The day of the century, starting at 1 on January 1st
+of the century's first year (e.g. 2001 for the 21st century)
+
+Example
+-------
+>>> Date(2001, 1, 1).ordinal_in_century()
+1
+>>> Date(2000, 12, 31).ordinal_in_century()
+36525
+";
 pub(crate) const DATE_PARSE_COMMON_ISO: &CStr = c"\
 Create from the common ISO 8601 date format ``YYYY-MM-DD``.
 Does not accept more \"exotic\" ISO 8601 formats.
@@ -302,19 +498,165 @@ Example
 >>> Date.parse_common_iso(\"2021-01-02\")
 Date(2021-01-02)
 ";
+pub(crate) const DATE_PARSE_ISO: &CStr = c"\
+Create from any of the ISO 8601 date formats:
+calendar date (``YYYY-MM-DD``), week date (``YYYY-Www-D``),
+or ordinal date (``YYYY-DDD``).
+
+Use :meth:`parse_common_iso` to only accept calendar dates.
+
+Example
+-------
+>>> Date.parse_iso(\"2024-03-04\")
+Date(2024-03-04)
+>>> Date.parse_iso(\"2024-W10-1\")
+Date(2024-03-04)
+>>> Date.parse_iso(\"2024-064\")
+Date(2024-03-04)
+";
+pub(crate) const DATE_PREVIOUS_DAY: &CStr = c"\
+The day before this date.
+Raises ``ValueError`` if this is :attr:`MIN`.
+
+Example
+-------
+>>> Date(2024, 3, 1).previous_day()
+Date(2024-02-29)
+";
+pub(crate) const DATE_PROLEPTIC_MONTH: &CStr = c"\
+The number of months since the start of year 0,
+i.e. ``year * 12 + month - 1``.
+
+Useful for grouping by month, or stepping through months
+with plain integer arithmetic.
+Inverse of :meth:`from_proleptic_month`.
+
+Example
+-------
+>>> Date(2024, 3, 15).proleptic_month()
+24290
+";
 pub(crate) const DATE_PY_DATE: &CStr = c"\
-Convert to a standard library :class:`~datetime.date`";
+py_date($self, cls=None, /)
+--
+
+Convert to a standard library :class:`~datetime.date`
+
+Pass a subclass of :class:`~datetime.date` to create an instance
+of it instead. It's called with the year, month, and day.
+
+Example
+-------
+>>> class MyDate(date): ...
+>>> Date(2024, 3, 15).py_date(MyDate)
+MyDate(2024, 3, 15)
+";
+pub(crate) const DATE_RANGE: &CStr = c"\
+range($self, end, /, *, step_days=1, inclusive=True)
+--
+
+Create a list of dates from this date up to the given end date,
+in steps of ``step_days``.
+
+The end date is included (if reached) unless ``inclusive=False``
+is passed. To go back in time, pass an earlier end date
+and a negative step.
+
+Example
+-------
+>>> d = Date(2024, 3, 1)
+>>> d.range(Date(2024, 3, 3))
+[Date(2024-03-01), Date(2024-03-02), Date(2024-03-03)]
+>>> d.range(Date(2024, 3, 8), step_days=3)
+[Date(2024-03-01), Date(2024-03-04), Date(2024-03-07)]
+>>> d.range(Date(2024, 2, 28), step_days=-1, inclusive=False)
+[Date(2024-03-01), Date(2024-02-29)]
+";
+pub(crate) const DATE_RANGE_MONTHS: &CStr = c"\
+range_months($self, end, /, *, step_months=1, inclusive=True)
+--
+
+Iterate over dates from this date up to the given end date,
+in steps of ``step_months`` calendar months.
+
+As with :meth:`range`, the end date is included (if reached)
+unless ``inclusive=False`` is passed, and a negative step
+goes back in time.
+
+Each date keeps this date's day, clamped to the end of the month
+if needed. Dates are calculated from this date, so clamping
+in a short month doesn't carry over to the following months.
+
+Example
+-------
+>>> list(Date(2024, 1, 1).range_months(Date(2024, 3, 1)))
+[Date(2024-01-01), Date(2024-02-01), Date(2024-03-01)]
+>>> list(Date(2024, 1, 31).range_months(Date(2024, 3, 31)))
+[Date(2024-01-31), Date(2024-02-29), Date(2024-03-31)]
+>>> d = Date(2024, 1, 15)
+>>> list(d.range_months(Date(2025, 1, 15), step_months=6))
+[Date(2024-01-15), Date(2024-07-15), Date(2025-01-15)]
+";
 pub(crate) const DATE_REPLACE: &CStr = c"\
-replace($self, /, *, year=None, month=None, day=None)
+replace($self, /, *, year=None, month=None, day=None, on_invalid='error')
 --
 
 Create a new instance with the given fields replaced
 
+By default, a ``ValueError`` is raised if the resulting day doesn't
+exist in the month. Pass ``on_invalid=\"clamp\"`` to use the last day
+of the month instead.
+
 Example
 -------
 >>> d = Date(2021, 1, 2)
 >>> d.replace(day=4)
 Date(2021-01-04)
+>>> Date(2024, 2, 29).replace(year=2023, on_invalid=\"clamp\")
+Date(2023-02-28)
+";
+pub(crate) const DATE_REPLACE_ORDINAL_DAY: &CStr = c"\
+The ``n``-th day of this date's year,
+where January 1st is day 1.
+
+Raises ``ValueError`` if the year doesn't have that many days.
+
+Example
+-------
+>>> Date(2024, 6, 1).replace_ordinal_day(60)
+Date(2024-02-29)
+>>> Date(2023, 6, 1).replace_ordinal_day(60)
+Date(2023-03-01)
+";
+pub(crate) const DATE_SEASON: &CStr = c"\
+season($self, *, hemisphere='north')
+--
+
+The meteorological season of this date.
+
+Seasons start on the first of December, March, June,
+and September. In the southern hemisphere, they're reversed.
+
+Example
+-------
+>>> Date(2024, 7, 15).season()
+'summer'
+>>> Date(2024, 7, 15).season(hemisphere=\"south\")
+'winter'
+";
+pub(crate) const DATE_START_OF_WEEK: &CStr = c"\
+start_of_week($self, start=None, /)
+--
+
+The date on which this date's week starts.
+Weeks start on Monday by default.
+
+Example
+-------
+>>> Date(2024, 3, 1).start_of_week()
+Date(2024-02-26)
+>>> Date(2024, 3, 1).start_of_week(Weekday.SUNDAY)
+Date(2024-02-25)
 ";
 pub(crate) const DATE_SUBTRACT: &CStr = c"\
 subtract($self, delta=None, /, *, years=0, months=0, weeks=0, days=0)
@@ -342,6 +684,93 @@ Example
 >>> Date.today_in_system_tz()
 Date(2021-01-02)
 ";
+pub(crate) const DATE_WEEKDAY_ISO: &CStr = c"\
+The ISO day of the week as an integer, from 1 (Monday)
+to 7 (Sunday)
+
+Example
+-------
+>>> Date(2021, 1, 2).weekday_iso()
+6
+>>> Date(2021, 1, 3).weekday_iso()
+7
+";
+pub(crate) const DATE_WEEKDAY_SUNDAY_BASED: &CStr = c"\
+The day of the week as an integer, from 0 (Sunday)
+to 6 (Saturday).
+
+This is the convention of C's ``tm_wday`` and JavaScript's
+``Date.getDay()``.
+
+Example
+-------
+>>> Date(2021, 1, 2).weekday_sunday_based()
+6
+>>> Date(2021, 1, 3).weekday_sunday_based()
+0
+";
+pub(crate) const DATE_WEEKDAYS_IN_MONTH: &CStr = c"\
+All dates in this date's month that fall on the given weekday
+
+Example
+-------
+>>> Date(2024, 3, 15).weekdays_in_month(Weekday.MONDAY)
+[Date(2024-03-04), Date(2024-03-11), Date(2024-03-18), Date(2024-03-25)]
+";
+pub(crate) const DATE_WEEKS_IN_YEAR: &CStr = c"\
+The number of ISO 8601 weeks in this date's year: 52 or 53
+
+Equivalent to ``Date.iso_weeks_in_year(d.year)``.
+Note that this uses the calendar year, which may differ from
+the ISO week-numbering year for dates in late December
+or early January.
+
+Example
+-------
+>>> Date(2015, 6, 1).weeks_in_year()
+53
+>>> Date(2016, 6, 1).weeks_in_year()
+52
+";
+pub(crate) const DATE_WITH_DAY: &CStr = c"\
+Create a new date with the day replaced.
+Like ``replace(day=day)``, but faster.
+
+Raises ``ValueError`` if the day doesn't exist in the month.
+
+Example
+-------
+>>> Date(2024, 3, 15).with_day(1)
+Date(2024-03-01)
+";
+pub(crate) const DATE_WITH_MONTH: &CStr = c"\
+Create a new date with the month replaced.
+Like ``replace(month=month)``, but faster.
+
+Raises ``ValueError`` if the day doesn't exist in the resulting
+month (e.g. the 31st in April). Use
+``replace(month=month, on_invalid=\"clamp\")`` to get
+the last day of the month instead.
+
+Example
+-------
+>>> Date(2024, 3, 15).with_month(4)
+Date(2024-04-15)
+>>> Date(2024, 3, 31).with_month(4)
+ValueError  # April has 30 days
+";
+pub(crate) const DATE_WITH_YEAR: &CStr = c"\
+Create a new date with the year replaced.
+Like ``replace(year=year)``, but faster.
+
+Raises ``ValueError`` if the resulting date doesn't exist
+(i.e. February 29th in a non-leap year).
+
+Example
+-------
+>>> Date(2024, 3, 15).with_year(2025)
+Date(2025-03-15)
+";
 pub(crate) const DATE_YEAR_MONTH: &CStr = c"\
 The year and month (without a day component)
 
@@ -393,11 +822,17 @@ Example
 pub(crate) const DATEDELTA_IN_YEARS_MONTHS_DAYS: &CStr = c"\
 Convert to a tuple of years, months, and days.
 
+The months are split into whole years and the remaining 0-11 months,
+both with the same sign. Days are never carried over into months,
+since months differ in length.
+
 Example
 -------
 >>> p = DateDelta(years=1, months=2, days=11)
 >>> p.in_years_months_days()
 (1, 2, 11)
+>>> DateDelta(months=-14, days=-40).in_years_months_days()
+(-1, -2, -40)
 ";
 pub(crate) const DATEDELTA_PARSE_COMMON_ISO: &CStr = c"\
 Parse the *popular interpretation* of the ISO 8601 duration format.
@@ -490,6 +925,49 @@ Add a time amount to this instant.
 
 See the `docs on arithmetic <https://whenever.readthedocs.io/en/latest/overview.html#arithmetic>`_ for more information.
 ";
+pub(crate) const INSTANT_DIFFERENCE: &CStr = c"\
+difference($self, other, /, *, unit='nanosecond', increment=1, mode='half_even')
+--
+
+Calculate the difference between two instants in time,
+optionally rounded to the given unit and increment.
+
+Without rounding, this is equivalent to :meth:`__sub__`.
+With rounding, it's equivalent to ``(a - b).round(...)``.
+As with :meth:`round`, ``unit=\"day\"`` isn't accepted, since days
+don't have a fixed length. Use ``unit=\"hour\", increment=24``
+to round to 24-hour days.
+
+Example
+-------
+>>> a = Instant.from_utc(2020, 8, 15, 23, 12)
+>>> b = Instant.from_utc(2020, 8, 14, 20, 50, 30)
+>>> a.difference(b)
+TimeDelta(26:21:30)
+>>> a.difference(b, unit=\"hour\", mode=\"floor\")
+TimeDelta(26:00:00)
+>>> b.difference(a, unit=\"hour\", mode=\"floor\")
+TimeDelta(-27:00:00)
+
+See :ref:`the docs on arithmetic <arithmetic>` for more information.
+";
+pub(crate) const INSTANT_ELAPSED_SINCE: &CStr = c"\
+Calculate the time elapsed since an earlier moment.
+
+Like :meth:`difference`, but raises :class:`ValueError` if ``other``
+is later than this instant, instead of returning a negative delta.
+Use it for measurements that are ordered by design
+(e.g. time since startup), so that swapped arguments
+or clock issues are caught early.
+
+Example
+-------
+>>> start = Instant.from_utc(2020, 8, 15, 23, 12)
+>>> Instant.from_utc(2020, 8, 15, 23, 14).elapsed_since(start)
+TimeDelta(00:02:00)
+>>> start.elapsed_since(Instant.from_utc(2020, 8, 15, 23, 14))
+ValueError  # argument is later
+";
 pub(crate) const INSTANT_FORMAT_COMMON_ISO: &CStr = c"\
 Convert to the popular ISO format ``YYYY-MM-DDTHH:MM:SSZ``
 
@@ -506,16 +984,27 @@ Example
 \"Sat, 15 Aug 2020 23:12:00 GMT\"
 ";
 pub(crate) const INSTANT_FORMAT_RFC3339: &CStr = c"\
+format_rfc3339($self, *, fractional_digits=None)
+--
+
 Format as an RFC 3339 string ``YYYY-MM-DD HH:MM:SSZ``
 
 If you prefer the ``T`` separator, use `format_common_iso()` instead.
 
+By default, the fractional seconds are shown only as far as needed.
+Pass ``fractional_digits`` (0, 3, 6, or 9) to always show
+exactly that many digits. Extra digits are truncated, not rounded.
+
 The inverse of the ``parse_rfc3339()`` method.
 
 Example
 -------
 >>> Instant.from_utc(2020, 8, 15, hour=23, minute=12).format_rfc3339()
 \"2020-08-15 23:12:00Z\"
+>>> Instant.from_utc(
+...     2020, 8, 15, hour=23, minute=12, nanosecond=9_999
+... ).format_rfc3339(fractional_digits=6)
+\"2020-08-15 23:12:00.000009Z\"
 ";
 pub(crate) const INSTANT_FROM_PY_DATETIME: &CStr = c"\
 Create an Instant from a standard library ``datetime`` object.
@@ -543,8 +1032,35 @@ from_utc(year, month, day, hour=0, minute=0, second=0, *, nanosecond=0)
 --
 
 Create an Instant defined by a UTC date and time.";
+pub(crate) const INSTANT_IS_BETWEEN: &CStr = c"\
+is_between($self, start, end, /, *, inclusive='[)')
+--
+
+Check whether this instant lies between two moments.
+
+By default, ``start`` is included and ``end`` is excluded.
+Use ``inclusive`` to set whether each endpoint is included:
+``\"[]\"``, ``\"[)\"``, ``\"(]\"``, or ``\"()\"``.
+If ``start`` is after ``end``, the result is always ``False``.
+
+Example
+-------
+>>> start = Instant.from_utc(2020, 8, 15, 12)
+>>> end = Instant.from_utc(2020, 8, 15, 14)
+>>> Instant.from_utc(2020, 8, 15, 13).is_between(start, end)
+True
+>>> end.is_between(start, end)
+False
+>>> end.is_between(start, end, inclusive=\"[]\")
+True
+";
 pub(crate) const INSTANT_NOW: &CStr = c"\
-Create an Instant from the current time.";
+Create an Instant from the current time.
+
+The system's realtime clock is read with the full
+nanosecond precision it offers (i.e. not truncated to microseconds
+like :meth:`datetime.datetime.now`).
+";
 pub(crate) const INSTANT_PARSE_COMMON_ISO: &CStr = c"\
 Parse the popular ISO format ``YYYY-MM-DDTHH:MM:SSZ``
 
@@ -649,6 +1165,35 @@ during DST transitions, pass ``ignore_dst=True``.
 See `the documentation <https://whenever.rtfd.io/en/latest/overview.html#dst-safe-arithmetic>`_
 for more information.
 ";
+pub(crate) const LOCALDATETIME_ADD_DAYS: &CStr = c"\
+Add a number of days, keeping the time of day the same.
+Equivalent to ``add(days=n)``.
+
+Example
+-------
+>>> LocalDateTime(2020, 2, 28, 12).add_days(2)
+LocalDateTime(2020-03-01 12:00:00)
+";
+pub(crate) const LOCALDATETIME_ADD_MONTHS: &CStr = c"\
+Add a number of months, keeping the time of day the same.
+Equivalent to ``add(months=n)``.
+If the day doesn't exist in the resulting month,
+it's clamped to the last day of that month.
+
+Example
+-------
+>>> LocalDateTime(2020, 1, 31, 12).add_months(1)
+LocalDateTime(2020-02-29 12:00:00)
+";
+pub(crate) const LOCALDATETIME_AS_TUPLE: &CStr = c"\
+Convert to a tuple of
+(year, month, day, hour, minute, second, nanosecond).
+
+Example
+-------
+>>> LocalDateTime(2020, 8, 15, 23, 12, nanosecond=5).as_tuple()
+(2020, 8, 15, 23, 12, 0, 5)
+";
 pub(crate) const LOCALDATETIME_ASSUME_FIXED_OFFSET: &CStr = c"\
 Assume the datetime has the given offset, creating an ``OffsetDateTime``.
 
@@ -708,6 +1253,18 @@ Example
 >>> LocalDateTime(2020, 8, 15, 23, 12).assume_utc()
 Instant(2020-08-15 23:12:00Z)
 ";
+pub(crate) const LOCALDATETIME_COMBINE: &CStr = c"\
+combine(date, time, /)
+--
+
+Create an instance from a date and a time.
+Equivalent to ``date.at(time)``.
+
+Example
+-------
+>>> LocalDateTime.combine(Date(2021, 1, 2), Time(12, 30))
+LocalDateTime(2021-01-02 12:30:00)
+";
 pub(crate) const LOCALDATETIME_DIFFERENCE: &CStr = c"\
 difference($self, other, /, *, ignore_dst=False)
 --
@@ -729,8 +1286,70 @@ Convert to the popular ISO format ``YYYY-MM-DDTHH:MM:SS``
 
 The inverse of the ``parse_common_iso()`` method.
 ";
+pub(crate) const LOCALDATETIME_FROM_ISO_WEEK_DATETIME: &CStr = c"\
+from_iso_week_datetime(year, week, weekday, hour=0, minute=0, second=0, *, nanosecond=0)
+--
+
+Create from an ISO 8601 week date and a time of day.
+
+The weekday counts from 1 (Monday) to 7 (Sunday).
+Inverse of :meth:`iso_year_week_day`.
+
+Example
+-------
+>>> LocalDateTime.from_iso_week_datetime(2020, 53, 6, 23, 12)
+LocalDateTime(2021-01-02 23:12:00)
+";
 pub(crate) const LOCALDATETIME_FROM_PY_DATETIME: &CStr = c"\
 Create an instance from a \"naive\" standard library ``datetime`` object";
+pub(crate) const LOCALDATETIME_FROM_TIMESTAMP: &CStr = c"\
+from_timestamp(i, /, *, tz, nanosecond=0)
+--
+
+Create an instance from a UNIX timestamp (in seconds),
+as it would appear on the wall clock in the given timezone.
+
+The timezone's offset at that moment (including DST) is applied,
+after which the timezone is discarded.
+The optional ``nanosecond`` is added to the timestamp,
+for sub-second precision without the rounding of a float.
+
+Example
+-------
+>>> LocalDateTime.from_timestamp(1_597_493_520, tz=\"Europe/Amsterdam\")
+LocalDateTime(2020-08-15 14:12:00)
+>>> LocalDateTime.from_timestamp(0, tz=\"Asia/Tokyo\", nanosecond=5)
+LocalDateTime(1970-01-01 09:00:00.000000005)
+";
+pub(crate) const LOCALDATETIME_IS_VALID_IN_TZ: &CStr = c"\
+Whether this datetime exists in the given timezone.
+
+Returns ``False`` if the datetime falls in a gap
+(e.g. when the clock is set forward for DST).
+Ambiguous datetimes (in a fold) are considered valid.
+
+Example
+-------
+>>> d = LocalDateTime(2023, 3, 26, 2, 15)
+>>> d.is_valid_in_tz(\"Europe/Amsterdam\")
+False
+>>> d.is_valid_in_tz(\"America/New_York\")
+True
+";
+pub(crate) const LOCALDATETIME_ISO_YEAR_WEEK_DAY: &CStr = c"\
+Convert to a tuple of (ISO year, ISO week, ISO weekday,
+hour, minute, second, nanosecond).
+
+The weekday counts from 1 (Monday) to 7 (Sunday).
+Note that near New Year, the ISO year may differ
+from the calendar year.
+Inverse of :meth:`from_iso_week_datetime`.
+
+Example
+-------
+>>> LocalDateTime(2021, 1, 2, 23, 12).iso_year_week_day()
+(2020, 53, 6, 23, 12, 0, 0)
+";
 pub(crate) const LOCALDATETIME_PARSE_COMMON_ISO: &CStr = c"\
 Parse the popular ISO format ``YYYY-MM-DDTHH:MM:SS``
 
@@ -805,6 +1424,34 @@ during DST transitions, pass ``ignore_dst=True``.
 See `the documentation <https://whenever.rtfd.io/en/latest/overview.html#dst-safe-arithmetic>`_
 for more information.
 ";
+pub(crate) const LOCALDATETIME_TO_INSTANT: &CStr = c"\
+Get the ``Instant`` for this datetime at the given offset.
+
+Equivalent to ``assume_fixed_offset(offset).to_instant()``,
+but without creating an intermediate ``OffsetDateTime``.
+
+Example
+-------
+>>> LocalDateTime(2020, 8, 15, 23, 12).to_instant(+2)
+Instant(2020-08-15 21:12:00Z)
+";
+pub(crate) const LOCALDATETIME_TO_SYSTEM_OFFSET: &CStr = c"\
+to_system_offset($self, *, disambiguate='compatible')
+--
+
+Assume the datetime is in the system timezone,
+and return it as an ``OffsetDateTime`` with the resulting offset.
+
+Equivalent to ``assume_system_tz(...).to_fixed_offset()``.
+Ambiguity is handled the same way as in :meth:`assume_system_tz`.
+
+Example
+-------
+>>> d = LocalDateTime(2020, 8, 15, 23, 12)
+>>> # assuming system timezone is America/New_York
+>>> d.to_system_offset(disambiguate=\"raise\")
+OffsetDateTime(2020-08-15 23:12:00-04:00)
+";
 pub(crate) const MONTHDAY_FORMAT_COMMON_ISO: &CStr = c"\
 Format as the common ISO 8601 month-day format.
 
@@ -1098,6 +1745,20 @@ Note
 * This method has similar behavior to the ``round()`` method of
   Temporal objects in JavaScript.
 ";
+pub(crate) const OFFSETDATETIME_ROUND_OFFSET_TO_MINUTES: &CStr = c"\
+Round the offset to the nearest whole minute,
+adjusting the local time so that the exact time is preserved.
+
+Seconds in offsets mostly occur in historical timezone data.
+Exactly 30 seconds is rounded away from zero.
+
+Example
+-------
+>>> offset = TimeDelta(minutes=19, seconds=32)
+>>> d = OffsetDateTime(1900, 1, 1, 12, offset=offset)
+>>> d.round_offset_to_minutes()
+OffsetDateTime(1900-01-01 12:00:28+00:20)
+";
 pub(crate) const OFFSETDATETIME_STRPTIME: &CStr = c"\
 strptime(s, /, fmt)
 --
@@ -1297,6 +1958,19 @@ specify how to handle such a situation using the ``disambiguate`` argument.
 See `the documentation <https://whenever.rtfd.io/en/latest/overview.html#arithmetic>`_
 for more information.
 ";
+pub(crate) const TIME_ADD: &CStr = c"\
+Add a time delta, wrapping around midnight.
+Returns the resulting time and the number of days carried over.
+
+Use the ``+`` operator if you only need the resulting time.
+
+Example
+-------
+>>> Time(23, 30).add(hours(2))
+(Time(01:30:00), 1)
+>>> Time(1, 30).add(hours(-50))
+(Time(23:30:00), -3)
+";
 pub(crate) const TIME_FORMAT_COMMON_ISO: &CStr = c"\
 Format as the common ISO 8601 time format.
 
@@ -1317,6 +1991,21 @@ Time(12:30:00)
 
 `fold` value is ignored.
 ";
+pub(crate) const TIME_NOW_IN_SYSTEM_TZ: &CStr = c"\
+The current time of day in the system timezone
+
+Equivalent to ``SystemDateTime.now().time()``
+";
+pub(crate) const TIME_NOW_IN_TZ: &CStr = c"\
+The current time of day in the given timezone
+
+Equivalent to ``ZonedDateTime.now(tz).time()``
+
+Example
+-------
+>>> Time.now_in_tz(\"Europe/Paris\")
+Time(14:02:11.034903)
+";
 pub(crate) const TIME_ON: &CStr = c"\
 Combine a time with a date to create a datetime
 
@@ -1370,6 +2059,74 @@ Time(12:45:00)
 >>> Time(8, 9, 13).round(\"second\", 5, mode=\"floor\")
 Time(08:09:10)
 ";
+pub(crate) const TIME_ROUND_WITH_CARRY: &CStr = c"\
+round_with_carry($self, unit='second', increment=1, mode='half_even')
+--
+
+Round the time like :meth:`round`, and also return
+whether the rounding carried over into the next day (0 or 1).
+
+Useful for adjusting an accompanying date.
+
+Example
+-------
+>>> Time(12, 39, 59).round_with_carry(\"minute\", 15)
+(Time(12:45:00), 0)
+>>> Time(23, 59, 59, nanosecond=900_000_000).round_with_carry()
+(Time(00:00:00), 1)
+";
+pub(crate) const TIME_SUBTRACT: &CStr = c"\
+Subtract a time delta, wrapping around midnight.
+Returns the resulting time and the number of days carried over.
+
+Use the ``-`` operator if you only need the resulting time.
+
+Example
+-------
+>>> Time(1, 30).subtract(hours(2))
+(Time(23:30:00), -1)
+";
+pub(crate) const TIME_TOTAL_SECONDS_FLOAT: &CStr = c"\
+The number of seconds since midnight, as a float
+
+Note
+----
+A float can't represent every nanosecond value exactly.
+The result is the nearest float, which is accurate
+to within about 0.01 nanoseconds.
+
+Example
+-------
+>>> Time(1, 2, 3, nanosecond=500_000_000).total_seconds_float()
+3723.5
+";
+pub(crate) const TIMEDELTA_AS_TUPLE: &CStr = c"\
+Convert to a tuple of
+(sign, hours, minutes, seconds, nanoseconds).
+
+The sign is -1, 0, or 1. All other components are non-negative,
+which makes it easy to build strings like ``\"2h 30m ago\"``.
+
+Example
+-------
+>>> d = TimeDelta(hours=-1, minutes=-30, microseconds=-5_000_090)
+>>> d.as_tuple()
+(-1, 1, 30, 5, 90000)
+";
+pub(crate) const TIMEDELTA_CLAMP: &CStr = c"\
+clamp($self, lower, upper, /)
+--
+
+Limit the delta to the range from ``lower`` to ``upper``
+(inclusive). Raises :class:`ValueError` if ``lower > upper``.
+
+Example
+-------
+>>> hours(3).clamp(minutes(1), hours(1))
+TimeDelta(01:00:00)
+>>> seconds(-5).clamp(TimeDelta.ZERO, hours(1))
+TimeDelta(00:00:00)
+";
 pub(crate) const TIMEDELTA_FORMAT_COMMON_ISO: &CStr = c"\
 Format as the *popular interpretation* of the ISO 8601 duration format.
 May not strictly adhere to (all versions of) the standard.
@@ -1392,6 +2149,30 @@ Example
 >>> TimeDelta.from_py_timedelta(timedelta(seconds=5400))
 TimeDelta(01:30:00)
 ";
+pub(crate) const TIMEDELTA_FROM_UNITS: &CStr = c"\
+from_units(*, weeks=0, days=0, hours=0, minutes=0, seconds=0, milliseconds=0, microseconds=0, nanoseconds=0)
+--
+
+Create from any combination of units,
+like the :class:`~datetime.timedelta` constructor.
+
+Floats are converted exactly, based on their shortest
+decimal representation. Amounts that don't add up to
+a whole number of nanoseconds raise a :exc:`ValueError`.
+
+Example
+-------
+>>> TimeDelta.from_units(days=1.5, minutes=30)
+TimeDelta(36:30:00)
+>>> TimeDelta.from_units(hours=0.1)
+TimeDelta(00:06:00)
+
+Note
+----
+Days and weeks are always exactly 24 and 7×24 hours long.
+To add calendar days, which may be longer or shorter due to DST,
+use :class:`DateDelta` instead.
+";
 pub(crate) const TIMEDELTA_IN_DAYS_OF_24H: &CStr = c"\
 The total size in days (of exactly 24 hours each)
 
@@ -1457,6 +2238,21 @@ Example
 >>> d.in_seconds()
 121.5
 ";
+pub(crate) const TIMEDELTA_ISCLOSE: &CStr = c"\
+isclose($self, other, /, *, abs_tol)
+--
+
+Whether the delta differs from another by at most ``abs_tol``
+
+Like :func:`math.isclose`, but for deltas and with only an
+absolute tolerance.
+
+Example
+-------
+>>> d = TimeDelta(seconds=1, nanoseconds=400)
+>>> d.isclose(seconds(1), abs_tol=microseconds(1))
+True
+";
 pub(crate) const TIMEDELTA_PARSE_COMMON_ISO: &CStr = c"\
 Parse the *popular interpretation* of the ISO 8601 duration format.
 Does not parse all possible ISO 8601 durations.
@@ -1506,6 +2302,21 @@ TimeDelta(03:26:00)
 >>> t.round(\"second\", increment=10, mode=\"floor\")
 Time(03:25:40)
 ";
+pub(crate) const TIMEDELTA_WHOLE_DAYS_OF_24H: &CStr = c"\
+The number of complete days (of exactly 24 hours each),
+truncated toward zero.
+
+Like :meth:`in_days_of_24h`, but as an integer.
+Negative deltas are truncated toward zero as well,
+so -1.5 days becomes -1.
+
+Example
+-------
+>>> TimeDelta(hours=36).whole_days_of_24h()
+1
+>>> TimeDelta(hours=-36).whole_days_of_24h()
+-1
+";
 pub(crate) const YEARMONTH_FORMAT_COMMON_ISO: &CStr = c"\
 Format as the common ISO 8601 year-month format.
 
@@ -1625,6 +2436,17 @@ Create an instance from a UNIX timestamp (in nanoseconds).
 
 The inverse of the ``timestamp_nanos()`` method.
 ";
+pub(crate) const ZONEDDATETIME_IN_UTC: &CStr = c"\
+Convert to an OffsetDateTime in UTC, representing
+the same moment in time.
+
+Equivalent to ``to_fixed_offset(0)``.
+
+Example
+-------
+>>> ZonedDateTime(2020, 8, 15, 23, tz=\"Europe/London\").in_utc()
+OffsetDateTime(2020-08-15 22:00:00+00:00)
+";
 pub(crate) const ZONEDDATETIME_IS_AMBIGUOUS: &CStr = c"\
 Whether the local time is ambiguous, e.g. due to a DST transition.
 
@@ -1687,7 +2509,7 @@ Construct a new instance with the time replaced.
 See the ``replace()`` method for more information.
 ";
 pub(crate) const ZONEDDATETIME_ROUND: &CStr = c"\
-round($self, unit='second', increment=1, mode='half_even')
+round($self, unit='second', increment=1, mode='half_even', *, disambiguate=None)
 --
 
 Round the datetime to the specified unit and increment.
@@ -1703,8 +2525,13 @@ ZonedDateTime(2020-08-15 23:15:00+02:00[Europe/Paris])
 
 Notes
 -----
-* In the rare case that rounding results in an ambiguous time,
-  the offset is preserved if possible.
+* Rounding is done on the local (wall clock) time, not the exact time.
+  Around a DST transition, the rounded time may therefore not be
+  the nearest in exact time.
+* In the rare case that rounding results in an ambiguous
+  or non-existent time, it is resolved according to
+  the ``disambiguate`` argument.
+  If not given, the offset is preserved if possible.
   Otherwise, the time is resolved according to the \"compatible\" strategy.
 * Rounding in \"day\" mode may be affected by DST transitions.
   i.e. on 23-hour days, 11:31 AM is rounded up.
@@ -1712,10 +2539,29 @@ Notes
   Temporal objects in JavaScript.
 ";
 pub(crate) const ZONEDDATETIME_START_OF_DAY: &CStr = c"\
+start_of_day($self, *, disambiguate='compatible')
+--
+
 The start of the current calendar day.
 
 This is almost always at midnight the same day, but may be different
 for timezones which transition at—and thus skip over—midnight.
+
+The ``disambiguate`` argument determines how a skipped or repeated
+midnight is handled. It defaults to ``\"compatible\"``, which
+moves a skipped midnight forward to the end of the gap
+(e.g. 01:00), and picks the first of two repeated midnights.
+Note that ``\"earlier\"`` resolves a skipped midnight to
+a time on the *previous* day.
+See :ref:`ambiguity <ambiguity>` for more information.
+
+Example
+-------
+>>> d = ZonedDateTime(2018, 11, 4, 12, tz=\"America/Sao_Paulo\")
+>>> d.start_of_day()
+ZonedDateTime(2018-11-04 01:00:00-02:00[America/Sao_Paulo])
+>>> d.start_of_day(disambiguate=\"earlier\")
+ZonedDateTime(2018-11-03 23:00:00-03:00[America/Sao_Paulo])
 ";
 pub(crate) const ZONEDDATETIME_SUBTRACT: &CStr = c"\
 subtract($self, delta=None, /, *, years=0, months=0, weeks=0, days=0, hours=0, minutes=0, seconds=0, milliseconds=0, microseconds=0, nanoseconds=0, disambiguate=None)
@@ -1759,6 +2605,10 @@ Note
 If ``a.exact_eq(b)`` is true, then
 ``a == b`` is also true, but the converse is not necessarily true.
 
+For :class:`ZonedDateTime`, the timezone and offset must match too.
+This distinguishes the two occurrences of a time repeated
+by a DST fold, as well as zones which happen to share an offset.
+
 Examples
 --------
 
@@ -1770,6 +2620,11 @@ True  # equivalent instants
 False  # different values (hour and offset)
 >>> a.exact_eq(Instant.now())
 TypeError  # different types
+>>> c = ZonedDateTime(2020, 8, 15, hour=12, tz=\"Europe/Paris\")
+>>> c == c.to_tz(\"Europe/Amsterdam\")
+True  # same moment
+>>> c.exact_eq(c.to_tz(\"Europe/Amsterdam\"))
+False  # different timezones
 ";
 pub(crate) const KNOWSINSTANT_TIMESTAMP: &CStr = c"\
 The UNIX timestamp for this datetime. Inverse of :meth:`from_timestamp`.
@@ -1829,6 +2684,18 @@ As an inverse, :class:`LocalDateTime` has methods
 , :meth:`~LocalDateTime.assume_tz`, and :meth:`~LocalDateTime.assume_system_tz`
 which may require additional arguments.
 ";
+pub(crate) const KNOWSINSTANTANDLOCAL_TO_INSTANT: &CStr = c"\
+Get the underlying instant in time.
+Alias for :meth:`instant`, named consistently with
+:meth:`~_KnowsInstant.to_tz` and :meth:`~_KnowsInstant.to_fixed_offset`.
+
+Example
+-------
+
+>>> d = OffsetDateTime(2020, 8, 15, hour=23, offset=2)
+>>> d.to_instant()
+Instant(2020-08-15 21:00:00Z)
+";
 pub(crate) const KNOWSLOCAL_DATE: &CStr = c"\
 The date part of the datetime
 
@@ -1862,10 +2729,11 @@ like :meth:`~LocalDateTime.assume_utc` or
 ";
 pub(crate) const ADJUST_LOCAL_DATETIME_MSG: &str = "Adjusting a local datetime by time units (e.g. hours and minutess) ignores DST and other timezone changes. To perform DST-safe operations, convert to a ZonedDateTime first. Or, if you don't know the timezone and accept potentially incorrect results during DST transitions, pass `ignore_dst=True`. For more information, see whenever.rtfd.io/en/latest/overview.html#dst-safe-arithmetic";
 pub(crate) const ADJUST_OFFSET_DATETIME_MSG: &str = "Adjusting a fixed offset datetime implicitly ignores DST and other timezone changes. To perform DST-safe operations, convert to a ZonedDateTime first. Or, if you don't know the timezone and accept potentially incorrect results during DST transitions, pass `ignore_dst=True`. For more information, see whenever.rtfd.io/en/latest/overview.html#dst-safe-arithmetic";
-pub(crate) const CANNOT_ROUND_DAY_MSG: &str = "Cannot round to day, because days do not have a fixed length. Due to daylight saving time, some days have 23 or 25 hours.If you wish to round to exaxtly 24 hours, use `round('hour', increment=24)`.";
+pub(crate) const CANNOT_ROUND_DAY_MSG: &str = "Cannot round to day, because days do not have a fixed length. Due to daylight saving time, some days have 23 or 25 hours. If you wish to round to exactly 24 hours, use `round('hour', increment=24)`.";
 pub(crate) const DIFF_LOCAL_MSG: &str = "The difference between two local datetimes implicitly ignores DST transitions and other timezone changes. To perform DST-safe operations, convert to a ZonedDateTime first. Or, if you don't know the timezone and accept potentially incorrect results during DST transitions, pass `ignore_dst=True`. For more information, see whenever.rtfd.io/en/latest/overview.html#dst-safe-arithmetic";
 pub(crate) const DIFF_OPERATOR_LOCAL_MSG: &str = "The difference between two local datetimes implicitly ignores DST transitions and other timezone changes. Use the `difference` method instead.";
 pub(crate) const OFFSET_NOW_DST_MSG: &str = "Getting the current time with a fixed offset implicitly ignores DST and other timezone changes. Instead, use `Instant.now()` or `ZonedDateTime.now(<tz name>)` if you know the timezone. Or, if you want to ignore DST and accept potentially incorrect offsets, pass `ignore_dst=True` to this method. For more information, see whenever.rtfd.io/en/latest/overview.html#dst-safe-arithmetic";
 pub(crate) const OFFSET_ROUNDING_DST_MSG: &str = "Rounding a fixed offset datetime may (in rare cases) result in a datetime for which the offset is incorrect. This is because the offset may change during DST transitions. To perform DST-safe rounding, convert to a ZonedDateTime first. Or, if you don't know the timezone and accept potentially incorrect results during DST transitions, pass `ignore_dst=True`. For more information, see whenever.rtfd.io/en/latest/overview.html#dst-safe-arithmetic";
+pub(crate) const SHIFT_DATE_MSG: &str = "A Date has no time component, so it can't be shifted by a TimeDelta. Use `.at(time)` to create a LocalDateTime first, or use a DateDelta to shift by days.";
 pub(crate) const SHIFT_LOCAL_MSG: &str = "Adding or subtracting a (date)time delta to a local datetime implicitly ignores DST transitions and other timezone changes. Use the `add` or `subtract` method instead.";
 pub(crate) const TIMESTAMP_DST_MSG: &str = "Converting from a timestamp with a fixed offset implicitly ignores DST and other timezone changes. To perform a DST-safe conversion, use ZonedDateTime.from_timestamp() instead. Or, if you don't know the timezone and accept potentially incorrect results during DST transitions, pass `ignore_dst=True`. For more information, see whenever.rtfd.io/en/latest/overview.html#dst-safe-arithmetic";
@@ -340,8 +340,8 @@ unsafe fn from_utc(cls: *mut PyTypeObject, args: *mut PyObject, kwargs: *mut PyO
     }
 
     Instant::from_datetime(
-        Date::from_longs(year, month, day).ok_or_value_err("Invalid date")?,
-        Time::from_longs(hour, minute, second, nanos).ok_or_value_err("Invalid time")?,
+        Date::from_longs_or_err(year, month, day, State::for_type(cls))?,
+        Time::from_longs(hour, minute, second, nanos)?,
     )
     .to_obj(cls)
 }
@@ -358,9 +358,52 @@ unsafe fn __str__(slf: *mut PyObject) -> PyReturn {
     format!("{}T{}Z", date, time).to_py()
 }
 
-unsafe fn format_rfc3339(slf: *mut PyObject, _: *mut PyObject) -> PyReturn {
+unsafe fn format_rfc3339(
+    slf: *mut PyObject,
+    cls: *mut PyTypeObject,
+    args: &[*mut PyObject],
+    kwargs: &mut KwargIter,
+) -> PyReturn {
+    if !args.is_empty() {
+        Err(type_err!("format_rfc3339() takes no positional arguments"))?
+    }
+    let str_fractional_digits = State::for_type(cls).str_fractional_digits;
+    let mut digits = None;
+    handle_kwargs("format_rfc3339", kwargs, |key, value, eq| {
+        if eq(key, str_fractional_digits) {
+            if !is_none(value) {
+                digits = Some(
+                    value
+                        .to_long()?
+                        .ok_or_type_err("fractional_digits must be an integer or None")?,
+                );
+            }
+            Ok(true)
+        } else {
+            Ok(false)
+        }
+    })?;
     let DateTime { date, time } = Instant::extract(slf).to_datetime();
-    format!("{} {}Z", date, time).to_py()
+    match digits {
+        None => format!("{} {}Z", date, time),
+        Some(n @ (0 | 3 | 6 | 9)) => {
+            let Time {
+                hour,
+                minute,
+                second,
+                nanos,
+            } = time;
+            let mut s = format!("{} {:02}:{:02}:{:02}", date, hour, minute, second);
+            if n > 0 {
+                // Truncate rather than round, so we never move into the next second
+                s.push_str(&format!(".{:09}", nanos)[..n as usize + 1]);
+            }
+            s.push('Z');
+            s
+        }
+        Some(_) => Err(value_err!("fractional_digits must be 0, 3, 6, or 9"))?,
+    }
+    .to_py()
 }
 
 unsafe fn __richcmp__(a_obj: *mut PyObject, b_obj: *mut PyObject, op: c_int) -> PyReturn {
@@ -495,7 +538,7 @@ unsafe fn exact_eq(obj_a: *mut PyObject, obj_b: *mut PyObject) -> PyReturn {
 
 unsafe fn __reduce__(slf: *mut PyObject, _: *mut PyObject) -> PyReturn {
     let Instant { secs, nanos } = Instant::extract(slf);
-    let data = pack![secs, nanos];
+    let data = pack![PICKLE_VERSION, secs, nanos];
     (
         State::for_obj(slf).unpickle_instant,
         steal!((steal!(data.to_py()?),).to_py()?),
@@ -504,10 +547,8 @@ unsafe fn __reduce__(slf: *mut PyObject, _: *mut PyObject) -> PyReturn {
 }
 
 pub(crate) unsafe fn unpickle(module: *mut PyObject, arg: *mut PyObject) -> PyReturn {
-    let mut packed = arg.to_bytes()?.ok_or_value_err("Invalid pickle data")?;
-    if packed.len() != 12 {
-        Err(value_err!("Invalid pickle data"))?;
-    }
+    let mut packed =
+        strip_pickle_version(arg.to_bytes()?.ok_or_value_err("Invalid pickle data")?, 12)?;
     Instant {
         secs: unpack_one!(packed, i64),
         nanos: unpack_one!(packed, u32),
@@ -699,10 +740,41 @@ unsafe fn _shift_method(
         .to_obj(cls)
 }
 
-unsafe fn difference(obj_a: *mut PyObject, obj_b: *mut PyObject) -> PyReturn {
+unsafe fn difference(
+    obj_a: *mut PyObject,
+    cls: *mut PyTypeObject,
+    args: &[*mut PyObject],
+    kwargs: &mut KwargIter,
+) -> PyReturn {
+    let state = State::for_type(cls);
+    let &[obj_b] = args else {
+        Err(type_err!(
+            "difference() takes exactly 1 positional argument but {} were given",
+            args.len()
+        ))?
+    };
+    let mut unit = None;
+    let mut increment = None;
+    let mut mode = None;
+    handle_kwargs("difference", kwargs, |key, value, eq| {
+        if eq(key, state.str_unit) {
+            unit = Some(value);
+        } else if eq(key, state.str_increment) {
+            increment = Some(value);
+        } else if eq(key, state.str_mode) {
+            mode = Some(value);
+        } else {
+            return Ok(false);
+        }
+        Ok(true)
+    })?;
+    let (unit, increment, mode) =
+        round::resolve_args(state, unit, increment, mode, true, round::Unit::Nanosecond)?;
+    if unit == round::Unit::Day {
+        Err(value_err!(doc::CANNOT_ROUND_DAY_MSG))?;
+    }
+
     let type_b = Py_TYPE(obj_b);
-    let type_a = Py_TYPE(obj_a);
-    let state = State::for_type(type_a);
     let inst_a = Instant::extract(obj_a);
     let inst_b = if type_b == Py_TYPE(obj_a) {
         Instant::extract(obj_b)
@@ -716,7 +788,96 @@ unsafe fn difference(obj_a: *mut PyObject, obj_b: *mut PyObject) -> PyReturn {
              Instant, ZonedDateTime, or SystemDateTime"
         ))?
     };
-    inst_a.diff(inst_b).to_obj(state.time_delta_type)
+    inst_a
+        .diff(inst_b)
+        .round(increment, mode)
+        .ok_or_value_err("Resulting TimeDelta out of range")?
+        .to_obj(state.time_delta_type)
+}
+
+unsafe fn elapsed_since(slf: &mut PyObject, obj_b: &mut PyObject) -> PyReturn {
+    let type_a = Py_TYPE(slf);
+    let state = State::for_type(type_a);
+    let type_b = Py_TYPE(obj_b);
+    let inst_b = if type_b == type_a {
+        Instant::extract(obj_b)
+    } else if type_b == state.zoned_datetime_type {
+        ZonedDateTime::extract(obj_b).instant()
+    } else if type_b == state.system_datetime_type || type_b == state.offset_datetime_type {
+        OffsetDateTime::extract(obj_b).instant()
+    } else {
+        Err(type_err!(
+            "elapsed_since() argument must be an OffsetDateTime, \
+             Instant, ZonedDateTime, or SystemDateTime"
+        ))?
+    };
+    let delta = Instant::extract(slf).diff(inst_b);
+    if delta.secs < 0 {
+        Err(value_err!(
+            "elapsed_since() argument is later than this instant"
+        ))?
+    }
+    delta.to_obj(state.time_delta_type)
+}
+
+unsafe fn is_between(
+    slf: *mut PyObject,
+    cls: *mut PyTypeObject,
+    args: &[*mut PyObject],
+    kwargs: &mut KwargIter,
+) -> PyReturn {
+    let state = State::for_type(cls);
+    let &[start_obj, end_obj] = args else {
+        Err(type_err!(
+            "is_between() takes exactly 2 positional arguments ({} given)",
+            args.len()
+        ))?
+    };
+    let mut closed = (true, false);
+    handle_kwargs("is_between", kwargs, |key, value, eq| {
+        if eq(key, state.str_inclusive) {
+            closed = match value
+                .to_utf8()?
+                .ok_or_type_err("inclusive must be a string")?
+            {
+                b"[]" => (true, true),
+                b"[)" => (true, false),
+                b"(]" => (false, true),
+                b"()" => (false, false),
+                _ => Err(value_err!(
+                    "inclusive must be one of '[]', '[)', '(]', or '()'"
+                ))?,
+            };
+        } else {
+            return Ok(false);
+        }
+        Ok(true)
+    })?;
+    let extract = |obj: *mut PyObject| {
+        let tp = Py_TYPE(obj);
+        if tp == cls {
+            Ok(Instant::extract(obj))
+        } else if tp == state.zoned_datetime_type {
+            Ok(ZonedDateTime::extract(obj).instant())
+        } else if tp == state.system_datetime_type || tp == state.offset_datetime_type {
+            Ok(OffsetDateTime::extract(obj).instant())
+        } else {
+            Err(type_err!(
+                "is_between() arguments must be OffsetDateTime, \
+                 Instant, ZonedDateTime, or SystemDateTime"
+            ))
+        }
+    };
+    let start = extract(start_obj)?;
+    let end = extract(end_obj)?;
+    let inst = Instant::extract(slf);
+    let after_start = if closed.0 {
+        start <= inst
+    } else {
+        start < inst
+    };
+    let before_end = if closed.1 { inst <= end } else { inst < end };
+    (after_start && before_end).to_py()
 }
 
 unsafe fn to_tz(slf: &mut PyObject, tz: &mut PyObject) -> PyReturn {
@@ -903,7 +1064,7 @@ static mut METHODS: &[PyMethodDef] = &[
         METH_O | METH_CLASS
     ),
     method!(now, doc::INSTANT_NOW, METH_CLASS | METH_NOARGS),
-    method!(format_rfc3339, doc::INSTANT_FORMAT_RFC3339),
+    method_kwargs!(format_rfc3339, doc::INSTANT_FORMAT_RFC3339),
     method!(
         parse_rfc3339,
         doc::INSTANT_PARSE_RFC3339,
@@ -926,7 +1087,9 @@ static mut METHODS: &[PyMethodDef] = &[
     method!(to_tz, doc::KNOWSINSTANT_TO_TZ, METH_O),
     method!(to_system_tz, doc::KNOWSINSTANT_TO_SYSTEM_TZ),
     method_vararg!(to_fixed_offset, doc::KNOWSINSTANT_TO_FIXED_OFFSET),
-    method!(difference, doc::KNOWSINSTANT_DIFFERENCE, METH_O),
+    method_kwargs!(difference, doc::INSTANT_DIFFERENCE),
+    method!(elapsed_since, doc::INSTANT_ELAPSED_SINCE, METH_O),
+    method_kwargs!(is_between, doc::INSTANT_IS_BETWEEN),
     method_kwargs!(round, doc::INSTANT_ROUND),
     PyMethodDef::zeroed(),
 ];
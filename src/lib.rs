@@ -9,6 +9,7 @@ use crate::common::*;
 mod common;
 pub mod date;
 mod date_delta;
+mod date_iterator;
 mod datetime_delta;
 mod round;
 #[rustfmt::skip] // this module is autogenerated. No need to format it.
@@ -336,6 +337,13 @@ unsafe extern "C" fn module_exec(module: *mut PyObject) -> c_int {
         return -1;
     }
 
+    // Not exposed in the module: only created by Date methods
+    state.date_iterator_type =
+        PyType_FromModuleAndSpec(module, ptr::addr_of_mut!(date_iterator::SPEC), NULL()).cast();
+    if state.date_iterator_type.is_null() {
+        return -1;
+    }
+
     let zoneinfo_module = PyImport_ImportModule(c"zoneinfo".as_ptr());
     defer_decref!(zoneinfo_module);
     state.zoneinfo_type = PyObject_GetAttrString(zoneinfo_module, c"ZoneInfo".as_ptr());
@@ -418,6 +426,14 @@ unsafe extern "C" fn module_exec(module: *mut PyObject) -> c_int {
     state.str_disambiguate = PyUnicode_InternFromString(c"disambiguate".as_ptr());
     state.str_offset = PyUnicode_InternFromString(c"offset".as_ptr());
     state.str_ignore_dst = PyUnicode_InternFromString(c"ignore_dst".as_ptr());
+    state.str_on_invalid = PyUnicode_InternFromString(c"on_invalid".as_ptr());
+    state.str_abs_tol = PyUnicode_InternFromString(c"abs_tol".as_ptr());
+    state.str_inclusive = PyUnicode_InternFromString(c"inclusive".as_ptr());
+    state.str_step_days = PyUnicode_InternFromString(c"step_days".as_ptr());
+    state.str_step_months = PyUnicode_InternFromString(c"step_months".as_ptr());
+    state.str_fractional_digits = PyUnicode_InternFromString(c"fractional_digits".as_ptr());
+    state.str_weekend = PyUnicode_InternFromString(c"weekend".as_ptr());
+    state.str_hemisphere = PyUnicode_InternFromString(c"hemisphere".as_ptr());
     state.str_unit = PyUnicode_InternFromString(c"unit".as_ptr());
     state.str_increment = PyUnicode_InternFromString(c"increment".as_ptr());
     state.str_mode = PyUnicode_InternFromString(c"mode".as_ptr());
@@ -435,6 +451,12 @@ unsafe extern "C" fn module_exec(module: *mut PyObject) -> c_int {
         doc::INVALIDOFFSET,
         PyExc_ValueError,
     );
+    state.exc_unsupported_year = new_exc(
+        module,
+        c"whenever.UnsupportedYear",
+        doc::UNSUPPORTEDYEAR,
+        PyExc_ValueError,
+    );
     state.exc_implicitly_ignoring_dst = new_exc(
         module,
         c"whenever.ImplicitlyIgnoringDST",
@@ -495,6 +517,7 @@ unsafe extern "C" fn module_traverse(
     let state = State::for_mod(module);
     // types
     traverse_type(state.date_type, visit, arg, date::SINGLETONS.len());
+    traverse_type(state.date_iterator_type, visit, arg, 0);
     traverse_type(
         state.yearmonth_type,
         visit,
@@ -556,6 +579,7 @@ unsafe extern "C" fn module_traverse(
     traverse(state.exc_repeated, visit, arg);
     traverse(state.exc_skipped, visit, arg);
     traverse(state.exc_invalid_offset, visit, arg);
+    traverse(state.exc_unsupported_year, visit, arg);
     traverse(state.exc_implicitly_ignoring_dst, visit, arg);
 
     // Imported modules
@@ -574,6 +598,7 @@ unsafe extern "C" fn module_clear(module: *mut PyObject) -> c_int {
     let state = PyModule_GetState(module).cast::<State>().as_mut().unwrap();
     // types
     Py_CLEAR(ptr::addr_of_mut!(state.date_type).cast());
+    Py_CLEAR(ptr::addr_of_mut!(state.date_iterator_type).cast());
     Py_CLEAR(ptr::addr_of_mut!(state.yearmonth_type).cast());
     Py_CLEAR(ptr::addr_of_mut!(state.monthday_type).cast());
     Py_CLEAR(ptr::addr_of_mut!(state.time_type).cast());
@@ -620,6 +645,14 @@ unsafe extern "C" fn module_clear(module: *mut PyObject) -> c_int {
     Py_CLEAR(ptr::addr_of_mut!(state.str_disambiguate));
     Py_CLEAR(ptr::addr_of_mut!(state.str_offset));
     Py_CLEAR(ptr::addr_of_mut!(state.str_ignore_dst));
+    Py_CLEAR(ptr::addr_of_mut!(state.str_on_invalid));
+    Py_CLEAR(ptr::addr_of_mut!(state.str_abs_tol));
+    Py_CLEAR(ptr::addr_of_mut!(state.str_inclusive));
+    Py_CLEAR(ptr::addr_of_mut!(state.str_step_days));
+    Py_CLEAR(ptr::addr_of_mut!(state.str_step_months));
+    Py_CLEAR(ptr::addr_of_mut!(state.str_fractional_digits));
+    Py_CLEAR(ptr::addr_of_mut!(state.str_weekend));
+    Py_CLEAR(ptr::addr_of_mut!(state.str_hemisphere));
     Py_CLEAR(ptr::addr_of_mut!(state.str_unit));
     Py_CLEAR(ptr::addr_of_mut!(state.str_increment));
     Py_CLEAR(ptr::addr_of_mut!(state.str_mode));
@@ -633,6 +666,7 @@ unsafe extern "C" fn module_clear(module: *mut PyObject) -> c_int {
     Py_CLEAR(ptr::addr_of_mut!(state.exc_repeated));
     Py_CLEAR(ptr::addr_of_mut!(state.exc_skipped));
     Py_CLEAR(ptr::addr_of_mut!(state.exc_invalid_offset));
+    Py_CLEAR(ptr::addr_of_mut!(state.exc_unsupported_year));
     Py_CLEAR(ptr::addr_of_mut!(state.exc_implicitly_ignoring_dst));
 
     // imported stuff
@@ -649,6 +683,7 @@ unsafe extern "C" fn module_clear(module: *mut PyObject) -> c_int {
 struct State {
     // types
     date_type: *mut PyTypeObject,
+    date_iterator_type: *mut PyTypeObject,
     yearmonth_type: *mut PyTypeObject,
     monthday_type: *mut PyTypeObject,
     time_type: *mut PyTypeObject,
@@ -668,6 +703,7 @@ struct State {
     exc_repeated: *mut PyObject,
     exc_skipped: *mut PyObject,
     exc_invalid_offset: *mut PyObject,
+    exc_unsupported_year: *mut PyObject,
     exc_implicitly_ignoring_dst: *mut PyObject,
 
     // unpickling functions
@@ -719,6 +755,14 @@ struct State {
     str_disambiguate: *mut PyObject,
     str_offset: *mut PyObject,
     str_ignore_dst: *mut PyObject,
+    str_on_invalid: *mut PyObject,
+    str_abs_tol: *mut PyObject,
+    str_inclusive: *mut PyObject,
+    str_step_days: *mut PyObject,
+    str_step_months: *mut PyObject,
+    str_fractional_digits: *mut PyObject,
+    str_weekend: *mut PyObject,
+    str_hemisphere: *mut PyObject,
     str_unit: *mut PyObject,
     str_increment: *mut PyObject,
     str_mode: *mut PyObject,
@@ -6,7 +6,7 @@ use crate::common::*;
 use crate::docstrings as doc;
 use crate::offset_datetime::check_ignore_dst_kwarg;
 use crate::{
-    date::{Date, MAX as MAX_DATE},
+    date::{check_common_era, iso_weeks_in, Date, MAX as MAX_DATE, MAX_YEAR},
     date_delta::DateDelta,
     datetime_delta::{set_units_from_kwargs, DateTimeDelta},
     instant::Instant,
@@ -177,8 +177,8 @@ unsafe fn __new__(cls: *mut PyTypeObject, args: *mut PyObject, kwargs: *mut PyOb
     }
 
     DateTime {
-        date: Date::from_longs(year, month, day).ok_or_type_err("Invalid date")?,
-        time: Time::from_longs(hour, minute, second, nanos).ok_or_type_err("Invalid time")?,
+        date: Date::from_longs_or_err(year, month, day, State::for_type(cls))?,
+        time: Time::from_longs(hour, minute, second, nanos)?,
     }
     .to_obj(cls)
 }
@@ -196,6 +196,40 @@ unsafe fn format_common_iso(slf: *mut PyObject, _: *mut PyObject) -> PyReturn {
     __str__(slf)
 }
 
+unsafe fn __format__(slf: *mut PyObject, spec_obj: *mut PyObject) -> PyReturn {
+    let spec = spec_obj
+        .to_utf8()?
+        .ok_or_type_err("format spec must be a string")?;
+    if spec.is_empty() {
+        return __str__(slf);
+    }
+    if !is_valid_strftime_spec(spec) {
+        Err(value_err!(
+            "Invalid format directive in {}",
+            spec_obj.repr()
+        ))?
+    }
+    let dt = py_datetime(slf, NULL())?;
+    defer_decref!(dt);
+    PyObject_Format(dt, spec_obj).as_result()
+}
+
+// Only allow the directives supported on all platforms,
+// except the locale's full formats and the (always empty) offset and timezone
+fn is_valid_strftime_spec(spec: &[u8]) -> bool {
+    let mut chars = spec.iter();
+    while let Some(&c) = chars.next() {
+        if c == b'%'
+            && !chars
+                .next()
+                .is_some_and(|d| b"aAwdbBmyYHIpMSfjUWGuV%".contains(d))
+        {
+            return false;
+        }
+    }
+    true
+}
+
 unsafe fn __richcmp__(a_obj: *mut PyObject, b_obj: *mut PyObject, op: c_int) -> PyReturn {
     Ok(if Py_TYPE(b_obj) == Py_TYPE(a_obj) {
         let a = DateTime::extract(a_obj);
@@ -388,8 +422,8 @@ unsafe fn replace(
         )
     })?;
     DateTime {
-        date: Date::from_longs(year, month, day).ok_or_value_err("Invalid date")?,
-        time: Time::from_longs(hour, minute, second, nanos).ok_or_value_err("Invalid time")?,
+        date: Date::from_longs_or_err(year, month, day, module)?,
+        time: Time::from_longs(hour, minute, second, nanos)?,
     }
     .to_obj(cls)
 }
@@ -490,6 +524,27 @@ unsafe fn _shift_method(
         .to_obj(cls)
 }
 
+unsafe fn add_days(slf: *mut PyObject, n: *mut PyObject) -> PyReturn {
+    let days = n.to_i64()?.ok_or_type_err("days must be an integer")?;
+    i32::try_from(days)
+        .ok()
+        .and_then(|d| DateTime::extract(slf).shift_date(0, d))
+        .ok_or_value_err("Resulting datetime out of range")?
+        .to_obj(Py_TYPE(slf))
+}
+
+unsafe fn add_months(slf: *mut PyObject, n: *mut PyObject) -> PyReturn {
+    let months = n.to_i64()?.ok_or_type_err("months must be an integer")?;
+    i32::try_from(months)
+        .ok()
+        // Anything beyond this is out of range anyway, and would otherwise
+        // risk overflowing the month arithmetic
+        .filter(|m| m.unsigned_abs() < MAX_YEAR as u32 * 12)
+        .and_then(|m| DateTime::extract(slf).shift_date(m, 0))
+        .ok_or_value_err("Resulting datetime out of range")?
+        .to_obj(Py_TYPE(slf))
+}
+
 unsafe fn difference(
     slf: *mut PyObject,
     cls: *mut PyTypeObject,
@@ -523,7 +578,16 @@ unsafe fn __reduce__(slf: *mut PyObject, _: *mut PyObject) -> PyReturn {
                 nanos,
             },
     } = DateTime::extract(slf);
-    let data = pack![year, month, day, hour, minute, second, nanos];
+    let data = pack![
+        PICKLE_VERSION,
+        year,
+        month,
+        day,
+        hour,
+        minute,
+        second,
+        nanos
+    ];
     (
         State::for_obj(slf).unpickle_local_datetime,
         steal!((steal!(data.to_py()?),).to_py()?),
@@ -532,10 +596,8 @@ unsafe fn __reduce__(slf: *mut PyObject, _: *mut PyObject) -> PyReturn {
 }
 
 pub(crate) unsafe fn unpickle(module: *mut PyObject, arg: *mut PyObject) -> PyReturn {
-    let mut packed = arg.to_bytes()?.ok_or_type_err("Invalid pickle data")?;
-    if packed.len() != 11 {
-        Err(type_err!("Invalid pickle data"))?
-    }
+    let mut packed =
+        strip_pickle_version(arg.to_bytes()?.ok_or_type_err("Invalid pickle data")?, 11)?;
     DateTime {
         date: Date {
             year: unpack_one!(packed, u16),
@@ -579,6 +641,64 @@ unsafe fn from_py_datetime(type_: *mut PyObject, dt: *mut PyObject) -> PyReturn
     .to_obj(type_.cast())
 }
 
+unsafe fn from_timestamp(
+    _: *mut PyObject,
+    cls: *mut PyTypeObject,
+    args: &[*mut PyObject],
+    kwargs: &mut KwargIter,
+) -> PyReturn {
+    let state = State::for_type(cls);
+    let &[ts_obj] = args else {
+        Err(type_err!(
+            "from_timestamp() takes exactly 1 positional argument ({} given)",
+            args.len()
+        ))?
+    };
+    let mut tz: *mut PyObject = NULL();
+    let mut nanos: i64 = 0;
+    handle_kwargs("from_timestamp", kwargs, |key, value, eq| {
+        if eq(key, state.str_tz) {
+            tz = value;
+        } else if eq(key, state.str_nanosecond) {
+            nanos = value
+                .to_i64_saturating()?
+                .ok_or_type_err("nanosecond must be an integer")?;
+        } else {
+            return Ok(false);
+        }
+        Ok(true)
+    })?;
+    if tz.is_null() {
+        Err(type_err!(
+            "from_timestamp() missing 1 required keyword-only argument: 'tz'"
+        ))?
+    }
+    if !(0..1_000_000_000).contains(&nanos) {
+        Err(value_err!("nanosecond must be in 0..999999999"))?
+    }
+    let zoneinfo = call1(state.zoneinfo_type, tz)?;
+    defer_decref!(zoneinfo);
+
+    match ts_obj.to_i64()? {
+        Some(ts) => Instant::from_timestamp(ts),
+        None => Instant::from_timestamp_f64(
+            ts_obj
+                .to_f64()?
+                .ok_or_type_err("Timestamp must be an integer or float")?,
+        ),
+    }
+    .and_then(|inst| {
+        inst.shift(TimeDelta {
+            secs: 0,
+            nanos: nanos as u32,
+        })
+    })
+    .ok_or_value_err("timestamp is out of range")?
+    .to_tz(state.py_api, zoneinfo)?
+    .without_offset()
+    .to_obj(cls)
+}
+
 unsafe fn py_datetime(slf: *mut PyObject, _: *mut PyObject) -> PyReturn {
     let DateTime {
         date: Date { year, month, day },
@@ -621,6 +741,110 @@ unsafe fn get_time(slf: *mut PyObject, _: *mut PyObject) -> PyReturn {
         .to_obj(State::for_obj(slf).time_type)
 }
 
+unsafe fn as_tuple(slf: *mut PyObject, _: *mut PyObject) -> PyReturn {
+    let DateTime {
+        date: Date { year, month, day },
+        time:
+            Time {
+                hour,
+                minute,
+                second,
+                nanos,
+            },
+    } = DateTime::extract(slf);
+    (
+        steal!(year.to_py()?),
+        steal!(month.to_py()?),
+        steal!(day.to_py()?),
+        steal!(hour.to_py()?),
+        steal!(minute.to_py()?),
+        steal!(second.to_py()?),
+        steal!(nanos.to_py()?),
+    )
+        .to_py()
+}
+
+unsafe fn iso_year_week_day(slf: *mut PyObject, _: *mut PyObject) -> PyReturn {
+    let DateTime {
+        date,
+        time:
+            Time {
+                hour,
+                minute,
+                second,
+                nanos,
+            },
+    } = DateTime::extract(slf);
+    let (year, week, weekday) = date.iso_week_date();
+    (
+        steal!(year.to_py()?),
+        steal!(week.to_py()?),
+        steal!(weekday.to_py()?),
+        steal!(hour.to_py()?),
+        steal!(minute.to_py()?),
+        steal!(second.to_py()?),
+        steal!(nanos.to_py()?),
+    )
+        .to_py()
+}
+
+unsafe fn from_iso_week_datetime(
+    cls: *mut PyTypeObject,
+    args: *mut PyObject,
+    kwargs: *mut PyObject,
+) -> PyReturn {
+    let mut year: c_long = 0;
+    let mut week: c_long = 0;
+    let mut weekday: c_long = 0;
+    let mut hour: c_long = 0;
+    let mut minute: c_long = 0;
+    let mut second: c_long = 0;
+    let mut nanos: c_long = 0;
+
+    if PyArg_ParseTupleAndKeywords(
+        args,
+        kwargs,
+        c"lll|lll$l:LocalDateTime.from_iso_week_datetime".as_ptr(),
+        arg_vec(&[
+            c"year",
+            c"week",
+            c"weekday",
+            c"hour",
+            c"minute",
+            c"second",
+            c"nanosecond",
+        ])
+        .as_mut_ptr(),
+        &mut year,
+        &mut week,
+        &mut weekday,
+        &mut hour,
+        &mut minute,
+        &mut second,
+        &mut nanos,
+    ) == 0
+    {
+        Err(py_err!())?
+    }
+    check_common_era(State::for_type(cls), year)?;
+    if year > MAX_YEAR {
+        Err(value_err!("year must be in 1..9999"))?
+    }
+    let weeks = iso_weeks_in(year as u16);
+    if !(1..=weeks.into()).contains(&week) {
+        Err(value_err!("week must be in 1..{} for year {}", weeks, year))?
+    }
+    if !(1..=7).contains(&weekday) {
+        Err(value_err!("weekday must be in 1..7"))?
+    }
+    DateTime {
+        date: Date::from_iso_week(year as u16, week as u8, weekday as u8)
+            .ok_or_value_err("Resulting date out of range")?,
+        time: Time::from_longs(hour, minute, second, nanos)?,
+    }
+    .to_obj(cls)
+}
+
 pub fn parse_date_and_time(s: &[u8]) -> Option<(Date, Time)> {
     // This should have already been checked by caller
     debug_assert!(
@@ -641,6 +865,31 @@ unsafe fn parse_common_iso(cls: *mut PyObject, arg: *mut PyObject) -> PyReturn {
     }
 }
 
+unsafe fn combine(cls: *mut PyObject, args: &[*mut PyObject]) -> PyReturn {
+    let &[date_obj, time_obj] = args else {
+        Err(type_err!(
+            "combine() takes exactly 2 arguments ({} given)",
+            args.len()
+        ))?
+    };
+    let &State {
+        date_type,
+        time_type,
+        ..
+    } = State::for_type(cls.cast());
+    if Py_TYPE(date_obj) != date_type {
+        Err(type_err!("date must be a whenever.Date instance"))?
+    }
+    if Py_TYPE(time_obj) != time_type {
+        Err(type_err!("time must be a whenever.Time instance"))?
+    }
+    DateTime {
+        date: Date::extract(date_obj),
+        time: Time::extract(time_obj),
+    }
+    .to_obj(cls.cast())
+}
+
 unsafe fn strptime(cls: *mut PyObject, args: &[*mut PyObject]) -> PyReturn {
     if args.len() != 2 {
         type_err!(
@@ -697,21 +946,34 @@ unsafe fn assume_fixed_offset(slf: *mut PyObject, arg: *mut PyObject) -> PyRetur
         .to_obj(offset_datetime_type)
 }
 
+unsafe fn to_instant(slf: *mut PyObject, arg: *mut PyObject) -> PyReturn {
+    let &State {
+        time_delta_type,
+        instant_type,
+        ..
+    } = State::for_obj(slf);
+    let DateTime { date, time } = DateTime::extract(slf);
+    let offset_secs = offset_datetime::extract_offset(arg, time_delta_type)?;
+    Instant::from_datetime(date, time)
+        .shift_secs(-offset_secs as i64)
+        .ok_or_value_err("Datetime out of range")?
+        .to_obj(instant_type)
+}
+
 unsafe fn assume_tz(
     slf: *mut PyObject,
     cls: *mut PyTypeObject,
     args: &[*mut PyObject],
     kwargs: &mut KwargIter,
 ) -> PyReturn {
+    let state = State::for_type(cls);
     let &State {
         py_api,
         zoneinfo_type,
         str_disambiguate,
         zoned_datetime_type,
-        exc_skipped,
-        exc_repeated,
         ..
-    } = State::for_type(cls);
+    } = state;
     let DateTime { date, time } = DateTime::extract(slf);
     let &[tz] = args else {
         Err(type_err!(
@@ -729,43 +991,69 @@ unsafe fn assume_tz(
         time,
         zoneinfo,
         dis.unwrap_or(Disambiguate::Compatible),
-        exc_repeated,
-        exc_skipped,
+        state,
     )?
     .to_obj(zoned_datetime_type)
 }
 
-unsafe fn assume_system_tz(
+unsafe fn _resolve_system_tz(
     slf: *mut PyObject,
-    cls: *mut PyTypeObject,
+    state: &State,
     args: &[*mut PyObject],
     kwargs: &mut KwargIter,
-) -> PyReturn {
-    let &State {
-        py_api,
-        str_disambiguate,
-        system_datetime_type,
-        exc_skipped,
-        exc_repeated,
-        ..
-    } = State::for_type(cls);
+    fname: &str,
+) -> PyResult<OffsetDateTime> {
     let DateTime { date, time } = DateTime::extract(slf);
     if !args.is_empty() {
-        Err(type_err!(
-            "assume_system_tz() takes no positional arguments"
-        ))?
+        Err(type_err!("{}() takes no positional arguments", fname))?
     }
 
-    let dis = Disambiguate::from_only_kwarg(kwargs, str_disambiguate, "assume_system_tz")?;
+    let dis = Disambiguate::from_only_kwarg(kwargs, state.str_disambiguate, fname)?;
     OffsetDateTime::resolve_system_tz_using_disambiguate(
-        py_api,
+        state.py_api,
         date,
         time,
         dis.unwrap_or(Disambiguate::Compatible),
-        exc_repeated,
-        exc_skipped,
-    )?
-    .to_obj(system_datetime_type)
+        state,
+    )
+}
+
+unsafe fn assume_system_tz(
+    slf: *mut PyObject,
+    cls: *mut PyTypeObject,
+    args: &[*mut PyObject],
+    kwargs: &mut KwargIter,
+) -> PyReturn {
+    let state = State::for_type(cls);
+    _resolve_system_tz(slf, state, args, kwargs, "assume_system_tz")?
+        .to_obj(state.system_datetime_type)
+}
+
+unsafe fn to_system_offset(
+    slf: *mut PyObject,
+    cls: *mut PyTypeObject,
+    args: &[*mut PyObject],
+    kwargs: &mut KwargIter,
+) -> PyReturn {
+    let state = State::for_type(cls);
+    _resolve_system_tz(slf, state, args, kwargs, "to_system_offset")?
+        .to_obj(state.offset_datetime_type)
+}
+
+unsafe fn is_valid_in_tz(slf: *mut PyObject, tz: *mut PyObject) -> PyReturn {
+    let &State {
+        py_api,
+        zoneinfo_type,
+        ..
+    } = State::for_obj(slf);
+    let DateTime { date, time } = DateTime::extract(slf);
+    let zoneinfo = call1(zoneinfo_type, tz)?;
+    defer_decref!(zoneinfo);
+    (!matches!(
+        OffsetResult::for_tz(py_api, date, time, zoneinfo)?,
+        OffsetResult::Gap(..)
+    ))
+    .to_py()
 }
 
 unsafe fn replace_date(slf: *mut PyObject, arg: *mut PyObject) -> PyReturn {
@@ -828,6 +1116,11 @@ static mut METHODS: &[PyMethodDef] = &[
         doc::LOCALDATETIME_FROM_PY_DATETIME,
         METH_CLASS | METH_O
     ),
+    method_kwargs!(
+        from_timestamp,
+        doc::LOCALDATETIME_FROM_TIMESTAMP,
+        METH_CLASS
+    ),
     method!(py_datetime, doc::BASICCONVERSIONS_PY_DATETIME),
     method!(
         get_date named "date",
@@ -837,12 +1130,36 @@ static mut METHODS: &[PyMethodDef] = &[
         get_time named "time",
         doc::KNOWSLOCAL_TIME
     ),
+    method!(as_tuple, doc::LOCALDATETIME_AS_TUPLE),
+    method!(iso_year_week_day, doc::LOCALDATETIME_ISO_YEAR_WEEK_DAY),
+    PyMethodDef {
+        ml_name: c"from_iso_week_datetime".as_ptr(),
+        ml_meth: PyMethodDefPointer {
+            PyCFunctionWithKeywords: {
+                unsafe extern "C" fn _wrap(
+                    slf: *mut PyObject,
+                    args: *mut PyObject,
+                    kwargs: *mut PyObject,
+                ) -> *mut PyObject {
+                    match from_iso_week_datetime(slf.cast(), args, kwargs) {
+                        Ok(x) => x as *mut PyObject,
+                        Err(PyErrOccurred()) => core::ptr::null_mut(),
+                    }
+                }
+                _wrap
+            },
+        },
+        ml_flags: METH_CLASS | METH_VARARGS | METH_KEYWORDS,
+        ml_doc: doc::LOCALDATETIME_FROM_ISO_WEEK_DATETIME.as_ptr(),
+    },
     method!(format_common_iso, doc::LOCALDATETIME_FORMAT_COMMON_ISO),
+    method!(__format__, c"", METH_O),
     method!(
         parse_common_iso,
         doc::LOCALDATETIME_PARSE_COMMON_ISO,
         METH_O | METH_CLASS
     ),
+    method_vararg!(combine, doc::LOCALDATETIME_COMBINE, METH_CLASS),
     method_vararg!(strptime, doc::LOCALDATETIME_STRPTIME, METH_CLASS),
     method_kwargs!(replace, doc::LOCALDATETIME_REPLACE),
     method!(assume_utc, doc::LOCALDATETIME_ASSUME_UTC),
@@ -851,12 +1168,17 @@ static mut METHODS: &[PyMethodDef] = &[
         doc::LOCALDATETIME_ASSUME_FIXED_OFFSET,
         METH_O
     ),
+    method!(to_instant, doc::LOCALDATETIME_TO_INSTANT, METH_O),
     method_kwargs!(assume_tz, doc::LOCALDATETIME_ASSUME_TZ),
     method_kwargs!(assume_system_tz, doc::LOCALDATETIME_ASSUME_SYSTEM_TZ),
+    method_kwargs!(to_system_offset, doc::LOCALDATETIME_TO_SYSTEM_OFFSET),
+    method!(is_valid_in_tz, doc::LOCALDATETIME_IS_VALID_IN_TZ, METH_O),
     method!(replace_date, doc::LOCALDATETIME_REPLACE_DATE, METH_O),
     method!(replace_time, doc::LOCALDATETIME_REPLACE_TIME, METH_O),
     method_kwargs!(add, doc::LOCALDATETIME_ADD),
     method_kwargs!(subtract, doc::LOCALDATETIME_SUBTRACT),
+    method!(add_days, doc::LOCALDATETIME_ADD_DAYS, METH_O),
+    method!(add_months, doc::LOCALDATETIME_ADD_MONTHS, METH_O),
     method_kwargs!(difference, doc::LOCALDATETIME_DIFFERENCE),
     method_kwargs!(round, doc::LOCALDATETIME_ROUND),
     PyMethodDef::zeroed(),
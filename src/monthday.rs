@@ -4,7 +4,7 @@ use pyo3_ffi::*;
 use std::fmt::{self, Display, Formatter};
 
 use crate::common::*;
-use crate::date::{Date, MAX_MONTH_DAYS_IN_LEAP_YEAR};
+use crate::date::{check_common_era, Date, MAX_MONTH_DAYS_IN_LEAP_YEAR};
 use crate::docstrings as doc;
 use crate::State;
 
@@ -211,18 +211,17 @@ unsafe fn replace(
 }
 
 unsafe fn in_year(slf: *mut PyObject, year_obj: *mut PyObject) -> PyReturn {
-    let &State { date_type, .. } = State::for_obj(slf);
+    let state = State::for_obj(slf);
     let MonthDay { month, day } = MonthDay::extract(slf);
     let year = year_obj
         .to_long()?
-        .ok_or_type_err("year must be an integer")?
-        .try_into()
-        .ok()
-        .ok_or_value_err("year out of range")?;
+        .ok_or_type_err("year must be an integer")?;
+    check_common_era(state, year)?;
+    let year = year.try_into().ok().ok_or_value_err("year out of range")?;
     // OPTIMIZE: we don't need to check the validity of the month again
     Date::new(year, month, day)
         .ok_or_value_err("Invalid date components")?
-        .to_obj(date_type)
+        .to_obj(state.date_type)
 }
 
 unsafe fn is_leap(slf: *mut PyObject, _: *mut PyObject) -> PyReturn {
@@ -96,7 +96,11 @@ impl OffsetDateTime {
             offset_secs,
             ..
         } = self;
-        let tz = TimeZone_FromTimeZone(Delta_FromDelta(0, offset_secs, 0, 0, DeltaType), NULL());
+        // Note: the delta must be normalized, otherwise negative offsets
+        // don't compare equal to those of the standard library
+        let delta = Delta_FromDelta(0, offset_secs, 0, 1, DeltaType).as_result()?;
+        defer_decref!(delta);
+        let tz = TimeZone_FromTimeZone(delta, NULL()).as_result()?;
         defer_decref!(tz);
         DateTime_FromDateAndTime(
             year.into(),
@@ -227,9 +231,10 @@ unsafe fn __new__(cls: *mut PyTypeObject, args: *mut PyObject, kwargs: *mut PyOb
         Err(py_err!())?
     }
 
-    let date = Date::from_longs(year, month, day).ok_or_value_err("Invalid date")?;
-    let time = Time::from_longs(hour, minute, second, nanos).ok_or_value_err("Invalid time")?;
-    let offset_secs = extract_offset(offset, State::for_type(cls).time_delta_type)?;
+    let state = State::for_type(cls);
+    let date = Date::from_longs_or_err(year, month, day, state)?;
+    let time = Time::from_longs(hour, minute, second, nanos)?;
+    let offset_secs = extract_offset(offset, state.time_delta_type)?;
     OffsetDateTime::new(date, time, offset_secs)
         .ok_or_value_err("Time is out of range")?
         .to_obj(cls)
@@ -246,16 +251,24 @@ pub(crate) unsafe fn extract_offset(
             .to_i64()?
             // We've checked before that it's a py int
             .unwrap();
-        Ok(
-            clamp(given_int, 24i32).ok_or_value_err("offset must be between -24 and 24 hours")?
-                * 3600,
-        )
+        // Like the standard library, we exclude +/-24 hours itself
+        if (-23..=23).contains(&given_int) {
+            Ok(given_int as i32 * 3600)
+        } else {
+            Err(value_err!(
+                "offset must be strictly between -24 and 24 hours"
+            ))
+        }
     } else if Py_TYPE(obj) == tdelta_cls {
         let TimeDelta { secs, nanos } = TimeDelta::extract(obj);
-        if nanos == 0 {
-            clamp(secs, 24i32 * 3600).ok_or_value_err("offset must be between -24 and 24 hours")
-        } else {
+        if nanos != 0 {
             Err(value_err!("offset must be a whole number of seconds"))
+        } else if secs.abs() < 24 * 3600 {
+            Ok(secs as i32)
+        } else {
+            Err(value_err!(
+                "offset must be strictly between -24 and 24 hours"
+            ))
         }
     } else {
         Err(type_err!(
@@ -422,6 +435,23 @@ unsafe fn to_fixed_offset(slf_obj: *mut PyObject, args: &[*mut PyObject]) -> PyR
     }
 }
 
+unsafe fn round_offset_to_minutes(slf: *mut PyObject, _: *mut PyObject) -> PyReturn {
+    let odt = OffsetDateTime::extract(slf);
+    let offset_secs = odt.offset_secs;
+    // Round to the nearest minute, with ties away from zero
+    let rounded = (offset_secs.abs() + 30) / 60 * 60 * offset_secs.signum();
+    if rounded == offset_secs {
+        return Ok(newref(slf));
+    }
+    if rounded.abs() >= S_PER_DAY {
+        Err(value_err!("Resulting offset out of range"))?
+    }
+    odt.instant()
+        .to_offset(rounded)
+        .ok_or_value_err("Resulting local date is out of range")?
+        .to_obj(Py_TYPE(slf))
+}
+
 unsafe fn to_tz(slf: *mut PyObject, tz: *mut PyObject) -> PyReturn {
     let type_ = Py_TYPE(slf);
     let &State {
@@ -450,10 +480,8 @@ unsafe fn to_system_tz(slf: *mut PyObject, _: *mut PyObject) -> PyReturn {
 }
 
 pub(crate) unsafe fn unpickle(module: *mut PyObject, arg: *mut PyObject) -> PyReturn {
-    let mut packed = arg.to_bytes()?.ok_or_type_err("Invalid pickle data")?;
-    if packed.len() != 15 {
-        Err(value_err!("Invalid pickle data"))?;
-    }
+    let mut packed =
+        strip_pickle_version(arg.to_bytes()?.ok_or_type_err("Invalid pickle data")?, 15)?;
     OffsetDateTime::new_unchecked(
         Date {
             year: unpack_one!(packed, u16),
@@ -613,8 +641,8 @@ unsafe fn replace(
         ))?
     }
 
-    let date = Date::from_longs(year, month, day).ok_or_value_err("Invalid date")?;
-    let time = Time::from_longs(hour, minute, second, nanos).ok_or_value_err("Invalid time")?;
+    let date = Date::from_longs_or_err(year, month, day, state)?;
+    let time = Time::from_longs(hour, minute, second, nanos)?;
     OffsetDateTime::new(date, time, offset_secs)
         .ok_or_value_err("Resulting datetime is out of range")?
         .to_obj(cls)
@@ -819,7 +847,17 @@ unsafe fn __reduce__(slf: *mut PyObject, _: *mut PyObject) -> PyReturn {
             },
         offset_secs,
     } = OffsetDateTime::extract(slf);
-    let data = pack![year, month, day, hour, minute, second, nanos, offset_secs];
+    let data = pack![
+        PICKLE_VERSION,
+        year,
+        month,
+        day,
+        hour,
+        minute,
+        second,
+        nanos,
+        offset_secs
+    ];
     (
         State::for_obj(slf).unpickle_offset_datetime,
         steal!((steal!(data.to_py()?),).to_py()?),
@@ -1148,9 +1186,14 @@ static mut METHODS: &[PyMethodDef] = &[
         METH_O | METH_CLASS
     ),
     method!(instant, doc::KNOWSINSTANTANDLOCAL_INSTANT),
+    method!(instant named "to_instant", doc::KNOWSINSTANTANDLOCAL_TO_INSTANT),
     method!(local, doc::KNOWSINSTANTANDLOCAL_LOCAL),
     method!(to_tz, doc::KNOWSINSTANT_TO_TZ, METH_O),
     method_vararg!(to_fixed_offset, doc::KNOWSINSTANT_TO_FIXED_OFFSET),
+    method!(
+        round_offset_to_minutes,
+        doc::OFFSETDATETIME_ROUND_OFFSET_TO_MINUTES
+    ),
     method!(to_system_tz, doc::KNOWSINSTANT_TO_SYSTEM_TZ),
     method!(date, doc::KNOWSLOCAL_DATE),
     method!(time, doc::KNOWSLOCAL_TIME),
@@ -138,27 +138,62 @@ pub(crate) unsafe fn parse_args(
     hours_largest_unit: bool,
     ignore_dst_kwarg: bool,
 ) -> PyResult<(Unit, i64, Mode)> {
-    let &State {
-        str_nanosecond,
-        str_microsecond,
-        str_millisecond,
-        str_second,
-        str_minute,
-        str_hour,
-        str_day,
+    let ([unit, increment, mode], ignore_dst) = collect_args(
+        args,
+        kwargs,
+        state,
+        ignore_dst_kwarg.then_some(state.str_ignore_dst),
+    )?;
+
+    if ignore_dst_kwarg && ignore_dst != Some(Py_True()) {
+        Err(py_err!(
+            state.exc_implicitly_ignoring_dst,
+            doc::OFFSET_ROUNDING_DST_MSG
+        ))?
+    }
+
+    resolve_args(
+        state,
+        unit,
+        increment,
+        mode,
+        hours_largest_unit,
+        Unit::Second,
+    )
+}
+
+// Like `parse_args`, but also accepting an optional `disambiguate` kwarg
+pub(crate) unsafe fn parse_args_disambiguate(
+    state: &State,
+    args: &[*mut PyObject],
+    kwargs: &mut KwargIter,
+) -> PyResult<(Unit, i64, Mode, Option<Disambiguate>)> {
+    let ([unit, increment, mode], dis) =
+        collect_args(args, kwargs, state, Some(state.str_disambiguate))?;
+    let (unit, increment, mode) = resolve_args(state, unit, increment, mode, false, Unit::Second)?;
+    Ok((
+        unit,
+        increment,
+        mode,
+        dis.map(|d| Disambiguate::from_py(d)).transpose()?,
+    ))
+}
+
+// Gather the unit, increment, and mode arguments (positional or keyword),
+// plus the value of an optional extra keyword-only argument.
+unsafe fn collect_args(
+    args: &[*mut PyObject],
+    kwargs: &mut KwargIter,
+    &State {
         str_unit,
         str_mode,
         str_increment,
-        str_floor,
-        str_ceil,
-        str_half_floor,
-        str_half_ceil,
-        str_half_even,
         ..
-    } = state;
-
+    }: &State,
+    extra_kwarg: Option<*mut PyObject>,
+) -> PyResult<([Option<*mut PyObject>; 3], Option<*mut PyObject>)> {
     let num_argkwargs = args.len() + kwargs.len() as usize;
-    if ignore_dst_kwarg {
+    if extra_kwarg.is_some() {
         if args.len() > 3 {
             Err(type_err!(
                 "round() takes at most 3 positional arguments, got {}",
@@ -177,7 +212,7 @@ pub(crate) unsafe fn parse_args(
             num_argkwargs
         ))?;
     }
-    let mut ignore_dst = false;
+    let mut extra = None;
     let mut arg_obj: [Option<NonNull<PyObject>>; 3] = [None, None, None];
     for i in 0..args.len() {
         arg_obj[i] = Some(NonNull::new_unchecked(args[i]));
@@ -194,26 +229,43 @@ pub(crate) unsafe fn parse_args(
                 return Ok(true);
             }
         }
-        if ignore_dst_kwarg && eq(key, state.str_ignore_dst) {
-            if value == Py_True() {
-                ignore_dst = true;
-            }
+        if extra_kwarg.is_some_and(|name| eq(key, name)) {
+            extra = Some(value);
             return Ok(true);
         }
         Ok(false)
     })?;
+    Ok((arg_obj.map(|v| v.map(NonNull::as_ptr)), extra))
+}
 
-    if ignore_dst_kwarg && !ignore_dst {
-        Err(py_err!(
-            state.exc_implicitly_ignoring_dst,
-            doc::OFFSET_ROUNDING_DST_MSG
-        ))?
-    }
-
-    let unit = arg_obj[0]
+// Convert the (optional) unit, increment, and mode arguments.
+// Defaults are used for any missing arguments.
+pub(crate) unsafe fn resolve_args(
+    &State {
+        str_nanosecond,
+        str_microsecond,
+        str_millisecond,
+        str_second,
+        str_minute,
+        str_hour,
+        str_day,
+        str_floor,
+        str_ceil,
+        str_half_floor,
+        str_half_ceil,
+        str_half_even,
+        ..
+    }: &State,
+    unit: Option<*mut PyObject>,
+    increment: Option<*mut PyObject>,
+    mode: Option<*mut PyObject>,
+    hours_largest_unit: bool,
+    default_unit: Unit,
+) -> PyResult<(Unit, i64, Mode)> {
+    let unit = unit
         .map(|v| {
             Unit::from_py(
-                v.as_ptr(),
+                v,
                 str_nanosecond,
                 str_microsecond,
                 str_millisecond,
@@ -224,15 +276,15 @@ pub(crate) unsafe fn parse_args(
             )
         })
         .transpose()?
-        .unwrap_or(Unit::Second);
-    let increment = arg_obj[1]
-        .map(|v| unit.increment_from_py(v.as_ptr(), hours_largest_unit))
+        .unwrap_or(default_unit);
+    let increment = increment
+        .map(|v| unit.increment_from_py(v, hours_largest_unit))
         .transpose()?
         .unwrap_or_else(|| unit.default_increment());
-    let mode = arg_obj[2]
+    let mode = mode
         .map(|v| {
             Mode::from_py(
-                v.as_ptr(),
+                v,
                 str_floor,
                 str_ceil,
                 str_half_floor,
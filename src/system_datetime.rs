@@ -15,7 +15,7 @@ use crate::{
     round,
     time::{Time, MIDNIGHT},
     time_delta::TimeDelta,
-    zoned_datetime::ZonedDateTime,
+    zoned_datetime::{raise_dst_error, ZonedDateTime},
     State,
 };
 
@@ -28,18 +28,10 @@ impl OffsetDateTime {
         time: Time,
         dis: Option<Disambiguate>,
         preferred_offset: i32,
-        exc_repeated: *mut PyObject,
-        exc_skipped: *mut PyObject,
+        state: &State,
     ) -> PyResult<Self> {
         match dis {
-            Some(dis) => Self::resolve_system_tz_using_disambiguate(
-                py_api,
-                date,
-                time,
-                dis,
-                exc_repeated,
-                exc_skipped,
-            ),
+            Some(dis) => Self::resolve_system_tz_using_disambiguate(py_api, date, time, dis, state),
             None => Self::resolve_system_tz_using_offset(py_api, date, time, preferred_offset),
         }
     }
@@ -49,8 +41,7 @@ impl OffsetDateTime {
         date: Date,
         time: Time,
         dis: Disambiguate,
-        exc_repeated: *mut PyObject,
-        exc_skipped: *mut PyObject,
+        state: &State,
     ) -> PyResult<Self> {
         use OffsetResult::*;
         Ok(match OffsetResult::for_system_tz(py_api, date, time)? {
@@ -59,11 +50,13 @@ impl OffsetDateTime {
                 let offset = match dis {
                     Disambiguate::Compatible | Disambiguate::Earlier => offset0,
                     Disambiguate::Later => offset1,
-                    Disambiguate::Raise => Err(py_err!(
-                        exc_repeated,
-                        "{} {} is repeated in the system timezone",
+                    Disambiguate::Raise => Err(raise_dst_error(
+                        state,
+                        state.exc_repeated,
+                        format!("{} {} is repeated in the system timezone", date, time),
                         date,
-                        time
+                        time,
+                        None,
                     ))?,
                 };
                 OffsetDateTime::new_unchecked(date, time, offset)
@@ -72,11 +65,13 @@ impl OffsetDateTime {
                 let (offset_secs, shift) = match dis {
                     Disambiguate::Compatible | Disambiguate::Later => (offset1, offset1 - offset0),
                     Disambiguate::Earlier => (offset0, offset0 - offset1),
-                    Disambiguate::Raise => Err(py_err!(
-                        exc_skipped,
-                        "{} {} is skipped in the system timezone",
+                    Disambiguate::Raise => Err(raise_dst_error(
+                        state,
+                        state.exc_skipped,
+                        format!("{} {} is skipped in the system timezone", date, time),
                         date,
-                        time
+                        time,
+                        None,
                     ))?,
                 };
                 DateTime { date, time }
@@ -144,8 +139,7 @@ impl OffsetDateTime {
         days: i32,
         delta: TimeDelta,
         dis: Option<Disambiguate>,
-        exc_repeated: *mut PyObject,
-        exc_skipped: *mut PyObject,
+        state: &State,
     ) -> PyResult<Self> {
         let slf = if months != 0 || days != 0 {
             Self::resolve_system_tz(
@@ -156,8 +150,7 @@ impl OffsetDateTime {
                 self.time,
                 dis,
                 self.offset_secs,
-                exc_repeated,
-                exc_skipped,
+                state,
             )?
         } else {
             self
@@ -194,13 +187,12 @@ impl Instant {
 }
 
 unsafe fn __new__(cls: *mut PyTypeObject, args: *mut PyObject, kwargs: *mut PyObject) -> PyReturn {
+    let state = State::for_type(cls);
     let &State {
         py_api,
-        exc_repeated,
-        exc_skipped,
         str_compatible,
         ..
-    } = State::for_type(cls);
+    } = state;
     let mut year = 0;
     let mut month = 0;
     let mut day = 0;
@@ -239,18 +231,11 @@ unsafe fn __new__(cls: *mut PyTypeObject, args: *mut PyObject, kwargs: *mut PyOb
         Err(py_err!())?
     }
 
-    let date = Date::from_longs(year, month, day).ok_or_value_err("Invalid date")?;
-    let time = Time::from_longs(hour, minute, second, nanos).ok_or_value_err("Invalid time")?;
+    let date = Date::from_longs_or_err(year, month, day, state)?;
+    let time = Time::from_longs(hour, minute, second, nanos)?;
     let dis = Disambiguate::from_py(disambiguate)?;
-    OffsetDateTime::resolve_system_tz_using_disambiguate(
-        py_api,
-        date,
-        time,
-        dis,
-        exc_repeated,
-        exc_skipped,
-    )?
-    .to_obj(cls)
+    OffsetDateTime::resolve_system_tz_using_disambiguate(py_api, date, time, dis, state)?
+        .to_obj(cls)
 }
 
 unsafe fn __repr__(slf: *mut PyObject) -> PyReturn {
@@ -307,15 +292,14 @@ unsafe fn _shift_operator(obj_a: *mut PyObject, obj_b: *mut PyObject, negate: bo
     );
     let type_a = Py_TYPE(obj_a);
     let type_b = Py_TYPE(obj_b);
+    let state = State::for_type(type_a);
     let &State {
         time_delta_type,
         date_delta_type,
         datetime_delta_type,
         py_api,
-        exc_repeated,
-        exc_skipped,
         ..
-    } = State::for_type(type_a);
+    } = state;
 
     let odt = OffsetDateTime::extract(obj_a);
     let mut months = 0;
@@ -342,16 +326,8 @@ unsafe fn _shift_operator(obj_a: *mut PyObject, obj_b: *mut PyObject, negate: bo
         tdelta = -tdelta;
     };
 
-    odt.shift_in_system_tz(
-        py_api,
-        months,
-        days,
-        tdelta,
-        None,
-        exc_repeated,
-        exc_skipped,
-    )?
-    .to_obj(type_a)
+    odt.shift_in_system_tz(py_api, months, days, tdelta, None, state)?
+        .to_obj(type_a)
 }
 
 unsafe fn __add__(obj_a: *mut PyObject, obj_b: *mut PyObject) -> PyReturn {
@@ -446,10 +422,8 @@ unsafe fn exact_eq(obj_a: *mut PyObject, obj_b: *mut PyObject) -> PyReturn {
 }
 
 pub(crate) unsafe fn unpickle(module: *mut PyObject, arg: *mut PyObject) -> PyReturn {
-    let mut packed = arg.to_bytes()?.ok_or_type_err("Invalid pickle data")?;
-    if packed.len() != 15 {
-        Err(value_err!("Invalid pickle data"))?
-    }
+    let mut packed =
+        strip_pickle_version(arg.to_bytes()?.ok_or_type_err("Invalid pickle data")?, 15)?;
     OffsetDateTime::new_unchecked(
         Date {
             year: unpack_one!(packed, u16),
@@ -489,14 +463,13 @@ unsafe fn replace_date(
     args: &[*mut PyObject],
     kwargs: &mut KwargIter,
 ) -> PyReturn {
+    let state = State::for_obj(slf);
     let &State {
         date_type,
         py_api,
         str_disambiguate,
-        exc_skipped,
-        exc_repeated,
         ..
-    } = State::for_obj(slf);
+    } = state;
 
     let &[arg] = args else {
         Err(type_err!(
@@ -515,8 +488,7 @@ unsafe fn replace_date(
             time,
             Disambiguate::from_only_kwarg(kwargs, str_disambiguate, "replace_date")?,
             offset_secs,
-            exc_repeated,
-            exc_skipped,
+            state,
         )?
         .to_obj(cls)
     } else {
@@ -530,14 +502,13 @@ unsafe fn replace_time(
     args: &[*mut PyObject],
     kwargs: &mut KwargIter,
 ) -> PyReturn {
+    let state = State::for_obj(slf);
     let &State {
         time_type,
         py_api,
         str_disambiguate,
-        exc_skipped,
-        exc_repeated,
         ..
-    } = State::for_obj(slf);
+    } = state;
 
     let &[arg] = args else {
         Err(type_err!(
@@ -556,8 +527,7 @@ unsafe fn replace_time(
             Time::extract(arg),
             Disambiguate::from_only_kwarg(kwargs, str_disambiguate, "replace_time")?,
             offset_secs,
-            exc_repeated,
-            exc_skipped,
+            state,
         )?
         .to_obj(cls)
     } else {
@@ -614,18 +584,10 @@ unsafe fn replace(
         }
     })?;
 
-    let date = Date::from_longs(year, month, day).ok_or_value_err("Invalid date")?;
-    let time = Time::from_longs(hour, minute, second, nanos).ok_or_value_err("Invalid time")?;
-    OffsetDateTime::resolve_system_tz(
-        state.py_api,
-        date,
-        time,
-        dis,
-        offset_secs,
-        state.exc_repeated,
-        state.exc_skipped,
-    )?
-    .to_obj(cls)
+    let date = Date::from_longs_or_err(year, month, day, state)?;
+    let time = Time::from_longs(hour, minute, second, nanos)?;
+    OffsetDateTime::resolve_system_tz(state.py_api, date, time, dis, offset_secs, state)?
+        .to_obj(cls)
 }
 
 unsafe fn now(cls: *mut PyObject, _: *mut PyObject) -> PyReturn {
@@ -667,7 +629,17 @@ unsafe fn __reduce__(slf: *mut PyObject, _: *mut PyObject) -> PyReturn {
             },
         offset_secs,
     } = OffsetDateTime::extract(slf);
-    let data = pack![year, month, day, hour, minute, second, nanos, offset_secs];
+    let data = pack![
+        PICKLE_VERSION,
+        year,
+        month,
+        day,
+        hour,
+        minute,
+        second,
+        nanos,
+        offset_secs
+    ];
     (
         State::for_obj(slf).unpickle_system_datetime,
         steal!((steal!(data.to_py()?),).to_py()?),
@@ -856,15 +828,7 @@ unsafe fn _shift_method(
         tdelta = -tdelta;
     }
     OffsetDateTime::extract(slf)
-        .shift_in_system_tz(
-            state.py_api,
-            months,
-            days,
-            tdelta,
-            dis,
-            state.exc_repeated,
-            state.exc_skipped,
-        )?
+        .shift_in_system_tz(state.py_api, months, days, tdelta, dis, state)?
         .to_obj(cls)
 }
 
@@ -901,39 +865,32 @@ unsafe fn is_ambiguous(slf: *mut PyObject, _: *mut PyObject) -> PyReturn {
 
 unsafe fn start_of_day(slf: *mut PyObject, _: *mut PyObject) -> PyReturn {
     let OffsetDateTime { date, .. } = OffsetDateTime::extract(slf);
-    let &State {
-        py_api,
-        exc_repeated,
-        exc_skipped,
-        ..
-    } = State::for_obj(slf);
+    let state = State::for_obj(slf);
+    let &State { py_api, .. } = state;
     OffsetDateTime::resolve_system_tz_using_disambiguate(
         py_api,
         date,
         MIDNIGHT,
         Disambiguate::Compatible,
-        exc_repeated,
-        exc_skipped,
+        state,
     )?
     .to_obj(Py_TYPE(slf))
 }
 
 unsafe fn day_length(slf: *mut PyObject, _: *mut PyObject) -> PyReturn {
     let OffsetDateTime { date, .. } = OffsetDateTime::extract(slf);
+    let state = State::for_obj(slf);
     let &State {
         py_api,
-        exc_repeated,
-        exc_skipped,
         time_delta_type,
         ..
-    } = State::for_obj(slf);
+    } = state;
     let start_of_day = OffsetDateTime::resolve_system_tz_using_disambiguate(
         py_api,
         date,
         MIDNIGHT,
         Disambiguate::Compatible,
-        exc_repeated,
-        exc_skipped,
+        state,
     )?
     .instant();
     let start_of_next_day = OffsetDateTime::resolve_system_tz_using_disambiguate(
@@ -941,8 +898,7 @@ unsafe fn day_length(slf: *mut PyObject, _: *mut PyObject) -> PyReturn {
         date.increment(),
         MIDNIGHT,
         Disambiguate::Compatible,
-        exc_repeated,
-        exc_skipped,
+        state,
     )?
     .instant();
     start_of_next_day.diff(start_of_day).to_obj(time_delta_type)
@@ -990,20 +946,14 @@ unsafe fn _round_day(
     mode: round::Mode,
 ) -> PyResult<OffsetDateTime> {
     let OffsetDateTime { date, time, .. } = OffsetDateTime::extract(slf);
-    let &State {
-        py_api,
-        exc_repeated,
-        exc_skipped,
-        ..
-    } = state;
+    let &State { py_api, .. } = state;
     let get_floor = || {
         OffsetDateTime::resolve_system_tz_using_disambiguate(
             py_api,
             date,
             MIDNIGHT,
             Disambiguate::Compatible,
-            exc_repeated,
-            exc_skipped,
+            state,
         )
     };
     let get_ceil = || {
@@ -1012,8 +962,7 @@ unsafe fn _round_day(
             date.increment(),
             MIDNIGHT,
             Disambiguate::Compatible,
-            exc_repeated,
-            exc_skipped,
+            state,
         )
     };
     match mode {
@@ -1051,6 +1000,7 @@ static mut METHODS: &[PyMethodDef] = &[
     method!(exact_eq, doc::KNOWSINSTANT_EXACT_EQ, METH_O),
     method!(py_datetime, doc::BASICCONVERSIONS_PY_DATETIME),
     method!(instant, doc::KNOWSINSTANTANDLOCAL_INSTANT),
+    method!(instant named "to_instant", doc::KNOWSINSTANTANDLOCAL_TO_INSTANT),
     method!(local, doc::KNOWSINSTANTANDLOCAL_LOCAL),
     method!(date, doc::KNOWSLOCAL_DATE),
     method!(time, doc::KNOWSLOCAL_TIME),
@@ -9,6 +9,7 @@ use crate::date::Date;
 use crate::docstrings as doc;
 use crate::local_datetime::DateTime;
 use crate::round;
+use crate::time_delta::TimeDelta;
 use crate::State;
 
 #[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Copy, Clone)]
@@ -75,24 +76,34 @@ impl Time {
         }
     }
 
-    pub(crate) const fn from_longs(
+    // Shift by a number of nanoseconds, wrapping around midnight.
+    // Also returns the number of days carried over.
+    pub(crate) fn shift_nanos_wrapping(self, nanos: i128) -> (Self, i64) {
+        let total = self.total_nanos() as i128 + nanos;
+        (
+            Self::from_total_nanos_unchecked(total.rem_euclid(NS_PER_DAY) as u64),
+            total.div_euclid(NS_PER_DAY) as i64,
+        )
+    }
+
+    // The error message names the out-of-range component,
+    // so users know which field to fix
+    pub(crate) unsafe fn from_longs(
         hour: c_long,
         minute: c_long,
         second: c_long,
         nanos: c_long,
-    ) -> Option<Self> {
-        if hour < 0
-            || hour > 23
-            || minute < 0
-            || minute > 59
-            || second < 0
-            || second > 59
-            || nanos < 0
-            || nanos > 999_999_999
-        {
-            None
+    ) -> PyResult<Self> {
+        if !(0..=23).contains(&hour) {
+            Err(value_err!("hour must be in 0..23"))
+        } else if !(0..=59).contains(&minute) {
+            Err(value_err!("minute must be in 0..59"))
+        } else if !(0..=59).contains(&second) {
+            Err(value_err!("second must be in 0..59"))
+        } else if !(0..=999_999_999).contains(&nanos) {
+            Err(value_err!("nanosecond must be in 0..999999999"))
         } else {
-            Some(Time {
+            Ok(Time {
                 hour: hour as u8,
                 minute: minute as u8,
                 second: second as u8,
@@ -262,9 +273,7 @@ unsafe fn __new__(cls: *mut PyTypeObject, args: *mut PyObject, kwargs: *mut PyOb
         Err(py_err!())?
     }
 
-    Time::from_longs(hour, minute, second, nanos)
-        .ok_or_value_err("Invalid time component value")?
-        .to_obj(cls)
+    Time::from_longs(hour, minute, second, nanos)?.to_obj(cls)
 }
 
 unsafe fn __repr__(slf: *mut PyObject) -> PyReturn {
@@ -294,11 +303,48 @@ unsafe fn __richcmp__(obj_a: *mut PyObject, obj_b: *mut PyObject, op: c_int) ->
     })
 }
 
+unsafe fn __add__(obj_a: *mut PyObject, obj_b: *mut PyObject) -> PyReturn {
+    _shift_operator(obj_a, obj_b, false)
+}
+
+unsafe fn __sub__(obj_a: *mut PyObject, obj_b: *mut PyObject) -> PyReturn {
+    _shift_operator(obj_a, obj_b, true)
+}
+
+#[inline]
+unsafe fn _shift_operator(obj_a: *mut PyObject, obj_b: *mut PyObject, negate: bool) -> PyReturn {
+    // We need to be careful since this method can be called reflexively
+    let type_a = Py_TYPE(obj_a);
+    let type_b = Py_TYPE(obj_b);
+    let mod_a = PyType_GetModule(type_a);
+    let mod_b = PyType_GetModule(type_b);
+    if mod_a == mod_b && type_b == State::for_mod(mod_a).time_delta_type {
+        let mut nanos = TimeDelta::extract(obj_b).total_nanos();
+        if negate {
+            nanos = -nanos;
+        }
+        Time::extract(obj_a)
+            .shift_nanos_wrapping(nanos)
+            .0
+            .to_obj(type_a)
+    } else {
+        // We can safely discount other types within our module
+        Err(type_err!(
+            "unsupported operand type(s) for {}: {} and {}",
+            if negate { "-" } else { "+" },
+            (type_a as *mut PyObject).repr(),
+            (type_b as *mut PyObject).repr()
+        ))
+    }
+}
+
 static mut SLOTS: &[PyType_Slot] = &[
     slotmethod!(Py_tp_new, __new__),
     slotmethod!(Py_tp_str, format_common_iso, 2),
     slotmethod!(Py_tp_repr, __repr__, 1),
     slotmethod!(Py_tp_richcompare, __richcmp__),
+    slotmethod!(Py_nb_add, __add__, 2),
+    slotmethod!(Py_nb_subtract, __sub__, 2),
     PyType_Slot {
         slot: Py_tp_doc,
         pfunc: doc::TIME.as_ptr() as *mut c_void,
@@ -365,6 +411,50 @@ unsafe fn from_py_time(type_: *mut PyObject, time: *mut PyObject) -> PyReturn {
     .to_obj(type_.cast())
 }
 
+unsafe fn now_in_tz(cls: *mut PyObject, tz: *mut PyObject) -> PyReturn {
+    let state = State::for_type(cls.cast());
+    let zoneinfo = call1(state.zoneinfo_type, tz)?;
+    defer_decref!(zoneinfo);
+    _now_in(cls, state, zoneinfo)
+}
+
+unsafe fn now_in_system_tz(cls: *mut PyObject, _: *mut PyObject) -> PyReturn {
+    // Passing `None` as the tzinfo gives the local time in the system timezone
+    _now_in(cls, State::for_type(cls.cast()), Py_None())
+}
+
+#[inline]
+unsafe fn _now_in(cls: *mut PyObject, state: &State, tzinfo: *mut PyObject) -> PyReturn {
+    let &State {
+        py_api:
+            &PyDateTime_CAPI {
+                DateTime_FromTimestamp,
+                DateTimeType,
+                ..
+            },
+        ..
+    } = state;
+    let (timestamp, nanos) = state.time_ns()?;
+    let dt = DateTime_FromTimestamp(
+        DateTimeType,
+        steal!((steal!(timestamp.to_py()?), tzinfo).to_py()?),
+        NULL(),
+    )
+    .as_result()?;
+    defer_decref!(dt);
+    Time {
+        hour: PyDateTime_DATE_GET_HOUR(dt) as u8,
+        minute: PyDateTime_DATE_GET_MINUTE(dt) as u8,
+        second: PyDateTime_DATE_GET_SECOND(dt) as u8,
+        nanos,
+    }
+    .to_obj(cls.cast())
+}
+
+unsafe fn total_seconds_float(slf: *mut PyObject, _: *mut PyObject) -> PyReturn {
+    (Time::extract(slf).total_nanos() as f64 / 1e9).to_py()
+}
+
 unsafe fn format_common_iso(slf: *mut PyObject, _: *mut PyObject) -> PyReturn {
     _default_fmt(Time::extract(slf)).to_py()
 }
@@ -461,18 +551,15 @@ unsafe fn replace(
             }
             Ok(true)
         })?;
-        Time::from_longs(hour, minute, second, nanos)
-            .ok_or_value_err("Invalid time component value")?
-            .to_obj(type_)
+        Time::from_longs(hour, minute, second, nanos)?.to_obj(type_)
     }
 }
 
-unsafe fn round(
+unsafe fn _round(
     slf: *mut PyObject,
-    cls: *mut PyTypeObject,
     args: &[*mut PyObject],
     kwargs: &mut KwargIter,
-) -> PyReturn {
+) -> PyResult<(Time, u64)> {
     let (unit, increment, mode) =
         round::parse_args(State::for_obj(slf), args, kwargs, false, false)?;
     if unit == round::Unit::Day {
@@ -480,10 +567,48 @@ unsafe fn round(
     } else if unit == round::Unit::Hour && 86_400_000_000_000 % increment != 0 {
         Err(value_err!("increment must be a divisor of 24"))?;
     }
-    Time::extract(slf)
-        .round(increment as u64, mode)
-        .0
-        .to_obj(cls)
+    Ok(Time::extract(slf).round(increment as u64, mode))
+}
+
+unsafe fn round(
+    slf: *mut PyObject,
+    cls: *mut PyTypeObject,
+    args: &[*mut PyObject],
+    kwargs: &mut KwargIter,
+) -> PyReturn {
+    _round(slf, args, kwargs)?.0.to_obj(cls)
+}
+
+unsafe fn round_with_carry(
+    slf: *mut PyObject,
+    cls: *mut PyTypeObject,
+    args: &[*mut PyObject],
+    kwargs: &mut KwargIter,
+) -> PyReturn {
+    let (time, carry) = _round(slf, args, kwargs)?;
+    (steal!(time.to_obj(cls)?), steal!((carry as u8).to_py()?)).to_py()
+}
+
+unsafe fn add(slf: *mut PyObject, delta: *mut PyObject) -> PyReturn {
+    _shift_method(slf, delta, false)
+}
+
+unsafe fn subtract(slf: *mut PyObject, delta: *mut PyObject) -> PyReturn {
+    _shift_method(slf, delta, true)
+}
+
+#[inline]
+unsafe fn _shift_method(slf: *mut PyObject, delta: *mut PyObject, negate: bool) -> PyReturn {
+    let cls = Py_TYPE(slf);
+    if Py_TYPE(delta) != State::for_type(cls).time_delta_type {
+        Err(type_err!("argument must be a whenever.TimeDelta"))?
+    }
+    let mut nanos = TimeDelta::extract(delta).total_nanos();
+    if negate {
+        nanos = -nanos;
+    }
+    let (time, days) = Time::extract(slf).shift_nanos_wrapping(nanos);
+    (steal!(time.to_obj(cls)?), steal!(days.to_py()?)).to_py()
 }
 
 static mut METHODS: &[PyMethodDef] = &[
@@ -491,6 +616,7 @@ static mut METHODS: &[PyMethodDef] = &[
     method!(identity2 named "__deepcopy__", c"", METH_O),
     method!(__reduce__, c""),
     method!(py_time, doc::TIME_PY_TIME),
+    method!(total_seconds_float, doc::TIME_TOTAL_SECONDS_FLOAT),
     method_kwargs!(replace, doc::TIME_REPLACE),
     method!(format_common_iso, doc::TIME_FORMAT_COMMON_ISO),
     method!(
@@ -499,8 +625,17 @@ static mut METHODS: &[PyMethodDef] = &[
         METH_O | METH_CLASS
     ),
     method!(from_py_time, doc::TIME_FROM_PY_TIME, METH_O | METH_CLASS),
+    method!(now_in_tz, doc::TIME_NOW_IN_TZ, METH_O | METH_CLASS),
+    method!(
+        now_in_system_tz,
+        doc::TIME_NOW_IN_SYSTEM_TZ,
+        METH_NOARGS | METH_CLASS
+    ),
     method!(on, doc::TIME_ON, METH_O),
     method_kwargs!(round, doc::TIME_ROUND),
+    method_kwargs!(round_with_carry, doc::TIME_ROUND_WITH_CARRY),
+    method!(add, doc::TIME_ADD, METH_O),
+    method!(subtract, doc::TIME_SUBTRACT, METH_O),
     PyMethodDef::zeroed(),
 ];
 
@@ -458,6 +458,18 @@ unsafe fn __truediv__(slf: *mut PyObject, factor_obj: *mut PyObject) -> PyReturn
     .to_obj(Py_TYPE(slf))
 }
 
+// Python-style floor division: the remainder has the sign of the divisor
+fn floor_divmod(a: i128, b: i128) -> (i128, i128) {
+    debug_assert!(b != 0);
+    let quotient = a / b;
+    let remainder = a % b;
+    if remainder != 0 && (remainder < 0) != (b < 0) {
+        (quotient - 1, remainder + b)
+    } else {
+        (quotient, remainder)
+    }
+}
+
 unsafe fn __floordiv__(a_obj: *mut PyObject, b_obj: *mut PyObject) -> PyReturn {
     if Py_TYPE(b_obj) == Py_TYPE(a_obj) {
         // NOTE: we can't avoid using i128 *in general*, because the divisor
@@ -467,12 +479,7 @@ unsafe fn __floordiv__(a_obj: *mut PyObject, b_obj: *mut PyObject) -> PyReturn {
         if b == 0 {
             Err(py_err!(PyExc_ZeroDivisionError, "Division by zero"))?
         }
-        let mut result = a / b;
-        // Adjust for "correct" (Python style) floor division with mixed signs
-        if a.signum() != b.signum() && a % b != 0 {
-            result -= 1;
-        }
-        result.to_py()
+        floor_divmod(a, b).0.to_py()
     } else {
         Ok(newref(Py_NotImplemented()))
     }
@@ -486,12 +493,26 @@ unsafe fn __mod__(a_obj: *mut PyObject, b_obj: *mut PyObject) -> PyReturn {
         if b == 0 {
             Err(py_err!(PyExc_ZeroDivisionError, "Division by zero"))?
         }
-        let mut result = a % b;
-        // Adjust for "correct" (Python style) floor division with mixed signs
-        if a.signum() != b.signum() && result != 0 {
-            result += b;
+        TimeDelta::from_nanos_unchecked(floor_divmod(a, b).1).to_obj(type_a)
+    } else {
+        Ok(newref(Py_NotImplemented()))
+    }
+}
+
+unsafe fn __divmod__(a_obj: *mut PyObject, b_obj: *mut PyObject) -> PyReturn {
+    let type_a = Py_TYPE(a_obj);
+    if type_a == Py_TYPE(b_obj) {
+        let a = TimeDelta::extract(a_obj).total_nanos();
+        let b = TimeDelta::extract(b_obj).total_nanos();
+        if b == 0 {
+            Err(py_err!(PyExc_ZeroDivisionError, "Division by zero"))?
         }
-        TimeDelta::from_nanos_unchecked(result).to_obj(type_a)
+        let (quotient, remainder) = floor_divmod(a, b);
+        (
+            steal!(quotient.to_py()?),
+            steal!(TimeDelta::from_nanos_unchecked(remainder).to_obj(type_a)?),
+        )
+            .to_py()
     } else {
         Ok(newref(Py_NotImplemented()))
     }
@@ -582,6 +603,7 @@ static mut SLOTS: &[PyType_Slot] = &[
     slotmethod!(Py_nb_absolute, __abs__, 1),
     slotmethod!(Py_nb_floor_divide, __floordiv__, 2),
     slotmethod!(Py_nb_remainder, __mod__, 2),
+    slotmethod!(Py_nb_divmod, __divmod__, 2),
     PyType_Slot {
         slot: Py_tp_doc,
         pfunc: doc::TIMEDELTA.as_ptr() as *mut c_void,
@@ -664,6 +686,11 @@ unsafe fn in_days_of_24h(slf: *mut PyObject, _: *mut PyObject) -> PyReturn {
     (secs as f64 / 86_400.0 + nanos as f64 * 1e-9 / 86_400.0).to_py()
 }
 
+unsafe fn whole_days_of_24h(slf: *mut PyObject, _: *mut PyObject) -> PyReturn {
+    // Integer division truncates toward zero, also for negative deltas
+    ((TimeDelta::extract(slf).total_nanos() / 86_400_000_000_000) as i64).to_py()
+}
+
 unsafe fn from_py_timedelta(cls: *mut PyObject, d: *mut PyObject) -> PyReturn {
     if PyDelta_Check(d) == 0 {
         Err(type_err!("argument must be datetime.timedelta"))?;
@@ -680,6 +707,92 @@ unsafe fn from_py_timedelta(cls: *mut PyObject, d: *mut PyObject) -> PyReturn {
     .to_obj(cls.cast())
 }
 
+unsafe fn from_units(
+    _: *mut PyObject,
+    cls: *mut PyTypeObject,
+    args: &[*mut PyObject],
+    kwargs: &mut KwargIter,
+) -> PyReturn {
+    let &State {
+        str_weeks,
+        str_days,
+        str_hours,
+        str_minutes,
+        str_seconds,
+        str_milliseconds,
+        str_microseconds,
+        str_nanoseconds,
+        ..
+    } = State::for_type(cls);
+    if !args.is_empty() {
+        Err(type_err!("from_units() takes no positional arguments"))?
+    }
+    let mut nanos: i128 = 0;
+    handle_kwargs("from_units", kwargs, |key, value, eq| {
+        let (max, name, factor) = if eq(key, str_weeks) {
+            ((MAX_SECS / 604_800).into(), "weeks", 604_800_000_000_000)
+        } else if eq(key, str_days) {
+            ((MAX_SECS / SECS_PER_DAY).into(), "days", 86_400_000_000_000)
+        } else if eq(key, str_hours) {
+            (MAX_HOURS.into(), "hours", 3_600_000_000_000)
+        } else if eq(key, str_minutes) {
+            (MAX_MINUTES.into(), "minutes", 60_000_000_000)
+        } else if eq(key, str_seconds) {
+            (MAX_SECS.into(), "seconds", 1_000_000_000)
+        } else if eq(key, str_milliseconds) {
+            (MAX_MILLISECONDS.into(), "milliseconds", 1_000_000)
+        } else if eq(key, str_microseconds) {
+            (MAX_MICROSECONDS.into(), "microseconds", 1_000)
+        } else if eq(key, str_nanoseconds) {
+            (MAX_NANOSECONDS, "nanoseconds", 1)
+        } else {
+            return Ok(false);
+        };
+        nanos += handle_precise_unit(value, max, name, factor)?;
+        Ok(true)
+    })?;
+    TimeDelta::from_nanos(nanos)
+        .ok_or_value_err("TimeDelta out of range")?
+        .to_obj(cls)
+}
+
+// Like `handle_exact_unit`, but floats are converted exactly
+// (based on their shortest decimal representation),
+// rejecting amounts that aren't a whole number of nanoseconds.
+unsafe fn handle_precise_unit(
+    value: *mut PyObject,
+    max: i128,
+    name: &str,
+    factor: i128,
+) -> PyResult<i128> {
+    if let Some(i) = value.to_i128()? {
+        return if (-max..=max).contains(&i) {
+            Ok(i * factor)
+        } else {
+            Err(value_err!("{} out of range", name))
+        };
+    }
+    let f = value
+        .to_f64()?
+        .ok_or_else(|| type_err!("{} must be an integer or float", name))?;
+    if !(-max as f64..=max as f64).contains(&f) {
+        Err(value_err!("{} out of range", name))?
+    }
+    float_to_nanos(f, factor)
+        .ok_or_else(|| value_err!("{} must be a whole number of nanoseconds", name))
+}
+
+fn float_to_nanos(f: f64, factor: i128) -> Option<i128> {
+    // Rust formats floats with the shortest representation that
+    // round-trips, and never in scientific notation.
+    let s = format!("{}", f.abs());
+    let (whole, fraction) = s.split_once('.').unwrap_or((&s, ""));
+    let scale = 10_i128.checked_pow(fraction.len() as u32)?;
+    let digits: i128 = format!("{}{}", whole, fraction).parse().ok()?;
+    let total = digits.checked_mul(factor)?;
+    (total % scale == 0).then(|| total / scale * if f < 0.0 { -1 } else { 1 })
+}
+
 unsafe fn py_timedelta(slf: *mut PyObject, _: *mut PyObject) -> PyReturn {
     let TimeDelta { nanos, secs } = TimeDelta::extract(slf);
     let &PyDateTime_CAPI {
@@ -730,6 +843,26 @@ unsafe fn in_hrs_mins_secs_nanos(slf: *mut PyObject, _: *mut PyObject) -> PyRetu
         .to_py()
 }
 
+unsafe fn as_tuple(slf: *mut PyObject, _: *mut PyObject) -> PyReturn {
+    let delta = TimeDelta::extract(slf);
+    let sign: i32 = if delta.secs < 0 {
+        -1
+    } else if delta.secs == 0 && delta.nanos == 0 {
+        0
+    } else {
+        1
+    };
+    let TimeDelta { secs, nanos } = delta.abs();
+    (
+        steal!(sign.to_py()?),
+        steal!((secs / 3_600).to_py()?),
+        steal!((secs % 3_600 / 60).to_py()?),
+        steal!((secs % 60).to_py()?),
+        steal!(nanos.to_py()?),
+    )
+        .to_py()
+}
+
 #[inline]
 pub(crate) fn format_components(td: TimeDelta, s: &mut String) {
     let TimeDelta { mut secs, nanos } = td;
@@ -882,6 +1015,70 @@ unsafe fn parse_common_iso(cls: *mut PyObject, s_obj: *mut PyObject) -> PyReturn
         .to_obj(cls.cast())
 }
 
+unsafe fn isclose(
+    slf: *mut PyObject,
+    cls: *mut PyTypeObject,
+    args: &[*mut PyObject],
+    kwargs: &mut KwargIter,
+) -> PyReturn {
+    let &[other] = args else {
+        Err(type_err!(
+            "isclose() takes exactly 1 positional argument ({} given)",
+            args.len()
+        ))?
+    };
+    let &State { str_abs_tol, .. } = State::for_type(cls);
+    let mut abs_tol = None;
+    handle_kwargs("isclose", kwargs, |key, value, eq| {
+        if eq(key, str_abs_tol) {
+            abs_tol = Some(value);
+            Ok(true)
+        } else {
+            Ok(false)
+        }
+    })?;
+    let abs_tol =
+        abs_tol.ok_or_type_err("isclose() missing required keyword argument: 'abs_tol'")?;
+    if Py_TYPE(other) != cls || Py_TYPE(abs_tol) != cls {
+        Err(type_err!("isclose() arguments must be TimeDelta instances"))?
+    }
+    let tolerance = TimeDelta::extract(abs_tol).total_nanos();
+    if tolerance < 0 {
+        Err(value_err!("abs_tol must not be negative"))?
+    }
+    ((TimeDelta::extract(slf).total_nanos() - TimeDelta::extract(other).total_nanos()).abs()
+        <= tolerance)
+        .to_py()
+}
+
+unsafe fn clamp(slf: &mut PyObject, args: &[*mut PyObject]) -> PyReturn {
+    let &[lower_obj, upper_obj] = args else {
+        Err(type_err!(
+            "clamp() takes exactly 2 positional arguments ({} given)",
+            args.len()
+        ))?
+    };
+    let cls = Py_TYPE(slf);
+    if Py_TYPE(lower_obj) != cls || Py_TYPE(upper_obj) != cls {
+        Err(type_err!("clamp() arguments must be TimeDelta instances"))?
+    }
+    let lower = TimeDelta::extract(lower_obj);
+    let upper = TimeDelta::extract(upper_obj);
+    if lower > upper {
+        Err(value_err!(
+            "lower bound must not be greater than upper bound"
+        ))?
+    }
+    let delta = TimeDelta::extract(slf);
+    Ok(newref(if delta < lower {
+        &mut *lower_obj
+    } else if delta > upper {
+        &mut *upper_obj
+    } else {
+        slf
+    }))
+}
+
 unsafe fn round(
     slf: *mut PyObject,
     cls: *mut PyTypeObject,
@@ -916,17 +1113,22 @@ static mut METHODS: &[PyMethodDef] = &[
     method!(in_minutes, doc::TIMEDELTA_IN_MINUTES),
     method!(in_hours, doc::TIMEDELTA_IN_HOURS),
     method!(in_days_of_24h, doc::TIMEDELTA_IN_DAYS_OF_24H),
+    method!(whole_days_of_24h, doc::TIMEDELTA_WHOLE_DAYS_OF_24H),
     method!(
         from_py_timedelta,
         doc::TIMEDELTA_FROM_PY_TIMEDELTA,
         METH_O | METH_CLASS
     ),
+    method_kwargs!(from_units, doc::TIMEDELTA_FROM_UNITS, METH_CLASS),
     method!(py_timedelta, doc::TIMEDELTA_PY_TIMEDELTA),
     method!(
         in_hrs_mins_secs_nanos,
         doc::TIMEDELTA_IN_HRS_MINS_SECS_NANOS
     ),
+    method!(as_tuple, doc::TIMEDELTA_AS_TUPLE),
     method_kwargs!(round, doc::TIMEDELTA_ROUND),
+    method_kwargs!(isclose, doc::TIMEDELTA_ISCLOSE),
+    method_vararg!(clamp, doc::TIMEDELTA_CLAMP),
     PyMethodDef::zeroed(),
 ];
 
@@ -57,19 +57,10 @@ impl ZonedDateTime {
         zoneinfo: *mut PyObject,
         dis: Option<Disambiguate>,
         preferred_offset: i32,
-        exc_repeated: *mut PyObject,
-        exc_skipped: *mut PyObject,
+        state: &State,
     ) -> PyResult<Self> {
         match dis {
-            Some(d) => Self::resolve_using_disambiguate(
-                py_api,
-                date,
-                time,
-                zoneinfo,
-                d,
-                exc_repeated,
-                exc_skipped,
-            ),
+            Some(d) => Self::resolve_using_disambiguate(py_api, date, time, zoneinfo, d, state),
             None => Self::resolve_using_offset(py_api, date, time, zoneinfo, preferred_offset),
         }
     }
@@ -80,8 +71,7 @@ impl ZonedDateTime {
         time: Time,
         zoneinfo: *mut PyObject,
         dis: Disambiguate,
-        exc_repeated: *mut PyObject,
-        exc_skipped: *mut PyObject,
+        state: &State,
     ) -> PyResult<Self> {
         use Disambiguate::*;
         use OffsetResult::*;
@@ -91,13 +81,17 @@ impl ZonedDateTime {
                 let offset_secs = match dis {
                     Compatible | Earlier => offset0,
                     Later => offset1,
-                    Raise => Err(py_err!(
-                        exc_repeated,
-                        "{} {} is repeated in timezone '{}'",
-                        date,
-                        time,
-                        zoneinfo_key(zoneinfo)
-                    ))?,
+                    Raise => {
+                        let key = zoneinfo_key(zoneinfo);
+                        Err(raise_dst_error(
+                            state,
+                            state.exc_repeated,
+                            format!("{} {} is repeated in timezone '{}'", date, time, key),
+                            date,
+                            time,
+                            Some(&key),
+                        ))?
+                    }
                 };
                 ZonedDateTime::new(date, time, offset_secs, zoneinfo)
             }
@@ -105,13 +99,17 @@ impl ZonedDateTime {
                 let (offset_secs, shift) = match dis {
                     Compatible | Later => (offset1, offset1 - offset0),
                     Earlier => (offset0, offset0 - offset1),
-                    Raise => Err(py_err!(
-                        exc_skipped,
-                        "{} {} is skipped in timezone '{}'",
-                        date,
-                        time,
-                        zoneinfo_key(zoneinfo)
-                    ))?,
+                    Raise => {
+                        let key = zoneinfo_key(zoneinfo);
+                        Err(raise_dst_error(
+                            state,
+                            state.exc_skipped,
+                            format!("{} {} is skipped in timezone '{}'", date, time, key),
+                            date,
+                            time,
+                            Some(&key),
+                        ))?
+                    }
                 };
                 DateTime { date, time }
                     .small_shift_unchecked(shift)
@@ -176,8 +174,7 @@ impl ZonedDateTime {
         days: i32,
         delta: TimeDelta,
         dis: Option<Disambiguate>,
-        exc_repeated: *mut PyObject,
-        exc_skipped: *mut PyObject,
+        state: &State,
     ) -> PyResult<Self> {
         let shifted_by_date = if months != 0 || days != 0 {
             let ZonedDateTime {
@@ -194,8 +191,7 @@ impl ZonedDateTime {
                 zoneinfo,
                 dis,
                 offset_secs,
-                exc_repeated,
-                exc_skipped,
+                state,
             )?
         } else {
             self
@@ -297,15 +293,59 @@ unsafe fn zoneinfo_key(zoneinfo: *mut PyObject) -> String {
     .to_string()
 }
 
+/// Raise a SkippedTime or RepeatedTime exception, with the local date, time,
+/// and timezone key (None for the system timezone) available as attributes.
+pub(crate) unsafe fn raise_dst_error(
+    state: &State,
+    exc_type: *mut PyObject,
+    msg: String,
+    date: Date,
+    time: Time,
+    tz: Option<&str>,
+) -> PyErrOccurred {
+    unsafe fn build(
+        state: &State,
+        exc_type: *mut PyObject,
+        msg: String,
+        date: Date,
+        time: Time,
+        tz: Option<&str>,
+    ) -> PyReturn {
+        let exc = call1(exc_type, steal!(msg.to_py()?))?;
+        let date_obj = date.to_obj(state.date_type)?;
+        defer_decref!(date_obj);
+        let time_obj = time.to_obj(state.time_type)?;
+        defer_decref!(time_obj);
+        let tz_obj = match tz {
+            Some(key) => key.to_py()?,
+            None => newref(Py_None()),
+        };
+        defer_decref!(tz_obj);
+        if PyObject_SetAttrString(exc, c"date".as_ptr(), date_obj) != 0
+            || PyObject_SetAttrString(exc, c"time".as_ptr(), time_obj) != 0
+            || PyObject_SetAttrString(exc, c"tz".as_ptr(), tz_obj) != 0
+        {
+            Py_DECREF(exc);
+            return Err(PyErrOccurred());
+        }
+        Ok(exc)
+    }
+    // If building the exception fails, that error is raised instead
+    if let Ok(exc) = build(state, exc_type, msg, date, time, tz) {
+        PyErr_SetObject(exc_type, exc);
+        Py_DECREF(exc);
+    }
+    PyErrOccurred()
+}
+
 unsafe fn __new__(cls: *mut PyTypeObject, args: *mut PyObject, kwargs: *mut PyObject) -> PyReturn {
+    let state = State::for_type(cls);
     let &State {
         zoneinfo_type,
         py_api,
-        exc_repeated,
-        exc_skipped,
         str_compatible,
         ..
-    } = State::for_type(cls);
+    } = state;
     let mut year: c_long = 0;
     let mut month: c_long = 0;
     let mut day: c_long = 0;
@@ -353,19 +393,10 @@ unsafe fn __new__(cls: *mut PyTypeObject, args: *mut PyObject, kwargs: *mut PyOb
     let zoneinfo = call1(zoneinfo_type, tz)?;
     defer_decref!(zoneinfo);
 
-    let date = Date::from_longs(year, month, day).ok_or_value_err("Invalid date")?;
-    let time = Time::from_longs(hour, minute, second, nanos).ok_or_value_err("Invalid time")?;
+    let date = Date::from_longs_or_err(year, month, day, state)?;
+    let time = Time::from_longs(hour, minute, second, nanos)?;
     let dis = Disambiguate::from_py(disambiguate)?;
-    ZonedDateTime::resolve_using_disambiguate(
-        py_api,
-        date,
-        time,
-        zoneinfo,
-        dis,
-        exc_repeated,
-        exc_skipped,
-    )?
-    .to_obj(cls)
+    ZonedDateTime::resolve_using_disambiguate(py_api, date, time, zoneinfo, dis, state)?.to_obj(cls)
 }
 
 unsafe extern "C" fn dealloc(slf: *mut PyObject) {
@@ -433,15 +464,14 @@ unsafe fn _shift_operator(obj_a: *mut PyObject, obj_b: *mut PyObject, negate: bo
     );
     let type_a = Py_TYPE(obj_a);
     let type_b = Py_TYPE(obj_b);
+    let state = State::for_type(type_a);
     let &State {
         time_delta_type,
         date_delta_type,
         datetime_delta_type,
         py_api,
-        exc_repeated,
-        exc_skipped,
         ..
-    } = State::for_type(type_a);
+    } = state;
 
     let zdt = ZonedDateTime::extract(obj_a);
     let mut months = 0;
@@ -468,16 +498,8 @@ unsafe fn _shift_operator(obj_a: *mut PyObject, obj_b: *mut PyObject, negate: bo
         tdelta = -tdelta;
     };
 
-    zdt.shift(
-        py_api,
-        months,
-        days,
-        tdelta,
-        None,
-        exc_repeated,
-        exc_skipped,
-    )?
-    .to_obj(type_a)
+    zdt.shift(py_api, months, days, tdelta, None, state)?
+        .to_obj(type_a)
 }
 
 unsafe fn __add__(slf: *mut PyObject, arg: *mut PyObject) -> PyReturn {
@@ -593,12 +615,10 @@ pub(crate) unsafe fn unpickle(module: &mut PyObject, args: &[*mut PyObject]) ->
         zoned_datetime_type,
         ..
     } = State::for_mod(module);
-    let mut packed = data.to_bytes()?.ok_or_type_err("Invalid pickle data")?;
+    let mut packed =
+        strip_pickle_version(data.to_bytes()?.ok_or_type_err("Invalid pickle data")?, 15)?;
     let zoneinfo = call1(zoneinfo_type, tz)?;
     defer_decref!(zoneinfo);
-    if packed.len() != 15 {
-        Err(type_err!("Invalid pickle data"))?;
-    }
     ZonedDateTime {
         date: Date {
             year: unpack_one!(packed, u16),
@@ -710,14 +730,13 @@ unsafe fn replace_date(
     args: &[*mut PyObject],
     kwargs: &mut KwargIter,
 ) -> PyReturn {
+    let state = State::for_obj(slf);
     let &State {
         date_type,
         py_api,
         str_disambiguate,
-        exc_skipped,
-        exc_repeated,
         ..
-    } = State::for_obj(slf);
+    } = state;
 
     let &[arg] = args else {
         Err(type_err!(
@@ -741,8 +760,7 @@ unsafe fn replace_date(
             zoneinfo,
             dis,
             offset_secs,
-            exc_repeated,
-            exc_skipped,
+            state,
         )?
         .to_obj(cls)
     } else {
@@ -756,14 +774,13 @@ unsafe fn replace_time(
     args: &[*mut PyObject],
     kwargs: &mut KwargIter,
 ) -> PyReturn {
+    let state = State::for_obj(slf);
     let &State {
         time_type,
         py_api,
         str_disambiguate,
-        exc_skipped,
-        exc_repeated,
         ..
-    } = State::for_obj(slf);
+    } = state;
 
     let &[arg] = args else {
         Err(type_err!(
@@ -787,8 +804,7 @@ unsafe fn replace_time(
             zoneinfo,
             dis,
             offset_secs,
-            exc_repeated,
-            exc_skipped,
+            state,
         )?
         .to_obj(cls)
     } else {
@@ -853,19 +869,9 @@ unsafe fn replace(
         Ok(true)
     })?;
 
-    let date = Date::from_longs(year, month, day).ok_or_value_err("Invalid date")?;
-    let time = Time::from_longs(hour, minute, second, nanos).ok_or_value_err("Invalid time")?;
-    ZonedDateTime::resolve(
-        state.py_api,
-        date,
-        time,
-        zoneinfo,
-        dis,
-        offset_secs,
-        state.exc_repeated,
-        state.exc_skipped,
-    )?
-    .to_obj(cls)
+    let date = Date::from_longs_or_err(year, month, day, state)?;
+    let time = Time::from_longs(hour, minute, second, nanos)?;
+    ZonedDateTime::resolve(state.py_api, date, time, zoneinfo, dis, offset_secs, state)?.to_obj(cls)
 }
 
 unsafe fn now(cls: *mut PyObject, tz: *mut PyObject) -> PyReturn {
@@ -1004,7 +1010,17 @@ unsafe fn __reduce__(slf: *mut PyObject, _: *mut PyObject) -> PyReturn {
         offset_secs,
         zoneinfo,
     } = ZonedDateTime::extract(slf);
-    let data = pack![year, month, day, hour, minute, second, nanos, offset_secs];
+    let data = pack![
+        PICKLE_VERSION,
+        year,
+        month,
+        day,
+        hour,
+        minute,
+        second,
+        nanos,
+        offset_secs
+    ];
     (
         State::for_obj(slf).unpickle_zoned_datetime,
         steal!((
@@ -1120,6 +1136,14 @@ unsafe fn from_timestamp_nanos(
     .to_obj(cls)
 }
 
+unsafe fn in_utc(slf: *mut PyObject, _: *mut PyObject) -> PyReturn {
+    ZonedDateTime::extract(slf)
+        .instant()
+        .to_datetime()
+        .with_offset_unchecked(0)
+        .to_obj(State::for_obj(slf).offset_datetime_type)
+}
+
 unsafe fn is_ambiguous(slf: *mut PyObject, _: *mut PyObject) -> PyReturn {
     let ZonedDateTime {
         date,
@@ -1207,9 +1231,9 @@ unsafe fn parse_common_iso(cls: *mut PyObject, s_obj: *mut PyObject) -> PyReturn
     defer_decref!(zoneinfo);
     let offset_is_valid = match OffsetResult::for_tz(py_api, date, time, zoneinfo)? {
         OffsetResult::Unambiguous(o) => o == offset_secs,
-        OffsetResult::Gap(o1, o2) | OffsetResult::Fold(o1, o2) => {
-            o1 == offset_secs || o2 == offset_secs
-        }
+        OffsetResult::Fold(o1, o2) => o1 == offset_secs || o2 == offset_secs,
+        // Skipped times don't exist in the timezone, regardless of offset
+        OffsetResult::Gap(_, _) => false,
     };
     if offset_is_valid {
         ZonedDateTime::new(date, time, offset_secs, zoneinfo)
@@ -1309,15 +1333,7 @@ unsafe fn _shift_method(
     }
 
     ZonedDateTime::extract(slf)
-        .shift(
-            state.py_api,
-            months,
-            days,
-            tdelta,
-            dis,
-            state.exc_repeated,
-            state.exc_skipped,
-        )?
+        .shift(state.py_api, months, days, tdelta, dis, state)?
         .to_obj(cls)
 }
 
@@ -1341,43 +1357,49 @@ unsafe fn difference(obj_a: *mut PyObject, obj_b: *mut PyObject) -> PyReturn {
     inst_a.diff(inst_b).to_obj(state.time_delta_type)
 }
 
-unsafe fn start_of_day(slf: *mut PyObject, _: *mut PyObject) -> PyReturn {
-    let ZonedDateTime { date, zoneinfo, .. } = ZonedDateTime::extract(slf);
+unsafe fn start_of_day(
+    slf: *mut PyObject,
+    cls: *mut PyTypeObject,
+    args: &[*mut PyObject],
+    kwargs: &mut KwargIter,
+) -> PyReturn {
+    let state = State::for_type(cls);
     let &State {
         py_api,
-        exc_repeated,
-        exc_skipped,
+        str_disambiguate,
         ..
-    } = State::for_obj(slf);
+    } = state;
+    if !args.is_empty() {
+        Err(type_err!("start_of_day() takes no positional arguments"))?
+    }
+    let dis = Disambiguate::from_only_kwarg(kwargs, str_disambiguate, "start_of_day")?;
+    let ZonedDateTime { date, zoneinfo, .. } = ZonedDateTime::extract(slf);
     ZonedDateTime::resolve_using_disambiguate(
         py_api,
         date,
         MIDNIGHT,
         zoneinfo,
-        Disambiguate::Compatible,
-        exc_repeated,
-        exc_skipped,
+        dis.unwrap_or(Disambiguate::Compatible),
+        state,
     )?
-    .to_obj(Py_TYPE(slf))
+    .to_obj(cls)
 }
 
 unsafe fn day_length(slf: *mut PyObject, _: *mut PyObject) -> PyReturn {
     let ZonedDateTime { date, zoneinfo, .. } = ZonedDateTime::extract(slf);
+    let state = State::for_obj(slf);
     let &State {
         py_api,
-        exc_repeated,
-        exc_skipped,
         time_delta_type,
         ..
-    } = State::for_obj(slf);
+    } = state;
     let start_of_day = ZonedDateTime::resolve_using_disambiguate(
         py_api,
         date,
         MIDNIGHT,
         zoneinfo,
         Disambiguate::Compatible,
-        exc_repeated,
-        exc_skipped,
+        state,
     )?
     .instant();
     let start_of_next_day = ZonedDateTime::resolve_using_disambiguate(
@@ -1386,8 +1408,7 @@ unsafe fn day_length(slf: *mut PyObject, _: *mut PyObject) -> PyReturn {
         MIDNIGHT,
         zoneinfo,
         Disambiguate::Compatible,
-        exc_repeated,
-        exc_skipped,
+        state,
     )?
     .instant();
     start_of_next_day.diff(start_of_day).to_obj(time_delta_type)
@@ -1400,10 +1421,10 @@ unsafe fn round(
     kwargs: &mut KwargIter,
 ) -> PyReturn {
     let state = State::for_type(cls);
-    let (unit, increment, mode) = round::parse_args(state, args, kwargs, false, false)?;
+    let (unit, increment, mode, dis) = round::parse_args_disambiguate(state, args, kwargs)?;
 
     match unit {
-        round::Unit::Day => _round_day(slf, state, mode),
+        round::Unit::Day => _round_day(slf, state, mode, dis),
         _ => {
             let ZonedDateTime {
                 mut date,
@@ -1419,13 +1440,23 @@ unsafe fn round(
                     Err(value_err!("Resulting datetime out of range"))?
                 }
             };
-            ZonedDateTime::resolve_using_offset(
-                state.py_api,
-                date,
-                time_rounded,
-                zoneinfo,
-                offset_secs,
-            )
+            match dis {
+                Some(dis) => ZonedDateTime::resolve_using_disambiguate(
+                    state.py_api,
+                    date,
+                    time_rounded,
+                    zoneinfo,
+                    dis,
+                    state,
+                ),
+                None => ZonedDateTime::resolve_using_offset(
+                    state.py_api,
+                    date,
+                    time_rounded,
+                    zoneinfo,
+                    offset_secs,
+                ),
+            }
         }
     }?
     .to_obj(cls)
@@ -1435,48 +1466,27 @@ unsafe fn _round_day(
     slf: *mut PyObject,
     state: &State,
     mode: round::Mode,
+    dis: Option<Disambiguate>,
 ) -> PyResult<ZonedDateTime> {
     let ZonedDateTime {
         date,
         time,
+        offset_secs,
         zoneinfo,
-        ..
     } = ZonedDateTime::extract(slf);
-    let &State {
-        py_api,
-        exc_repeated,
-        exc_skipped,
-        ..
-    } = state;
-    let get_floor = || {
-        ZonedDateTime::resolve_using_disambiguate(
-            py_api,
-            date,
-            MIDNIGHT,
-            zoneinfo,
-            Disambiguate::Compatible,
-            exc_repeated,
-            exc_skipped,
-        )
-    };
-    let get_ceil = || {
-        ZonedDateTime::resolve_using_disambiguate(
-            py_api,
-            date.increment(),
-            MIDNIGHT,
-            zoneinfo,
-            Disambiguate::Compatible,
-            exc_repeated,
-            exc_skipped,
-        )
+    let &State { py_api, .. } = state;
+    // The day length is always determined by the "compatible" midnights.
+    // The disambiguation only applies to the midnight we round to.
+    let get_midnight = |d, dis| {
+        ZonedDateTime::resolve_using_disambiguate(py_api, d, MIDNIGHT, zoneinfo, dis, state)
     };
-    match mode {
-        round::Mode::Ceil => get_ceil(),
-        round::Mode::Floor => get_floor(),
+    let round_up = match mode {
+        round::Mode::Ceil => true,
+        round::Mode::Floor => false,
         _ => {
             let time_ns = time.total_nanos();
-            let floor = get_floor()?;
-            let ceil = get_ceil()?;
+            let floor = get_midnight(date, Disambiguate::Compatible)?;
+            let ceil = get_midnight(date.increment(), Disambiguate::Compatible)?;
             let day_ns = ceil.instant().diff(floor.instant()).total_nanos() as u64;
             debug_assert!(day_ns > 1);
             let threshold = match mode {
@@ -1485,13 +1495,18 @@ unsafe fn _round_day(
                 round::Mode::HalfCeil => day_ns / 2,
                 _ => unreachable!(),
             };
-            if time_ns >= threshold {
-                Ok(ceil)
-            } else {
-                Ok(floor)
-            }
+            time_ns >= threshold
         }
-    }
+    };
+    ZonedDateTime::resolve(
+        py_api,
+        if round_up { date.increment() } else { date },
+        MIDNIGHT,
+        zoneinfo,
+        dis,
+        offset_secs,
+        state,
+    )
 }
 
 static mut METHODS: &[PyMethodDef] = &[
@@ -1504,6 +1519,7 @@ static mut METHODS: &[PyMethodDef] = &[
     method!(exact_eq, doc::KNOWSINSTANT_EXACT_EQ, METH_O),
     method!(py_datetime, doc::BASICCONVERSIONS_PY_DATETIME),
     method!(instant, doc::KNOWSINSTANTANDLOCAL_INSTANT),
+    method!(instant named "to_instant", doc::KNOWSINSTANTANDLOCAL_TO_INSTANT),
     method!(local, doc::KNOWSINSTANTANDLOCAL_LOCAL),
     method!(date, doc::KNOWSLOCAL_DATE),
     method!(time, doc::KNOWSLOCAL_TIME),
@@ -1522,6 +1538,7 @@ static mut METHODS: &[PyMethodDef] = &[
     method!(timestamp, doc::KNOWSINSTANT_TIMESTAMP),
     method!(timestamp_millis, doc::KNOWSINSTANT_TIMESTAMP_MILLIS),
     method!(timestamp_nanos, doc::KNOWSINSTANT_TIMESTAMP_NANOS),
+    method!(in_utc, doc::ZONEDDATETIME_IN_UTC),
     method!(is_ambiguous, doc::ZONEDDATETIME_IS_AMBIGUOUS),
     method_kwargs!(
         from_timestamp,
@@ -1544,7 +1561,7 @@ static mut METHODS: &[PyMethodDef] = &[
     method_kwargs!(add, doc::ZONEDDATETIME_ADD),
     method_kwargs!(subtract, doc::ZONEDDATETIME_SUBTRACT),
     method!(difference, doc::KNOWSINSTANT_DIFFERENCE, METH_O),
-    method!(start_of_day, doc::ZONEDDATETIME_START_OF_DAY),
+    method_kwargs!(start_of_day, doc::ZONEDDATETIME_START_OF_DAY),
     method!(day_length, doc::ZONEDDATETIME_DAY_LENGTH),
     method_kwargs!(round, doc::ZONEDDATETIME_ROUND),
     PyMethodDef::zeroed(),
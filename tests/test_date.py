@@ -1,20 +1,34 @@
+import locale
 import pickle
 import re
+from calendar import monthrange
 from copy import copy, deepcopy
-from datetime import date as py_date, datetime as py_datetime
-from itertools import chain, product
+from datetime import (
+    date as py_date,
+    datetime as py_datetime,
+    timedelta,
+    timezone,
+)
+from inspect import signature
+from itertools import chain, islice, product
+from operator import index as operator_index
 
 import pytest
+from hypothesis import given
+from hypothesis.strategies import integers
 
 from whenever import (
+    _EXTENSION_LOADED,
     Date,
     DateDelta,
     LocalDateTime,
     MonthDay,
     Time,
+    UnsupportedYear,
     Weekday,
     YearMonth,
     days,
+    hours,
 )
 
 from .common import AlwaysEqual, AlwaysLarger, AlwaysSmaller, NeverEqual
@@ -68,6 +82,26 @@ class TestInit:
         with pytest.raises(TypeError):
             Date(*args, **kwargs)
 
+    def test_iso_string(self):
+        assert Date("2021-01-02") == Date(2021, 1, 2)
+        assert Date("0001-01-01") == Date.MIN
+
+        with pytest.raises(ValueError, match="2021-1-2"):
+            Date("2021-1-2")
+
+        with pytest.raises(ValueError):
+            Date("2021-02-30")
+
+        with pytest.raises(ValueError):
+            Date("2021-W01-1")
+
+        # strings are only accepted as the sole argument
+        with pytest.raises(TypeError):
+            Date("2021-01-02", 1, 2)  # type: ignore[call-overload]
+
+        with pytest.raises(TypeError):
+            Date("2021-01-02", day=2)  # type: ignore[call-overload]
+
     def test_not_enough_args(self):
         with pytest.raises(TypeError, match=r"day"):
             Date(2021, 1)  # type: ignore[call-arg]
@@ -78,6 +112,12 @@ class TestInit:
         with pytest.raises(TypeError, match=r"year"):
             Date()  # type: ignore[call-arg]
 
+    @pytest.mark.skipif(
+        _EXTENSION_LOADED, reason="extension types have no init signature"
+    )
+    def test_signature(self):
+        assert list(signature(Date).parameters) == ["year", "month", "day"]
+
     @pytest.mark.parametrize(
         "year, month, day",
         [
@@ -95,6 +135,17 @@ class TestInit:
         ):
             Date(year, month, day)
 
+    @pytest.mark.parametrize("year", [0, -1, -44, -MAX_I64])
+    def test_year_before_common_era(self, year):
+        with pytest.raises(UnsupportedYear, match="common era"):
+            Date(year, 3, 15)
+
+        with pytest.raises(UnsupportedYear):
+            Date(year=year, month=3, day=15)
+
+        # it's still a ValueError, for compatibility
+        assert issubclass(UnsupportedYear, ValueError)
+
     @pytest.mark.parametrize(
         "year, month, day",
         [
@@ -144,6 +195,34 @@ def test_month_day():
 def test_py_date():
     d = Date(2021, 1, 2)
     assert d.py_date() == py_date(2021, 1, 2)
+    assert type(d.py_date()) is py_date
+    assert type(d.py_date(py_date)) is py_date
+
+
+def test_py_date_subclass():
+    class MyDate(py_date):
+        def __new__(cls, year, month, day):
+            self = super().__new__(cls, year, month, day)
+            self.constructed = True
+            return self
+
+    d = Date(2021, 1, 2)
+    result = d.py_date(MyDate)
+    assert type(result) is MyDate
+    assert result == py_date(2021, 1, 2)
+    assert result.constructed
+
+    # datetime is also a subclass of date
+    assert d.py_date(py_datetime) == py_datetime(2021, 1, 2)
+
+    with pytest.raises(TypeError, match="subclass"):
+        d.py_date(int)  # type: ignore[type-var]
+
+    with pytest.raises(TypeError, match="subclass"):
+        d.py_date(py_date(2021, 1, 2))  # type: ignore[arg-type]
+
+    with pytest.raises(TypeError):
+        d.py_date(MyDate, MyDate)  # type: ignore[call-overload]
 
 
 def test_today_in_system_tz():
@@ -164,6 +243,23 @@ def test_from_py_date():
 
     assert Date.from_py_date(CustomDate(2021, 1, 2)) == Date(2021, 1, 2)
 
+
+def test_from_py_date_truncates_datetime():
+    # datetimes are accepted on purpose: only the date part is used,
+    # without any conversion based on the timezone
+    aware = py_datetime(2021, 1, 2, 23, 59, 59, 999_999, tzinfo=timezone.utc)
+    assert Date.from_py_date(aware) == Date(2021, 1, 2)
+    assert Date.from_py_date(
+        py_datetime(2021, 1, 2, 23, tzinfo=timezone(timedelta(hours=-5)))
+    ) == Date(2021, 1, 2)
+
+    class CustomDateTime(py_datetime):
+        pass
+
+    assert Date.from_py_date(CustomDateTime(2021, 1, 2, 12)) == Date(
+        2021, 1, 2
+    )
+
     with pytest.raises(TypeError):
         Date.from_py_date(20210102)  # type: ignore[arg-type]
 
@@ -178,6 +274,58 @@ def test_str():
     assert str(d) == "2021-01-02"
 
 
+class TestFormat:
+
+    def test_names(self):
+        d = Date(2024, 3, 15)
+        assert f"{d:%a %b}" == "Fri Mar"
+        assert f"{d:%A %B}" == "Friday March"
+        assert f"{Date(2023, 9, 3):%a %A %b %B}" == "Sun Sunday Sep September"
+
+    @pytest.mark.parametrize(
+        "name", ["de_DE.UTF-8", "fr_FR.UTF-8", "nl_NL.UTF-8"]
+    )
+    def test_names_ignore_locale(self, name):
+        original = locale.setlocale(locale.LC_TIME)
+        try:
+            locale.setlocale(locale.LC_TIME, name)
+        except locale.Error:
+            pytest.skip(f"locale {name} not available")
+        try:
+            assert f"{Date(2024, 3, 15):%a %b}" == "Fri Mar"
+        finally:
+            locale.setlocale(locale.LC_TIME, original)
+
+    @pytest.mark.parametrize(
+        "spec, expected",
+        [
+            ("", "0009-01-05"),
+            ("%Y-%m-%d", "0009-01-05"),
+            ("%y", "09"),
+            ("%j", "005"),
+            ("%w %u", "1 1"),
+            ("%%Y", "%Y"),
+            ("no directives", "no directives"),
+            ("%d—%m 🎉", "05—01 🎉"),
+        ],
+    )
+    def test_directives(self, spec, expected):
+        assert format(Date(9, 1, 5), spec) == expected
+
+    def test_numbers_same_as_strftime(self):
+        # (names aren't compared, since strftime depends on the locale)
+        spec = "%d %m %y %Y %j %w %u %%"
+        for d in [Date(2024, 1, 1) + n for n in range(0, 400, 11)]:
+            assert f"{d:{spec}}" == d.py_date().strftime(spec)
+
+    @pytest.mark.parametrize(
+        "spec", ["%H", "%Y-%m-%dT%H:%M", "%", "%Y%", "%Q"]
+    )
+    def test_invalid(self, spec):
+        with pytest.raises(ValueError, match="directive"):
+            format(Date(2024, 3, 15), spec)
+
+
 class TestParseCommonIso:
 
     @pytest.mark.parametrize(
@@ -216,6 +364,80 @@ class TestParseCommonIso:
             Date.parse_common_iso(20210102)  # type: ignore[arg-type]
 
 
+class TestParseIso:
+
+    @pytest.mark.parametrize(
+        "s, expected",
+        [
+            ("2021-01-02", Date(2021, 1, 2)),
+            # week dates
+            ("2024-W10-1", Date(2024, 3, 4)),
+            ("2024-W01-1", Date(2024, 1, 1)),
+            ("2021-W01-1", Date(2021, 1, 4)),
+            ("2020-W53-5", Date(2021, 1, 1)),
+            ("2025-W01-2", Date(2024, 12, 31)),
+            ("0001-W01-1", Date.MIN),
+            ("9999-W52-5", Date.MAX),
+            # ordinal dates
+            ("2024-064", Date(2024, 3, 4)),
+            ("2024-001", Date(2024, 1, 1)),
+            ("2024-366", Date(2024, 12, 31)),
+            ("2023-365", Date(2023, 12, 31)),
+            ("0001-001", Date.MIN),
+            ("9999-365", Date.MAX),
+        ],
+    )
+    def test_valid(self, s, expected):
+        assert Date.parse_iso(s) == expected
+
+    @pytest.mark.parametrize(
+        "s",
+        [
+            "202A-01-02",  # non-digit
+            "2021-13-02",  # invalid month
+            "0000-01-01",  # year zero
+            "2021-W00-1",  # week zero
+            "2021-W53-1",  # year without week 53
+            "2021-W54-1",  # week out of range
+            "2021-W01-0",  # weekday zero
+            "2021-W01-8",  # weekday out of range
+            "9999-W52-6",  # beyond the max date
+            "0000-W52-7",  # year zero
+            "2021-w01-1",  # lowercase
+            "2021W011",  # basic format
+            "2021-W1-1",  # no padding
+            "2023-000",  # day zero
+            "2023-366",  # non-leap year
+            "2024-367",  # day out of range
+            "0000-001",  # year zero
+            "2021-1",  # no padding
+            "2021001",  # basic format
+            "2021-01-02T03:04:05",  # with a time
+            "202𝟙-001",  # non-ascii
+        ],
+    )
+    def test_invalid(self, s):
+        with pytest.raises(
+            ValueError,
+            match=r"Invalid format.*" + re.escape(repr(s)),
+        ):
+            Date.parse_iso(s)
+
+    @given(integers(1, 3_652_059))
+    def test_roundtrip(self, n):
+        d = Date.from_ordinal(n)
+        py_d = d.py_date()
+        year, week, weekday = py_d.isocalendar()
+        assert Date.parse_iso(f"{year:04}-W{week:02}-{weekday}") == d
+        day_of_year = py_d.timetuple().tm_yday
+        assert Date.parse_iso(f"{d.year:04}-{day_of_year:03}") == d
+        assert Date.parse_iso(str(d)) == d
+
+    def test_no_string(self):
+        with pytest.raises(TypeError, match="(int|str)"):
+            Date.parse_iso(20210102)  # type: ignore[arg-type]
+
+
 def test_replace():
     d = Date(2021, 1, 2)
     assert d.replace(year=2022) == Date(2022, 1, 2)
@@ -235,6 +457,118 @@ def test_replace():
     with pytest.raises(ValueError, match="(date|year)"):
         d.replace(year=10_000)
 
+    with pytest.raises(UnsupportedYear, match="common era"):
+        d.replace(year=0)
+
+    with pytest.raises(UnsupportedYear):
+        d.replace(year=-1, on_invalid="clamp")
+
+
+class TestReplaceOnInvalid:
+
+    def test_error_is_default(self):
+        d = Date(2024, 2, 29)
+        with pytest.raises(ValueError):
+            d.replace(year=2023)
+        with pytest.raises(ValueError):
+            d.replace(year=2023, on_invalid="error")
+        with pytest.raises(ValueError):
+            Date(2021, 1, 31).replace(month=4)
+
+    def test_clamp(self):
+        d = Date(2024, 2, 29)
+        assert d.replace(year=2023, on_invalid="clamp") == Date(2023, 2, 28)
+        assert d.replace(year=2028, on_invalid="clamp") == d.replace(year=2028)
+        assert d.replace(year=1900, on_invalid="clamp") == Date(1900, 2, 28)
+        assert Date(2021, 1, 31).replace(
+            month=4, on_invalid="clamp"
+        ) == Date(2021, 4, 30)
+        assert Date(2021, 1, 2).replace(
+            month=2, day=31, on_invalid="clamp"
+        ) == Date(2021, 2, 28)
+        # valid dates are unaffected
+        assert Date(2021, 1, 2).replace(day=3, on_invalid="clamp") == Date(
+            2021, 1, 3
+        )
+        assert Date(2021, 1, 2).replace(on_invalid="clamp") == Date(2021, 1, 2)
+
+    @pytest.mark.parametrize(
+        "kwargs",
+        [
+            dict(day=0),
+            dict(day=32),
+            dict(month=13),
+            dict(month=0),
+            dict(year=0),
+            dict(year=10_000),
+        ],
+    )
+    def test_clamp_still_rejects_out_of_range(self, kwargs):
+        with pytest.raises(ValueError):
+            Date(2021, 1, 2).replace(**kwargs, on_invalid="clamp")
+
+    def test_invalid_option(self):
+        d = Date(2024, 2, 29)
+        with pytest.raises(ValueError, match="on_invalid"):
+            d.replace(year=2023, on_invalid="ignore")  # type: ignore[arg-type]
+        with pytest.raises((TypeError, ValueError), match="on_invalid"):
+            d.replace(year=2023, on_invalid=1)  # type: ignore[arg-type]
+
+
+class TestWithField:
+
+    def test_examples(self):
+        d = Date(2021, 1, 2)
+        assert d.with_year(2022) == Date(2022, 1, 2)
+        assert d.with_month(2) == Date(2021, 2, 2)
+        assert d.with_day(3) == Date(2021, 1, 3)
+        assert d == Date(2021, 1, 2)  # original is unchanged
+
+    def test_matches_replace(self):
+        d = Date(2024, 2, 29)
+        assert d.with_year(2028) == d.replace(year=2028)
+        assert d.with_month(12) == d.replace(month=12)
+        assert d.with_day(1) == d.replace(day=1)
+
+    def test_nonexistent_day_is_error(self):
+        # Unlike add(), these don't clamp the day
+        d = Date(2024, 1, 31)
+        with pytest.raises(ValueError, match="day"):
+            d.with_month(2)
+        assert d.replace(month=2, on_invalid="clamp") == Date(2024, 2, 29)
+
+        with pytest.raises(ValueError, match="day"):
+            Date(2024, 2, 29).with_year(2023)
+
+        with pytest.raises(ValueError, match="day"):
+            Date(2023, 2, 1).with_day(29)
+
+    def test_out_of_range(self):
+        d = Date(2021, 1, 2)
+        with pytest.raises(ValueError, match="year"):
+            d.with_year(10_000)
+        with pytest.raises(UnsupportedYear, match="common era"):
+            d.with_year(0)
+        with pytest.raises(ValueError, match="month"):
+            d.with_month(13)
+        with pytest.raises(ValueError, match="month"):
+            d.with_month(0)
+        with pytest.raises(ValueError, match="day"):
+            d.with_day(0)
+        with pytest.raises(ValueError, match="day"):
+            d.with_day(32)
+        with pytest.raises((ValueError, OverflowError)):
+            d.with_day(1 << 64)
+
+    def test_invalid_type(self):
+        d = Date(2021, 1, 2)
+        with pytest.raises(TypeError, match="year"):
+            d.with_year("2022")  # type: ignore[arg-type]
+        with pytest.raises(TypeError, match="month"):
+            d.with_month(2.0)  # type: ignore[arg-type]
+        with pytest.raises(TypeError, match="day"):
+            d.with_day(None)  # type: ignore[arg-type]
+
 
 def test_kwarg_interning_bug_issue_149():
     d = Date(2021, 1, 2)
@@ -259,6 +593,14 @@ def test_hash():
     assert hash(d) != hash(Date(2021, 1, 3))
 
 
+def test_hash_is_stable():
+    # The hash doesn't depend on the memory layout or the hash seed,
+    # so it's the same across builds and runs.
+    assert hash(Date(2021, 1, 2)) == 2021 * 512 + 1 * 32 + 2
+    assert hash(Date.MIN) == 512 + 32 + 1
+    assert hash(Date.MAX) == 9999 * 512 + 12 * 32 + 31
+
+
 def test_eq():
     d = Date(2021, 1, 2)
     same = Date(2021, 1, 2)
@@ -312,6 +654,25 @@ def test_comparison():
     assert d > AlwaysSmaller()
 
 
+def test_not_comparable_with_tuple():
+    # Ordering against (year, month, day) tuples is deliberately unsupported:
+    # it couldn't be made consistent with equality and hashing.
+    # Construct a Date instead, e.g. Date(*t).
+    d = Date(2021, 5, 10)
+    t = (2021, 5, 10)
+    assert d != t
+    assert d == Date(*t)
+
+    with pytest.raises(TypeError):
+        d >= t  # type: ignore[operator]
+
+    with pytest.raises(TypeError):
+        d < t  # type: ignore[operator]
+
+    with pytest.raises(TypeError):
+        t <= d  # type: ignore[operator]
+
+
 class TestAdd:
 
     @pytest.mark.parametrize(
@@ -372,22 +733,124 @@ class TestAdd:
         with pytest.raises((OverflowError, ValueError)):
             d.add(DateDelta(**kwargs))
 
+    def test_int_days(self):
+        d = Date(2021, 1, 2)
+        assert d + 0 == d
+        assert d + 3 == Date(2021, 1, 5)
+        assert d + 365 == Date(2022, 1, 2)
+        assert d + -5 == Date(2020, 12, 28)
+        assert Date.MIN + (int(Date.MAX) - 1) == Date.MAX
+
+        with pytest.raises(ValueError, match="range"):
+            Date.MAX + 1
+        with pytest.raises(ValueError, match="range"):
+            d + MAX_I32
+        with pytest.raises(ValueError, match="range"):
+            d + (1 << 70)
+        with pytest.raises(ValueError, match="range"):
+            d + -(1 << 70)
+
     def test_invalid(self):
         with pytest.raises(TypeError):
             Date(2021, 1, 1) + None  # type: ignore[operator]
 
+        with pytest.raises(TypeError):
+            Date(2021, 1, 1) + 1.0  # type: ignore[operator]
+
+        with pytest.raises(TypeError):
+            Date(2021, 1, 1) + True  # type: ignore[operator]
+
+        with pytest.raises(TypeError):
+            1 + Date(2021, 1, 1)  # type: ignore[operator]
+
         with pytest.raises(TypeError):
             None + Date(2021, 1, 1)  # type: ignore[operator]
 
+    def test_unknown_unit(self):
+        # only calendar units are accepted, and they're listed explicitly
+        # in the signature, so there's no need for a separate alias
+        with pytest.raises(TypeError, match="hours"):
+            Date(2021, 1, 1).add(hours=1)  # type: ignore[call-overload]
+        assert Date(2021, 1, 31).add(
+            years=1, months=1, weeks=1, days=1
+        ) == Date(2022, 3, 8)
+
         with pytest.raises(TypeError):
             py_date(2020, 1, 1) + Date(2021, 1, 1)  # type: ignore[operator]
 
+    def test_time_delta(self):
+        d = Date(2021, 1, 1)
+        with pytest.raises(TypeError, match=r"no time component.*\.at\("):
+            d + hours(24)  # type: ignore[operator]
+
+        with pytest.raises(TypeError, match="unsupported operand"):
+            hours(24) + d  # type: ignore[operator]
+
     def test_no_mix_arg_kwargs(self):
         d = Date(2020, 1, 1)
         with pytest.raises(TypeError):
             d.add(DateDelta(years=1), months=1)  # type: ignore[call-overload]
 
 
+class TestAddWeeks:
+
+    def test_valid(self):
+        d = Date(2021, 1, 2)
+        assert d.add_weeks(0) == d
+        assert d.add_weeks(3) == Date(2021, 1, 23)
+        assert d.add_weeks(-1) == Date(2020, 12, 26)
+        assert d.add_weeks(60) == d.add(weeks=60)
+
+    @pytest.mark.parametrize(
+        "d, n",
+        [
+            (Date.MAX, 1),
+            (Date.MIN, -1),
+            (Date(2021, 1, 31), 600_000),
+            (Date(2024, 3, 15), 10**6),
+            (Date(2021, 1, 31), MAX_I32),
+            (Date(2021, 1, 31), MAX_I64 + 2),
+            (Date(2021, 1, 31), -MAX_I64 - 2),
+        ],
+    )
+    def test_out_of_range(self, d, n):
+        with pytest.raises(ValueError, match="range"):
+            d.add_weeks(n)
+
+    def test_invalid(self):
+        with pytest.raises(TypeError):
+            Date(2021, 1, 1).add_weeks(1.5)  # type: ignore[arg-type]
+
+        with pytest.raises(TypeError):
+            Date(2021, 1, 1).add_weeks(days(7))  # type: ignore[arg-type]
+
+
+@pytest.mark.parametrize(
+    "d, expected",
+    [
+        (Date(2021, 1, 2), Date(2021, 1, 3)),
+        (Date(2021, 1, 31), Date(2021, 2, 1)),
+        (Date(2024, 2, 28), Date(2024, 2, 29)),
+        (Date(2024, 2, 29), Date(2024, 3, 1)),
+        (Date(2023, 2, 28), Date(2023, 3, 1)),
+        (Date(2021, 12, 31), Date(2022, 1, 1)),
+        (Date.MIN, Date(1, 1, 2)),
+        (Date(9999, 12, 30), Date.MAX),
+    ],
+)
+def test_next_and_previous_day(d, expected):
+    assert d.next_day() == expected
+    assert expected.previous_day() == d
+
+
+def test_next_and_previous_day_out_of_range():
+    with pytest.raises(ValueError, match="range"):
+        Date(1, 1, 1).previous_day()
+
+    with pytest.raises(ValueError, match="range"):
+        Date(9999, 12, 31).next_day()
+
+
 class TestDaysUntilAndSince:
 
     @pytest.mark.parametrize(
@@ -407,11 +870,122 @@ class TestDaysUntilAndSince:
         assert d1.days_since(d2) == -expected
         assert d2.days_until(d1) == -expected
 
+    @given(integers(1, 3_652_059), integers(1, 3_652_059))
+    def test_symmetry(self, n1, n2):
+        a = Date.from_ordinal(n1)
+        b = Date.from_ordinal(n2)
+        assert a.days_since(b) == -b.days_since(a) == n1 - n2
+        assert a.days_since(b) == b.days_until(a)
+
     def test_invalid(self):
         with pytest.raises((TypeError, AttributeError)):
             Date(2021, 1, 1).days_until(LocalDateTime(2021, 1, 1, 1, 2, 3))  # type: ignore[arg-type]
 
 
+class TestLeapYearsUntil:
+
+    @pytest.mark.parametrize(
+        "d1, d2, expected",
+        [
+            (Date(2021, 1, 1), Date(2021, 12, 31), 0),
+            (Date(2020, 2, 28), Date(2020, 3, 1), 1),
+            # the bounds themselves don't count
+            (Date(2020, 2, 29), Date(2020, 3, 1), 0),
+            (Date(2020, 2, 28), Date(2020, 2, 29), 0),
+            (Date(2020, 2, 29), Date(2020, 2, 29), 0),
+            (Date(2020, 2, 29), Date(2024, 2, 29), 0),
+            # 2000 is a leap year, 1900 and 2100 aren't
+            (Date(1999, 12, 31), Date(2000, 12, 31), 1),
+            (Date(1896, 3, 1), Date(1904, 3, 1), 1),
+            (Date(1996, 1, 1), Date(2005, 1, 1), 3),
+            (Date(2096, 3, 1), Date(2104, 3, 1), 1),
+            (Date.MIN, Date.MAX, 2424),
+        ],
+    )
+    def test_examples(self, d1, d2, expected):
+        assert d1.leap_years_until(d2) == expected
+        assert d2.leap_years_until(d1) == -expected
+
+    @given(
+        integers(0, 3_000),
+        integers(0, 3_000),
+    )
+    def test_matches_counting(self, a, b):
+        d1 = Date(1890, 1, 1) + a
+        d2 = Date(1890, 1, 1) + b
+        start, end = sorted([d1, d2])
+        expected = sum(
+            1
+            for n in range(1, start.days_until(end))
+            if (start + n).month == 2 and (start + n).day == 29
+        )
+        assert d1.leap_years_until(d2) == (
+            expected if d1 <= d2 else -expected
+        )
+
+
+class TestBusinessDaysBetween:
+
+    def test_weekend_only_span(self):
+        sat = Date(2024, 3, 16)
+        assert sat.business_days_between(Date(2024, 3, 18)) == 0
+        assert Date(2024, 3, 18).business_days_between(sat) == 0
+
+    def test_default_weekend(self):
+        fri = Date(2024, 3, 15)
+        assert fri.business_days_between(fri) == 0
+        assert fri.business_days_between(Date(2024, 3, 16)) == 1
+        assert fri.business_days_between(Date(2024, 3, 19)) == 2
+        assert fri.business_days_between(Date(2024, 4, 15)) == 21
+        assert Date(2024, 4, 15).business_days_between(fri) == -21
+
+    def test_custom_weekend(self):
+        fri = Date(2024, 3, 15)
+        end = Date(2024, 3, 22)
+        assert (
+            fri.business_days_between(
+                end, weekend=[Weekday.FRIDAY, Weekday.SATURDAY]
+            )
+            == 5
+        )
+        assert fri.business_days_between(end, weekend=()) == 7
+        assert fri.business_days_between(end, weekend=list(Weekday)) == 0
+
+    def test_invalid(self):
+        d = Date(2024, 3, 15)
+        with pytest.raises(TypeError, match="Date"):
+            d.business_days_between(py_date(2024, 3, 19))  # type: ignore[arg-type]
+        with pytest.raises(TypeError, match="Weekday"):
+            d.business_days_between(d, weekend=[5, 6])  # type: ignore[list-item]
+        with pytest.raises(TypeError):
+            d.business_days_between(d, weekend=None)  # type: ignore[arg-type]
+        with pytest.raises(TypeError):
+            d.business_days_between(d, foo=1)  # type: ignore[call-arg]
+
+    @given(
+        integers(0, 3_000),
+        integers(0, 3_000),
+        integers(0, 127),
+    )
+    def test_matches_counting(self, a, b, mask):
+        d1 = Date(1890, 1, 1) + a
+        d2 = Date(1890, 1, 1) + b
+        weekend = [w for w in Weekday if mask & (1 << (w.value - 1))]
+        start, end = sorted([d1, d2])
+        expected = sum(
+            1
+            for n in range(start.days_until(end))
+            if (start + n).day_of_week() not in weekend
+        )
+        assert d1.business_days_between(d2, weekend=weekend) == (
+            expected if d1 <= d2 else -expected
+        )
+
+    def test_invalid(self):
+        with pytest.raises((TypeError, AttributeError)):
+            Date(2021, 1, 1).leap_years_until(LocalDateTime(2021, 1, 1))  # type: ignore[arg-type]
+
+
 _EXAMPLE_DATES = [
     *chain.from_iterable(
         [
@@ -585,9 +1159,27 @@ class TestSubtract:
         assert d1 - d2 == delta
         assert d2 + delta == d1
 
+    def test_int_days(self):
+        d = Date(2021, 1, 2)
+        assert d - 0 == d
+        assert d - 5 == Date(2020, 12, 28)
+        assert d - -5 == Date(2021, 1, 7)
+        assert Date.MAX - (int(Date.MAX) - 1) == Date.MIN
+
+        with pytest.raises(ValueError, match="range"):
+            Date.MIN - 1
+        with pytest.raises(ValueError, match="range"):
+            d - MAX_I32
+        with pytest.raises(ValueError, match="range"):
+            d - (1 << 70)
+        with pytest.raises(ValueError, match="range"):
+            d - -(1 << 70)
+
     def test_invalid_type(self):
         with pytest.raises(TypeError, match="unsupported operand"):
-            Date(2021, 1, 1) - 1  # type: ignore[operator]
+            Date(2021, 1, 1) - 1.0  # type: ignore[operator]
+        with pytest.raises(TypeError, match="unsupported operand"):
+            Date(2021, 1, 1) - True  # type: ignore[operator]
         with pytest.raises(TypeError, match="unsupported operand"):
             Date(2021, 1, 1) - "2021-01-01"  # type: ignore[operator]
 
@@ -597,6 +1189,14 @@ class TestSubtract:
         with pytest.raises(TypeError):
             3 - Date(2021, 1, 1)  # type: ignore[operator]
 
+    def test_time_delta(self):
+        d = Date(2021, 1, 1)
+        with pytest.raises(TypeError, match=r"no time component.*\.at\("):
+            d - hours(24)  # type: ignore[operator]
+
+        with pytest.raises(TypeError, match="unsupported operand"):
+            hours(24) - d  # type: ignore[operator]
+
     def test_fuzzing(self):
         for d1, d2 in product(_EXAMPLE_DATES, _EXAMPLE_DATES):
             delta = d1 - d2
@@ -614,6 +1214,784 @@ def test_day_of_week():
     assert Date(2021, 1, 8).day_of_week() is Weekday.FRIDAY
 
 
+@pytest.mark.parametrize(
+    "d, iso, sunday_based",
+    [
+        (Date(2021, 1, 2), 6, 6),
+        (Date(2021, 1, 3), 7, 0),
+        (Date(2021, 1, 4), 1, 1),
+        (Date(2021, 1, 8), 5, 5),
+        (Date.MIN, 1, 1),
+        (Date.MAX, 5, 5),
+    ],
+)
+def test_weekday_numbers(d, iso, sunday_based):
+    assert d.weekday_iso() == iso == d.day_of_week().value
+    assert d.weekday_sunday_based() == sunday_based
+    assert d.weekday_sunday_based() == int(d.py_date().strftime("%w"))
+
+
+@pytest.mark.parametrize(
+    "d, expected",
+    [
+        (Date(2021, 1, 2), 365),
+        (Date(2024, 12, 31), 366),
+        (Date(1900, 2, 28), 365),
+        (Date(2000, 2, 29), 366),
+        (Date.MIN, 365),
+        (Date.MAX, 365),
+    ],
+)
+def test_days_in_year(d, expected):
+    assert d.days_in_year() == expected
+
+
+class TestSeason:
+
+    @pytest.mark.parametrize(
+        "month, north, south",
+        [
+            (1, "winter", "summer"),
+            (2, "winter", "summer"),
+            (3, "spring", "autumn"),
+            (5, "spring", "autumn"),
+            (6, "summer", "winter"),
+            (8, "summer", "winter"),
+            (9, "autumn", "spring"),
+            (11, "autumn", "spring"),
+            (12, "winter", "summer"),
+        ],
+    )
+    def test_seasons(self, month, north, south):
+        d = Date(2024, month, 1)
+        assert d.season() == north
+        assert d.season(hemisphere="north") == north
+        assert d.season(hemisphere="south") == south
+
+    def test_invalid(self):
+        d = Date(2024, 1, 1)
+        with pytest.raises(ValueError, match="hemisphere"):
+            d.season(hemisphere="east")  # type: ignore[arg-type]
+        with pytest.raises((ValueError, TypeError), match="hemisphere"):
+            d.season(hemisphere=None)  # type: ignore[arg-type]
+        with pytest.raises(TypeError):
+            d.season("north")  # type: ignore[misc]
+        with pytest.raises(TypeError):
+            d.season(foo="north")  # type: ignore[call-arg]
+
+
+class TestWeeksInYear:
+
+    @pytest.mark.parametrize(
+        "year, expected",
+        [
+            (2015, 53),  # starts on a Thursday
+            (2016, 52),
+            (2020, 53),  # leap year starting on a Wednesday
+            (2024, 52),  # leap year starting on a Monday
+            (2026, 53),
+            (1, 52),
+            (9999, 52),
+        ],
+    )
+    def test_examples(self, year, expected):
+        assert Date.iso_weeks_in_year(year) == expected
+        assert Date(year, 1, 1).weeks_in_year() == expected
+        assert Date(year, 12, 31).weeks_in_year() == expected
+
+    @given(integers(1, 9999))
+    def test_matches_isocalendar(self, year):
+        # Dec 28th is always in the last ISO week of its year
+        expected = py_date(year, 12, 28).isocalendar()[1]
+        assert Date.iso_weeks_in_year(year) == expected
+
+    def test_invalid(self):
+        with pytest.raises(UnsupportedYear):
+            Date.iso_weeks_in_year(0)
+        with pytest.raises(ValueError, match="range"):
+            Date.iso_weeks_in_year(10_000)
+        with pytest.raises(TypeError, match="integer"):
+            Date.iso_weeks_in_year("2020")  # type: ignore[arg-type]
+
+
+@pytest.mark.parametrize(
+    "d, century, ordinal",
+    [
+        (Date(1, 1, 1), 1, 1),
+        (Date(100, 12, 31), 1, 36_524),
+        (Date(101, 1, 1), 2, 1),
+        (Date(1900, 12, 31), 19, 36_524),
+        (Date(1901, 1, 1), 20, 1),
+        # the year 2000 is the last year of the 20th century
+        (Date(2000, 1, 1), 20, 36_160),
+        (Date(2000, 12, 31), 20, 36_525),
+        (Date(2001, 1, 1), 21, 1),
+        (Date(2024, 3, 1), 21, 8_461),
+        (Date.MAX, 100, 36_159),
+    ],
+)
+def test_century(d, century, ordinal):
+    assert d.century == century
+    assert d.ordinal_in_century() == ordinal
+
+
+class TestOrdinal:
+
+    def test_int(self):
+        assert int(Date(1, 1, 1)) == 1
+        assert int(Date(2021, 7, 29)) == 738_000
+        assert int(Date.MAX) == py_date.max.toordinal()
+        assert int(Date(2024, 3, 1)) - int(Date(2024, 2, 28)) == 2
+        assert [0, 1, 2][Date.MIN] == 1
+        assert operator_index(Date(2021, 7, 29)) == 738_000
+
+    def test_from_ordinal(self):
+        assert Date.from_ordinal(1) == Date.MIN
+        assert Date.from_ordinal(738_000) == Date(2021, 7, 29)
+        assert Date.from_ordinal(py_date.max.toordinal()) == Date.MAX
+
+    @given(integers(min_value=1, max_value=py_date.max.toordinal()))
+    def test_roundtrip(self, n: int):
+        assert int(Date.from_ordinal(n)) == n
+
+    def test_from_ordinal_invalid(self):
+        with pytest.raises((ValueError, OverflowError)):
+            Date.from_ordinal(0)
+
+        with pytest.raises((ValueError, OverflowError)):
+            Date.from_ordinal(py_date.max.toordinal() + 1)
+
+        with pytest.raises((ValueError, OverflowError)):
+            Date.from_ordinal(1 << 64)
+
+        with pytest.raises(TypeError):
+            Date.from_ordinal(1.0)  # type: ignore[arg-type]
+
+
+class TestProlepticMonth:
+
+    def test_examples(self):
+        assert Date(2024, 3, 15).proleptic_month() == 24_290
+        assert Date.MIN.proleptic_month() == 12
+        assert Date.MAX.proleptic_month() == 9999 * 12 + 11
+        assert Date(2024, 12, 31).proleptic_month() + 1 == (
+            Date(2025, 1, 1).proleptic_month()
+        )
+
+    def test_from_proleptic_month(self):
+        assert Date.from_proleptic_month(24_290, 15) == Date(2024, 3, 15)
+        assert Date.from_proleptic_month(12, 1) == Date.MIN
+        assert Date.from_proleptic_month(9999 * 12 + 11, 31) == Date.MAX
+
+    @given(integers(1, 3_652_059))
+    def test_step_matches_add_months(self, n):
+        d = Date.from_ordinal(n)
+        for step in (1, -1, 13):
+            try:
+                expected = d.add(months=step)
+            except ValueError:
+                continue
+            n = d.proleptic_month() + step
+            # add() clamps the day to the end of the month
+            day = min(d.day, monthrange(n // 12, n % 12 + 1)[1])
+            assert expected == Date.from_proleptic_month(n, day)
+
+    def test_invalid(self):
+        with pytest.raises(ValueError, match="day"):
+            Date.from_proleptic_month(24_290 - 1, 30)  # February 2024
+        with pytest.raises(ValueError, match="day"):
+            Date.from_proleptic_month(24_290, 0)
+        with pytest.raises(ValueError, match="range"):
+            Date.from_proleptic_month(11, 1)
+        with pytest.raises(ValueError, match="range"):
+            Date.from_proleptic_month(10_000 * 12, 1)
+        with pytest.raises((ValueError, OverflowError)):
+            Date.from_proleptic_month(1 << 64, 1)
+        with pytest.raises(TypeError, match="integer"):
+            Date.from_proleptic_month(24_290.0, 1)  # type: ignore[arg-type]
+        with pytest.raises(TypeError, match="integer"):
+            Date.from_proleptic_month(24_290, "1")  # type: ignore[arg-type]
+        with pytest.raises(TypeError):
+            Date.from_proleptic_month(24_290)  # type: ignore[call-arg]
+
+
+class TestWeekdaysInMonth:
+
+    def test_examples(self):
+        d = Date(2024, 3, 15)
+        assert d.weekdays_in_month(Weekday.MONDAY) == [
+            Date(2024, 3, 4),
+            Date(2024, 3, 11),
+            Date(2024, 3, 18),
+            Date(2024, 3, 25),
+        ]
+        assert d.weekdays_in_month(Weekday.FRIDAY) == [
+            Date(2024, 3, 1),
+            Date(2024, 3, 8),
+            Date(2024, 3, 15),
+            Date(2024, 3, 22),
+            Date(2024, 3, 29),
+        ]
+        assert Date(2024, 2, 1).weekdays_in_month(Weekday.THURSDAY) == [
+            Date(2024, 2, 1),
+            Date(2024, 2, 8),
+            Date(2024, 2, 15),
+            Date(2024, 2, 22),
+            Date(2024, 2, 29),
+        ]
+
+    def test_all_weekdays(self):
+        d = Date(2021, 1, 31)
+        results = [d.weekdays_in_month(wd) for wd in Weekday]
+        assert sum(map(len, results)) == 31
+        for wd, dates in zip(Weekday, results):
+            assert all(x.day_of_week() is wd for x in dates)
+            assert all(x.year_month() == d.year_month() for x in dates)
+
+    def test_extremes(self):
+        assert Date.MIN.weekdays_in_month(Weekday.MONDAY)[0] == Date.MIN
+        assert Date.MAX.weekdays_in_month(Weekday.FRIDAY)[-1] == Date.MAX
+
+    def test_invalid(self):
+        with pytest.raises(TypeError, match="Weekday"):
+            Date(2021, 1, 1).weekdays_in_month(1)  # type: ignore[arg-type]
+
+
+class TestFirstAndLastWeekdayOfMonth:
+
+    def test_examples(self):
+        d = Date(2024, 3, 15)
+        assert d.first_weekday_of_month(Weekday.MONDAY) == Date(2024, 3, 4)
+        assert d.first_weekday_of_month(Weekday.FRIDAY) == Date(2024, 3, 1)
+        assert d.last_weekday_of_month(Weekday.FRIDAY) == Date(2024, 3, 29)
+        assert d.last_weekday_of_month(Weekday.SUNDAY) == Date(2024, 3, 31)
+
+    def test_month_ending_on_weekday(self):
+        # May 2024 ends on a Friday
+        d = Date(2024, 5, 2)
+        assert d.last_weekday_of_month(Weekday.FRIDAY) == Date(2024, 5, 31)
+        assert d.last_weekday_of_month(Weekday.THURSDAY) == Date(2024, 5, 30)
+        assert d.last_weekday_of_month(Weekday.SATURDAY) == Date(2024, 5, 25)
+
+    def test_leap_february(self):
+        d = Date(2024, 2, 10)
+        assert d.last_weekday_of_month(Weekday.THURSDAY) == Date(2024, 2, 29)
+        assert Date(2023, 2, 10).last_weekday_of_month(
+            Weekday.TUESDAY
+        ) == Date(2023, 2, 28)
+
+    @given(integers(1, 3_652_059))
+    def test_matches_weekdays_in_month(self, n):
+        d = Date.from_ordinal(n)
+        for wd in Weekday:
+            dates = d.weekdays_in_month(wd)
+            assert d.first_weekday_of_month(wd) == dates[0]
+            assert d.last_weekday_of_month(wd) == dates[-1]
+
+    def test_extremes(self):
+        assert Date.MIN.first_weekday_of_month(Weekday.MONDAY) == Date.MIN
+        assert Date.MAX.last_weekday_of_month(Weekday.FRIDAY) == Date.MAX
+
+    def test_invalid(self):
+        with pytest.raises(TypeError, match="Weekday"):
+            Date(2021, 1, 1).first_weekday_of_month(1)  # type: ignore[arg-type]
+        with pytest.raises(TypeError, match="Weekday"):
+            Date(2021, 1, 1).last_weekday_of_month(1)  # type: ignore[arg-type]
+
+
+class TestRange:
+
+    @pytest.mark.parametrize(
+        "start, end, kwargs, expected",
+        [
+            (
+                Date(2024, 2, 27),
+                Date(2024, 3, 1),
+                {},
+                [
+                    Date(2024, 2, 27),
+                    Date(2024, 2, 28),
+                    Date(2024, 2, 29),
+                    Date(2024, 3, 1),
+                ],
+            ),
+            (
+                Date(2024, 2, 27),
+                Date(2024, 3, 1),
+                {"inclusive": False},
+                [Date(2024, 2, 27), Date(2024, 2, 28), Date(2024, 2, 29)],
+            ),
+            # end not reached exactly
+            (
+                Date(2024, 1, 1),
+                Date(2024, 1, 8),
+                {"step_days": 3},
+                [Date(2024, 1, 1), Date(2024, 1, 4), Date(2024, 1, 7)],
+            ),
+            (
+                Date(2024, 1, 1),
+                Date(2024, 1, 7),
+                {"step_days": 3, "inclusive": False},
+                [Date(2024, 1, 1), Date(2024, 1, 4)],
+            ),
+            (
+                Date(2024, 1, 3),
+                Date(2024, 1, 1),
+                {"step_days": -1},
+                [Date(2024, 1, 3), Date(2024, 1, 2), Date(2024, 1, 1)],
+            ),
+            (
+                Date(2024, 1, 3),
+                Date(2024, 1, 1),
+                {"step_days": -1, "inclusive": False},
+                [Date(2024, 1, 3), Date(2024, 1, 2)],
+            ),
+            # empty and single-element ranges
+            (Date(2024, 1, 1), Date(2024, 1, 1), {}, [Date(2024, 1, 1)]),
+            (
+                Date(2024, 1, 1),
+                Date(2024, 1, 1),
+                {"step_days": -5},
+                [Date(2024, 1, 1)],
+            ),
+            (Date(2024, 1, 1), Date(2024, 1, 1), {"inclusive": False}, []),
+            (
+                Date(2024, 1, 1),
+                Date(2024, 1, 2),
+                {"step_days": MAX_I64 - 1},
+                [Date(2024, 1, 1)],
+            ),
+            # steps beyond the 64-bit range
+            (
+                Date(2024, 3, 15),
+                Date(2024, 3, 20),
+                {"step_days": 1 << 70},
+                [Date(2024, 3, 15)],
+            ),
+            (
+                Date(2024, 3, 20),
+                Date(2024, 3, 15),
+                {"step_days": -(1 << 70)},
+                [Date(2024, 3, 20)],
+            ),
+            # extremes
+            (
+                Date(9999, 12, 30),
+                Date.MAX,
+                {},
+                [Date(9999, 12, 30), Date.MAX],
+            ),
+            (
+                Date(1, 1, 2),
+                Date.MIN,
+                {"step_days": -1},
+                [Date(1, 1, 2), Date.MIN],
+            ),
+        ],
+    )
+    def test_examples(self, start, end, kwargs, expected):
+        result = start.range(end, **kwargs)
+        assert type(result) is list
+        assert result == expected
+
+    def test_full_range(self):
+        dates = Date.MIN.range(Date.MAX, step_days=1000)
+        assert len(dates) == Date.MIN.days_until(Date.MAX) // 1000 + 1
+        assert dates[0] == Date.MIN
+        assert dates[-1].days_until(Date.MAX) < 1000
+
+    def test_invalid_step(self):
+        d = Date(2024, 1, 1)
+        with pytest.raises(ValueError, match="zero"):
+            d.range(Date(2024, 1, 2), step_days=0)
+
+        with pytest.raises(ValueError, match="positive"):
+            d.range(Date(2024, 1, 2), step_days=-1)
+
+        with pytest.raises(ValueError, match="negative"):
+            d.range(Date(2023, 1, 2))
+
+        with pytest.raises(TypeError, match="step_days"):
+            d.range(Date(2024, 1, 2), step_days=1.5)  # type: ignore[arg-type]
+
+    def test_invalid_args(self):
+        d = Date(2024, 1, 1)
+        with pytest.raises(TypeError, match="end"):
+            d.range(LocalDateTime(2024, 1, 2))  # type: ignore[arg-type]
+
+        with pytest.raises(TypeError):
+            d.range()  # type: ignore[call-arg]
+
+        with pytest.raises(TypeError, match="foo"):
+            d.range(Date(2024, 1, 2), foo=3)  # type: ignore[call-arg]
+
+
+class TestRangeMonths:
+
+    @pytest.mark.parametrize(
+        "start, end, kwargs, expected",
+        [
+            (
+                Date(2024, 1, 1),
+                Date(2024, 3, 1),
+                {},
+                [Date(2024, 1, 1), Date(2024, 2, 1), Date(2024, 3, 1)],
+            ),
+            (
+                Date(2024, 1, 1),
+                Date(2024, 3, 1),
+                {"inclusive": False},
+                [Date(2024, 1, 1), Date(2024, 2, 1)],
+            ),
+            # day clamping doesn't carry over to later months
+            (
+                Date(2024, 1, 31),
+                Date(2024, 4, 30),
+                {},
+                [
+                    Date(2024, 1, 31),
+                    Date(2024, 2, 29),
+                    Date(2024, 3, 31),
+                    Date(2024, 4, 30),
+                ],
+            ),
+            # end not reached exactly
+            (
+                Date(2024, 1, 31),
+                Date(2024, 3, 30),
+                {},
+                [Date(2024, 1, 31), Date(2024, 2, 29)],
+            ),
+            (
+                Date(2024, 1, 15),
+                Date(2025, 1, 15),
+                {"step_months": 5},
+                [Date(2024, 1, 15), Date(2024, 6, 15), Date(2024, 11, 15)],
+            ),
+            (
+                Date(2024, 3, 31),
+                Date(2023, 12, 1),
+                {"step_months": -1},
+                [
+                    Date(2024, 3, 31),
+                    Date(2024, 2, 29),
+                    Date(2024, 1, 31),
+                    Date(2023, 12, 31),
+                ],
+            ),
+            (
+                Date(2024, 3, 31),
+                Date(2024, 1, 31),
+                {"step_months": -1, "inclusive": False},
+                [Date(2024, 3, 31), Date(2024, 2, 29)],
+            ),
+            # empty and single-element ranges
+            (Date(2024, 1, 1), Date(2024, 1, 1), {}, [Date(2024, 1, 1)]),
+            (Date(2024, 1, 1), Date(2024, 1, 1), {"inclusive": False}, []),
+            (
+                Date(2024, 1, 1),
+                Date(2024, 1, 31),
+                {},
+                [Date(2024, 1, 1)],
+            ),
+            (
+                Date(2024, 1, 1),
+                Date(2024, 3, 1),
+                {"step_months": MAX_I64 - 1},
+                [Date(2024, 1, 1)],
+            ),
+            # steps beyond the 64-bit range
+            (
+                Date(2024, 1, 1),
+                Date(2024, 3, 1),
+                {"step_months": 1 << 100},
+                [Date(2024, 1, 1)],
+            ),
+            (
+                Date(2024, 3, 1),
+                Date(2024, 1, 1),
+                {"step_months": -(1 << 100)},
+                [Date(2024, 3, 1)],
+            ),
+            # extremes
+            (
+                Date(9999, 11, 30),
+                Date.MAX,
+                {},
+                [Date(9999, 11, 30), Date(9999, 12, 30)],
+            ),
+            (
+                Date(1, 2, 1),
+                Date.MIN,
+                {"step_months": -1},
+                [Date(1, 2, 1), Date.MIN],
+            ),
+            (
+                Date(1, 2, 1),
+                Date.MIN,
+                {"step_months": -MAX_I64},
+                [Date(1, 2, 1)],
+            ),
+        ],
+    )
+    def test_examples(self, start, end, kwargs, expected):
+        assert list(start.range_months(end, **kwargs)) == expected
+
+    def test_is_iterator(self):
+        it = Date(2024, 1, 31).range_months(Date(2024, 3, 31))
+        assert iter(it) is it
+        assert next(it) == Date(2024, 1, 31)
+        assert list(it) == [Date(2024, 2, 29), Date(2024, 3, 31)]
+        with pytest.raises(StopIteration):
+            next(it)
+
+    @given(integers(1, 3_652_059), integers(1, 30))
+    def test_matches_add_months(self, n, step):
+        d = Date.from_ordinal(n)
+        for date_ in d.range_months(Date.MAX, step_months=step):
+            assert date_ == d.add(
+                months=(date_.proleptic_month() - d.proleptic_month())
+            )
+
+    def test_invalid_step(self):
+        d = Date(2024, 1, 1)
+        with pytest.raises(ValueError, match="zero"):
+            d.range_months(Date(2024, 2, 1), step_months=0)
+
+        with pytest.raises(ValueError, match="positive"):
+            d.range_months(Date(2024, 2, 1), step_months=-1)
+
+        with pytest.raises(ValueError, match="negative"):
+            d.range_months(Date(2023, 1, 1))
+
+        with pytest.raises(TypeError, match="step_months"):
+            d.range_months(
+                Date(2024, 2, 1), step_months=1.5  # type: ignore[arg-type]
+            )
+
+    def test_invalid_args(self):
+        d = Date(2024, 1, 1)
+        with pytest.raises(TypeError, match="end"):
+            d.range_months(LocalDateTime(2024, 2, 1))  # type: ignore[arg-type]
+
+        with pytest.raises(TypeError):
+            d.range_months()  # type: ignore[call-arg]
+
+        with pytest.raises(TypeError, match="step_days"):
+            d.range_months(
+                Date(2024, 2, 1), step_days=3  # type: ignore[call-arg]
+            )
+
+
+class TestIterWeekdays:
+
+    def test_examples(self):
+        d = Date(2024, 3, 15)  # a Friday
+        assert list(d.iter_weekdays(Weekday.MONDAY, 3)) == [
+            Date(2024, 3, 18),
+            Date(2024, 3, 25),
+            Date(2024, 4, 1),
+        ]
+        assert list(d.iter_weekdays(Weekday.FRIDAY, 2)) == [
+            Date(2024, 3, 15),
+            Date(2024, 3, 22),
+        ]
+        assert list(d.iter_weekdays(Weekday.FRIDAY, 2, inclusive=False)) == [
+            Date(2024, 3, 22),
+            Date(2024, 3, 29),
+        ]
+        # inclusive only matters if the date itself is on the weekday
+        assert list(
+            d.iter_weekdays(Weekday.SUNDAY, 1, inclusive=False)
+        ) == [Date(2024, 3, 17)]
+        assert list(d.iter_weekdays(Weekday.FRIDAY, 0)) == []
+
+    def test_is_iterator(self):
+        it = Date(2024, 3, 15).iter_weekdays(Weekday.FRIDAY, 2)
+        assert iter(it) is it
+        assert next(it) == Date(2024, 3, 15)
+        assert next(it) == Date(2024, 3, 22)
+        with pytest.raises(StopIteration):
+            next(it)
+        # stays exhausted
+        with pytest.raises(StopIteration):
+            next(it)
+
+    def test_partial_consumption(self):
+        it = Date(2024, 3, 15).iter_weekdays(Weekday.FRIDAY, 400_000)
+        assert next(it) == Date(2024, 3, 15)
+        assert list(islice(it, 2)) == [Date(2024, 3, 22), Date(2024, 3, 29)]
+        assert next(it) == Date(2024, 4, 5)
+
+    def test_cannot_instantiate_iterator(self):
+        it = Date(2024, 3, 15).iter_weekdays(Weekday.FRIDAY, 2)
+        with pytest.raises(TypeError):
+            type(it)()
+
+    def test_out_of_range(self):
+        d = Date(9999, 12, 17)  # a Friday
+        assert list(d.iter_weekdays(Weekday.FRIDAY, 3))[-1] == Date.MAX
+        with pytest.raises(ValueError, match="range"):
+            d.iter_weekdays(Weekday.FRIDAY, 4)
+
+        with pytest.raises(ValueError, match="range"):
+            d.iter_weekdays(Weekday.FRIDAY, 1 << 62)
+
+        with pytest.raises(ValueError, match="range"):
+            d.iter_weekdays(Weekday.FRIDAY, 1 << 70)
+
+    def test_invalid(self):
+        d = Date(2024, 3, 15)
+        with pytest.raises(TypeError, match="Weekday"):
+            d.iter_weekdays(5, 2)  # type: ignore[arg-type]
+
+        with pytest.raises(TypeError, match="count"):
+            d.iter_weekdays(Weekday.FRIDAY, 2.0)  # type: ignore[arg-type]
+
+        with pytest.raises(ValueError, match="count"):
+            d.iter_weekdays(Weekday.FRIDAY, -1)
+
+        with pytest.raises(ValueError, match="count"):
+            d.iter_weekdays(Weekday.FRIDAY, -(1 << 70))
+
+        with pytest.raises(TypeError):
+            d.iter_weekdays(Weekday.FRIDAY)  # type: ignore[call-arg]
+
+        with pytest.raises(TypeError):
+            d.iter_weekdays(Weekday.FRIDAY, 2, foo=1)  # type: ignore[call-arg]
+
+
+class TestStartOfWeek:
+
+    def test_default_monday(self):
+        assert Date(2024, 3, 1).start_of_week() == Date(2024, 2, 26)
+        assert Date(2024, 2, 26).start_of_week() == Date(2024, 2, 26)
+        assert Date(2024, 3, 3).start_of_week() == Date(2024, 2, 26)
+        # across a year boundary
+        assert Date(2021, 1, 2).start_of_week() == Date(2020, 12, 28)
+
+    def test_other_start(self):
+        d = Date(2024, 3, 1)  # a Friday
+        assert d.start_of_week(Weekday.SUNDAY) == Date(2024, 2, 25)
+        assert d.start_of_week(Weekday.SATURDAY) == Date(2024, 2, 24)
+        assert d.start_of_week(Weekday.FRIDAY) == d
+
+    def test_all_weekdays(self):
+        for d in [Date(2023, 12, 28) + i for i in range(7)]:
+            for start in Weekday:
+                result = d.start_of_week(start)
+                assert result.day_of_week() is start
+                assert 0 <= d.days_since(result) < 7
+
+    def test_extremes(self):
+        assert Date.MIN.start_of_week() == Date.MIN
+        assert Date.MAX.start_of_week().day_of_week() is Weekday.MONDAY
+        with pytest.raises(ValueError, match="range"):
+            Date.MIN.start_of_week(Weekday.SUNDAY)
+
+    def test_invalid(self):
+        with pytest.raises(TypeError, match="Weekday"):
+            Date(2021, 1, 1).start_of_week(1)  # type: ignore[arg-type]
+
+        with pytest.raises(TypeError):
+            Date(2021, 1, 1).start_of_week(  # type: ignore[call-arg]
+                Weekday.MONDAY, Weekday.SUNDAY
+            )
+
+        with pytest.raises(TypeError):
+            Date(2021, 1, 1).start_of_week(  # type: ignore[call-arg]
+                start=Weekday.SUNDAY
+            )
+
+
+class TestReplaceOrdinalDay:
+
+    @pytest.mark.parametrize(
+        "d, n, expected",
+        [
+            (Date(2024, 6, 1), 1, Date(2024, 1, 1)),
+            (Date(2024, 6, 1), 60, Date(2024, 2, 29)),
+            (Date(2023, 6, 1), 60, Date(2023, 3, 1)),
+            (Date(2024, 6, 1), 366, Date(2024, 12, 31)),
+            (Date(2023, 6, 1), 365, Date(2023, 12, 31)),
+            (Date(2000, 1, 1), 366, Date(2000, 12, 31)),
+            (Date.MIN, 1, Date.MIN),
+            (Date.MAX, 365, Date.MAX),
+        ],
+    )
+    def test_valid(self, d, n, expected):
+        assert d.replace_ordinal_day(n) == expected
+
+    @pytest.mark.parametrize(
+        "d, n",
+        [
+            (Date(2024, 6, 1), 0),
+            (Date(2024, 6, 1), -1),
+            (Date(2024, 6, 1), 367),
+            (Date(2023, 6, 1), 366),
+            (Date(1900, 6, 1), 366),
+            (Date(2024, 6, 1), MAX_I64 - 1),
+            (Date(2024, 6, 1), 1 << 70),
+            (Date(2024, 6, 1), -(1 << 70)),
+        ],
+    )
+    def test_out_of_range(self, d, n):
+        with pytest.raises(ValueError, match=r"day of year must be in 1\.\."):
+            d.replace_ordinal_day(n)
+
+    def test_invalid_type(self):
+        with pytest.raises(TypeError):
+            Date(2024, 6, 1).replace_ordinal_day(1.0)  # type: ignore[arg-type]
+
+
+class TestOnOrAfterBeforeWeekday:
+
+    @pytest.mark.parametrize(
+        "weekday, after, before",
+        [
+            # the date itself already matches
+            (Weekday.FRIDAY, Date(2024, 3, 15), Date(2024, 3, 15)),
+            (Weekday.SATURDAY, Date(2024, 3, 16), Date(2024, 3, 9)),
+            (Weekday.MONDAY, Date(2024, 3, 18), Date(2024, 3, 11)),
+            (Weekday.THURSDAY, Date(2024, 3, 21), Date(2024, 3, 14)),
+        ],
+    )
+    def test_examples(self, weekday, after, before):
+        d = Date(2024, 3, 15)  # a Friday
+        assert d.on_or_after_weekday(weekday) == after
+        assert d.on_or_before_weekday(weekday) == before
+
+    def test_all_weekdays(self):
+        for d in [Date(2023, 12, 28) + i for i in range(7)]:
+            for weekday in Weekday:
+                after = d.on_or_after_weekday(weekday)
+                before = d.on_or_before_weekday(weekday)
+                assert after.day_of_week() is weekday
+                assert before.day_of_week() is weekday
+                assert 0 <= d.days_until(after) < 7
+                assert 0 <= d.days_since(before) < 7
+                assert before == d.start_of_week(weekday)
+
+    def test_extremes(self):
+        assert Date.MAX.on_or_after_weekday(Date.MAX.day_of_week()) == Date.MAX
+        assert Date.MIN.on_or_before_weekday(Weekday.MONDAY) == Date.MIN
+        with pytest.raises(ValueError, match="range"):
+            Date.MAX.on_or_after_weekday(Weekday.SATURDAY)
+        with pytest.raises(ValueError, match="range"):
+            Date.MIN.on_or_before_weekday(Weekday.SUNDAY)
+
+    def test_invalid(self):
+        d = Date(2021, 1, 1)
+        with pytest.raises(TypeError, match="Weekday"):
+            d.on_or_after_weekday(1)  # type: ignore[arg-type]
+
+        with pytest.raises(TypeError, match="Weekday"):
+            d.on_or_before_weekday("MONDAY")  # type: ignore[arg-type]
+
+        with pytest.raises(TypeError):
+            d.on_or_after_weekday()  # type: ignore[call-arg]
+
+
 def test_pickling():
     d = Date(2021, 1, 2)
     dumped = pickle.dumps(d)
@@ -629,6 +2007,14 @@ def test_unpickle_compatibility():
     assert pickle.loads(dumped) == Date(2021, 1, 2)
 
 
+def test_unpickle_unknown_version():
+    dumped = pickle.dumps(Date(2021, 1, 2))
+    payload = b"\x01\xe5\x07\x01\x02"
+    assert payload in dumped
+    with pytest.raises(ValueError, match="pickle version"):
+        pickle.loads(dumped.replace(payload, b"\x09\xe5\x07\x01\x02"))
+
+
 def test_copy():
     d = Date(2021, 1, 2)
     assert copy(d) is d
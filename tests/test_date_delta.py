@@ -16,7 +16,7 @@ from whenever import (
     years,
 )
 
-from .common import AlwaysEqual, NeverEqual
+from .common import AlwaysEqual, AlwaysLarger, AlwaysSmaller, NeverEqual
 
 MAX_I64 = 1 << 64
 
@@ -140,13 +140,60 @@ def test_equality():
     assert hash(p) != hash(different)
 
 
+class TestComparison:
+
+    def test_days_only(self):
+        a = DateDelta(weeks=1)
+        b = DateDelta(days=8)
+        assert a < b
+        assert a <= b
+        assert not a > b
+        assert not a >= b
+        assert a <= DateDelta(days=7)
+        assert a >= DateDelta(days=7)
+        assert -b < -a < DateDelta.ZERO
+        assert sorted([b, DateDelta.ZERO, a]) == [DateDelta.ZERO, a, b]
+
+    def test_same_months(self):
+        assert DateDelta(months=2, days=1) > DateDelta(months=2)
+        assert DateDelta(years=1) <= DateDelta(months=12)
+
+    def test_different_months(self):
+        a = DateDelta(months=1)
+        b = DateDelta(days=31)
+        for op in [
+            lambda: a < b,
+            lambda: a <= b,
+            lambda: a > b,
+            lambda: a >= b,
+            lambda: DateDelta(months=2) > a,
+        ]:
+            with pytest.raises(TypeError, match="months"):
+                op()
+        # equality still works
+        assert a != b
+
+    def test_other_types(self):
+        d = DateDelta(days=1)
+        assert d < AlwaysLarger()
+        assert d > AlwaysSmaller()
+        with pytest.raises(TypeError):
+            d < 1  # type: ignore[operator]
+        with pytest.raises(TypeError):
+            d < TimeDelta(hours=1)  # type: ignore[operator]
+
+
 def test_zero():
     assert DateDelta.ZERO == DateDelta()
 
 
 def test_bool():
     assert not DateDelta()
+    assert not DateDelta(years=1, months=-12)
     assert DateDelta(days=1)
+    assert DateDelta(months=1)
+    assert DateDelta(days=-1)
+    assert not DateDelta.ZERO
 
 
 @pytest.mark.parametrize(
@@ -242,6 +289,19 @@ class TestParseCommonIso:
         ):
             DateDelta.parse_common_iso(s)
 
+    @pytest.mark.parametrize(
+        "d",
+        [
+            DateDelta(),
+            DateDelta(years=1, months=2, weeks=3, days=4),
+            DateDelta(years=-9998, days=-3),
+            DateDelta(months=11, weeks=52),
+            DateDelta(years=9999),
+        ],
+    )
+    def test_roundtrip(self, d):
+        assert DateDelta.parse_common_iso(d.format_common_iso()) == d
+
     def test_invalid_type(self):
         with pytest.raises(TypeError):
             DateDelta.parse_common_iso(1)  # type: ignore[arg-type]
@@ -258,6 +318,20 @@ def test_negate():
     assert -DateDelta() == DateDelta()
 
 
+@pytest.mark.parametrize(
+    "p",
+    [
+        DateDelta(years=1, months=-2, weeks=3, days=-4),
+        DateDelta(),
+        DateDelta(years=9999, days=9999 * 366),
+        DateDelta(years=-9999, days=-9999 * 366),
+    ],
+)
+def test_double_negate(p):
+    assert -(-p) == p
+    assert -p == p * -1
+
+
 @pytest.mark.parametrize(
     "p",
     [
@@ -378,6 +452,17 @@ class TestAdd:
         )
         assert p + d == d + p
 
+    def test_out_of_range(self):
+        with pytest.raises(ValueError, match="range|bounds"):
+            DateDelta(years=9999) + DateDelta(months=1)
+
+        with pytest.raises(ValueError, match="range|bounds"):
+            DateDelta(days=9999 * 366) + DateDelta(days=1)
+
+    def test_mixed_sign(self):
+        with pytest.raises(ValueError, match="(?i)mixed sign"):
+            DateDelta(months=1) + DateDelta(days=-1)
+
     def test_unsupported(self):
         p = DateDelta(years=1, months=2, weeks=3, days=4)
         with pytest.raises(TypeError, match="unsupported operand"):
@@ -398,6 +483,10 @@ class TestSubtract:
         assert p - q == DateDelta(years=2, months=-1, weeks=4, days=4)
         assert q - p == DateDelta(years=-2, months=1, weeks=-4, days=-4)
 
+    def test_out_of_range(self):
+        with pytest.raises(ValueError, match="range|bounds"):
+            DateDelta(years=-9999) - DateDelta(days=1, months=1)
+
     def test_time_delta(self):
         p = DateDelta(years=-1, months=-2, weeks=-3, days=4)
         d = TimeDelta(hours=1, minutes=2, seconds=3, microseconds=400_004)
@@ -441,6 +530,14 @@ def test_in_years_months_days():
     assert DateDelta.ZERO.in_years_months_days() == (0, 0, 0)
     assert DateDelta(months=-30).in_years_months_days() == (-2, -6, 0)
     assert DateDelta(months=4).in_years_months_days() == (0, 4, 0)
+    # days aren't carried over into months
+    assert DateDelta(months=14, days=40).in_years_months_days() == (1, 2, 40)
+    assert DateDelta(months=-14, days=-40).in_years_months_days() == (
+        -1,
+        -2,
+        -40,
+    )
+    assert DateDelta(months=-12).in_years_months_days() == (-1, 0, 0)
 
 
 def test_abs():
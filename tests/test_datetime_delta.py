@@ -122,6 +122,9 @@ def test_bool():
     assert not DateTimeDelta()
     assert DateTimeDelta(days=1)
     assert DateTimeDelta(nanoseconds=1)
+    assert DateTimeDelta(months=-1)
+    assert not DateTimeDelta(hours=1, minutes=-60)
+    assert not DateTimeDelta.ZERO
 
 
 @pytest.mark.parametrize(
@@ -398,6 +401,22 @@ class TestAdd:
         with pytest.raises(TypeError, match="unsupported operand"):
             32 + p  # type: ignore[operator]
 
+    def test_mixed_sign(self):
+        p = DateTimeDelta(hours=1)
+        with pytest.raises(ValueError, match="Mixed sign"):
+            p + DateTimeDelta(days=-1)
+        with pytest.raises(ValueError, match="Mixed sign"):
+            p + DateDelta(days=-1)
+        with pytest.raises(ValueError, match="Mixed sign"):
+            DateDelta(days=-1) + p
+
+    def test_out_of_range(self):
+        p = DateTimeDelta(years=9_999)
+        with pytest.raises(ValueError, match="range|bounds"):
+            p + p
+        with pytest.raises(ValueError, match="range|bounds"):
+            p + DateDelta(years=1)
+
 
 class TestSubtract:
 
@@ -548,6 +567,20 @@ class TestSubtract:
         with pytest.raises(TypeError, match="unsupported operand"):
             32 - p  # type: ignore[operator]
 
+    def test_mixed_sign(self):
+        p = DateTimeDelta(hours=1)
+        with pytest.raises(ValueError, match="Mixed sign"):
+            p - DateTimeDelta(days=1)
+        with pytest.raises(ValueError, match="Mixed sign"):
+            p - DateDelta(days=1)
+        with pytest.raises(ValueError, match="Mixed sign"):
+            DateDelta(days=3) - DateTimeDelta(hours=2)
+
+    def test_out_of_range(self):
+        p = DateTimeDelta(years=-9_999)
+        with pytest.raises(ValueError, match="range|bounds"):
+            p - DateTimeDelta(years=9_999)
+
 
 def test_multiplication():
     p = DateTimeDelta(
@@ -596,6 +629,20 @@ def test_negate():
     )
 
 
+@pytest.mark.parametrize(
+    "d",
+    [
+        DateTimeDelta(years=1, months=-2, hours=3, nanoseconds=-4),
+        DateTimeDelta.ZERO,
+        DateTimeDelta(years=9999, seconds=TimeDelta.MAX.in_seconds() // 2),
+        DateTimeDelta(years=-9999, seconds=TimeDelta.MIN.in_seconds() // 2),
+    ],
+)
+def test_double_negate(d):
+    assert -(-d) == d
+    assert -d == d * -1
+
+
 @pytest.mark.parametrize(
     "d",
     [DateTimeDelta.ZERO, DateTimeDelta(years=1, seconds=7)],
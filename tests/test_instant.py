@@ -2,6 +2,7 @@ import pickle
 import re
 from copy import copy, deepcopy
 from datetime import datetime as py_datetime, timedelta, timezone, tzinfo
+from time import time_ns
 from zoneinfo import ZoneInfo
 
 import pytest
@@ -14,6 +15,7 @@ from whenever import (
     OffsetDateTime,
     SystemDateTime,
     ZonedDateTime,
+    TimeDelta,
     hours,
     milliseconds,
     minutes,
@@ -342,6 +344,9 @@ def test_repr():
         repr(Instant.from_utc(2020, 8, 15, 23, 12))
         == "Instant(2020-08-15 23:12:00Z)"
     )
+    assert repr(Instant.MIN) == "Instant(0001-01-01 00:00:00Z)"
+    assert repr(Instant.MAX) == "Instant(9999-12-31 23:59:59.999999999Z)"
+    assert str(Instant.MAX) == "9999-12-31T23:59:59.999999999Z"
 
 
 class TestComparison:
@@ -569,6 +574,17 @@ class TestFromPyDatetime:
         with pytest.raises(ValueError, match="naive"):
             Instant.from_py_datetime(py_datetime(2020, 8, 15, 12))
 
+    @pytest.mark.parametrize(
+        "arg", [py_datetime(2020, 8, 15).date(), "2020-08-15T12:00Z", 3]
+    )
+    def test_not_a_datetime(self, arg):
+        with pytest.raises(TypeError, match="datetime"):
+            Instant.from_py_datetime(arg)  # type: ignore[arg-type]
+
+    def test_roundtrip(self):
+        d = py_datetime(2020, 8, 15, 23, 12, 9, 987_654, tzinfo=timezone.utc)
+        assert Instant.from_py_datetime(d).py_datetime() == d
+
     def test_utcoffset_none(self):
 
         class MyTz(tzinfo):
@@ -585,6 +601,15 @@ def test_now():
     assert py_now - now.py_datetime() < timedelta(seconds=1)
 
 
+def test_now_nanosecond_precision():
+    # Not truncated to microseconds: it must fall between two readings
+    # of the nanosecond clock.
+    before = time_ns()
+    now = Instant.now()
+    after = time_ns()
+    assert before <= now.timestamp_nanos() <= after
+
+
 def test_min_max():
     assert Instant.MIN == Instant.from_utc(1, 1, 1)
     assert Instant.MAX == Instant.from_utc(
@@ -592,6 +617,24 @@ def test_min_max():
     )
 
 
+def test_min_max_are_bounds():
+    d = Instant.from_utc(2020, 8, 15)
+    assert Instant.MIN <= d <= Instant.MAX
+    assert Instant.MIN is Instant.MIN
+
+    # useful as a starting point for reductions
+    earliest = Instant.MAX
+    for i in [d + seconds(1), d, d + seconds(2)]:
+        earliest = min(earliest, i)
+    assert earliest == d
+
+    with pytest.raises((ValueError, OverflowError), match="range"):
+        Instant.MAX + nanoseconds(1)
+
+    with pytest.raises((ValueError, OverflowError), match="range"):
+        Instant.MIN - nanoseconds(1)
+
+
 class TestAddMethod:
 
     def test_valid(self):
@@ -759,6 +802,181 @@ class TestDifference:
         with pytest.raises(TypeError, match="unsupported operand type"):
             None - d  # type: ignore[operator]
 
+        with pytest.raises(TypeError, match="difference"):
+            d.difference(hours(1))  # type: ignore[arg-type]
+
+        with pytest.raises(TypeError):
+            d.difference()  # type: ignore[call-arg]
+
+        with pytest.raises(TypeError):
+            d.difference(d, "hour")  # type: ignore[misc]
+
+        with pytest.raises(TypeError, match="foo"):
+            d.difference(d, foo="hour")  # type: ignore[call-arg]
+
+    @pytest.mark.parametrize(
+        "kwargs, expected",
+        [
+            (dict(), hours(26) + minutes(21) + seconds(30)),
+            (dict(unit="nanosecond"), hours(26) + minutes(21) + seconds(30)),
+            (dict(unit="hour"), hours(26)),
+            (dict(unit="hour", mode="ceil"), hours(27)),
+            (dict(unit="minute", mode="half_even"), hours(26) + minutes(22)),
+            (dict(unit="minute", mode="half_floor"), hours(26) + minutes(21)),
+            (dict(unit="minute", increment=15), hours(26) + minutes(15)),
+            (dict(unit="hour", increment=5, mode="floor"), hours(25)),
+            (dict(mode="floor"), hours(26) + minutes(21) + seconds(30)),
+        ],
+    )
+    def test_rounding(self, kwargs, expected):
+        a = Instant.from_utc(2020, 8, 15, 23, 12)
+        b = Instant.from_utc(2020, 8, 14, 20, 50, 30)
+        assert a.difference(b, **kwargs) == expected
+
+    def test_rounding_negative(self):
+        a = Instant.from_utc(2020, 8, 15, 23, 12)
+        b = Instant.from_utc(2020, 8, 14, 20, 50, 30)
+        assert b.difference(a, unit="hour", mode="floor") == hours(-27)
+        assert b.difference(a, unit="hour", mode="ceil") == hours(-26)
+        assert b.difference(a, unit="minute") == -a.difference(
+            b, unit="minute"
+        )
+
+    def test_rounding_to_24_hours(self):
+        a = Instant.from_utc(2020, 8, 15, 23, 12)
+        b = Instant.from_utc(2020, 8, 12, 20, 50, 30)
+        assert a.difference(b, unit="hour", increment=24) == hours(72)
+        assert a.difference(
+            b, unit="hour", increment=24, mode="ceil"
+        ) == hours(96)
+
+    def test_rounding_other_types(self):
+        d = Instant.from_utc(2020, 8, 15, 23, 12)
+        other = OffsetDateTime(2020, 8, 15, 20, 40, 1, offset=2)
+        assert d.difference(other, unit="minute", mode="floor") == hours(
+            4
+        ) + minutes(31)
+
+    def test_rounding_invalid(self):
+        d = Instant.from_utc(2020, 8, 15, 23, 12)
+        with pytest.raises(
+            ValueError, match=r"hours\. If you wish to round to exactly 24"
+        ):
+            d.difference(d, unit="day")  # type: ignore[arg-type]
+
+        with pytest.raises(ValueError):
+            d.difference(d, unit="foo")  # type: ignore[arg-type]
+
+        with pytest.raises(ValueError):
+            d.difference(d, mode="foo")  # type: ignore[arg-type]
+
+        with pytest.raises(ValueError):
+            d.difference(d, unit="minute", increment=7)
+
+
+class TestElapsedSince:
+
+    def test_later_instant(self):
+        start = Instant.from_utc(2020, 8, 14, 23, 12, 4, nanosecond=321)
+        d = Instant.from_utc(2020, 8, 15, 23, 12, 9, nanosecond=20)
+        assert d.elapsed_since(start) == d - start
+        assert d.elapsed_since(d) == TimeDelta.ZERO
+
+    def test_other_types(self):
+        d = Instant.from_utc(2020, 8, 15, 23)
+        assert d.elapsed_since(
+            OffsetDateTime(2020, 8, 15, 20, offset=2)
+        ) == hours(5)
+        assert d.elapsed_since(
+            ZonedDateTime(2020, 8, 15, 20, tz="Europe/Paris")
+        ) == hours(5)
+
+    def test_earlier_raises(self):
+        d = Instant.from_utc(2020, 8, 15, 23)
+        with pytest.raises(ValueError, match="later"):
+            d.elapsed_since(d + nanoseconds(1))
+        with pytest.raises(ValueError, match="later"):
+            d.elapsed_since(OffsetDateTime(2020, 8, 15, 23, 1, offset=0))
+
+    def test_invalid(self):
+        d = Instant.from_utc(2020, 8, 15, 23)
+        with pytest.raises(
+            TypeError,
+            match=r"^elapsed_since\(\) argument must be an OffsetDateTime, "
+            "Instant, ZonedDateTime, or SystemDateTime$",
+        ):
+            d.elapsed_since(hours(1))  # type: ignore[arg-type]
+        with pytest.raises(TypeError):
+            d.elapsed_since()  # type: ignore[call-arg]
+
+
+class TestIsBetween:
+
+    START = Instant.from_utc(2020, 8, 15, 12)
+    END = Instant.from_utc(2020, 8, 15, 14)
+
+    @pytest.mark.parametrize(
+        "inclusive, at_start, at_end",
+        [
+            ("[)", True, False),
+            ("[]", True, True),
+            ("(]", False, True),
+            ("()", False, False),
+        ],
+    )
+    def test_endpoints(self, inclusive, at_start, at_end):
+        start, end = self.START, self.END
+        assert (
+            start.is_between(start, end, inclusive=inclusive) is at_start
+        )
+        assert end.is_between(start, end, inclusive=inclusive) is at_end
+        assert (start + hours(1)).is_between(start, end, inclusive=inclusive)
+        assert not (start - nanoseconds(1)).is_between(
+            start, end, inclusive=inclusive
+        )
+        assert not (end + nanoseconds(1)).is_between(
+            start, end, inclusive=inclusive
+        )
+
+    def test_default_is_half_open(self):
+        start, end = self.START, self.END
+        assert start.is_between(start, end)
+        assert not end.is_between(start, end)
+        assert (end - nanoseconds(1)).is_between(start, end)
+
+    def test_empty_range(self):
+        start, end = self.START, self.END
+        assert not start.is_between(start, start)
+        assert start.is_between(start, start, inclusive="[]")
+        # start after end
+        assert not (start + hours(1)).is_between(end, start, inclusive="[]")
+
+    def test_other_types(self):
+        d = Instant.from_utc(2020, 8, 15, 13)
+        assert d.is_between(
+            OffsetDateTime(2020, 8, 15, 14, offset=2),
+            ZonedDateTime(2020, 8, 15, 16, tz="Europe/Paris"),
+        )
+        assert not d.is_between(
+            OffsetDateTime(2020, 8, 15, 14, offset=2),
+            ZonedDateTime(2020, 8, 15, 15, tz="Europe/Paris"),
+        )
+
+    def test_invalid(self):
+        start, end = self.START, self.END
+        with pytest.raises(ValueError, match="inclusive"):
+            start.is_between(start, end, inclusive="[[")  # type: ignore[arg-type]
+        with pytest.raises(TypeError, match="inclusive"):
+            start.is_between(start, end, inclusive=1)  # type: ignore[arg-type]
+        with pytest.raises(TypeError, match="is_between"):
+            start.is_between(start, hours(1))  # type: ignore[arg-type]
+        with pytest.raises(TypeError, match="is_between"):
+            start.is_between(LocalDateTime(2020, 1, 1), end)  # type: ignore[arg-type]
+        with pytest.raises(TypeError):
+            start.is_between(start)  # type: ignore[call-arg]
+        with pytest.raises(TypeError, match="foo"):
+            start.is_between(start, end, foo=1)  # type: ignore[call-arg]
+
 
 def test_pickle():
     d = Instant.from_utc(2020, 8, 15, 23, 12, 9, nanosecond=987_654_200)
@@ -779,6 +997,15 @@ def test_old_pickle_data_remains_unpicklable():
     )
 
 
+def test_unpickle_unknown_version():
+    d = Instant.from_utc(2020, 8, 15, 23, 12, 9, nanosecond=987_654_200)
+    dumped = pickle.dumps(d)
+    payload = b"\x01I\xb4\xcb\xd6\x0e\x00\x00\x008h\xde:"
+    assert payload in dumped
+    with pytest.raises(ValueError, match="pickle version"):
+        pickle.loads(dumped.replace(payload, b"\x09" + payload[1:]))
+
+
 def test_copy():
     d = Instant.from_utc(2020, 8, 15, 23, 12, 9, nanosecond=987_654)
     assert copy(d) is d
@@ -908,6 +1135,48 @@ def test_format_rfc3339():
     )
 
 
+@pytest.mark.parametrize(
+    "nanos, digits, expected",
+    [
+        (450, None, "2020-08-15 23:12:09.00000045Z"),
+        (0, None, "2020-08-15 23:12:09Z"),
+        (450, 0, "2020-08-15 23:12:09Z"),
+        (450, 3, "2020-08-15 23:12:09.000Z"),
+        (450, 6, "2020-08-15 23:12:09.000000Z"),
+        (450, 9, "2020-08-15 23:12:09.000000450Z"),
+        (0, 9, "2020-08-15 23:12:09.000000000Z"),
+        # truncated, not rounded
+        (999_999_999, 3, "2020-08-15 23:12:09.999Z"),
+        (999_999_999, 0, "2020-08-15 23:12:09Z"),
+        (120_000_000, 6, "2020-08-15 23:12:09.120000Z"),
+    ],
+)
+def test_format_rfc3339_fractional_digits(nanos, digits, expected):
+    d = Instant.from_utc(2020, 8, 15, 23, 12, 9, nanosecond=nanos)
+    assert d.format_rfc3339(fractional_digits=digits) == expected
+    # the parser accepts any number of digits
+    truncated = nanos if digits is None else nanos - nanos % 10 ** (9 - digits)
+    assert Instant.parse_rfc3339(expected) == Instant.from_utc(
+        2020, 8, 15, 23, 12, 9, nanosecond=truncated
+    )
+
+
+def test_format_rfc3339_invalid_fractional_digits():
+    d = Instant.from_utc(2020, 8, 15, 23, 12, 9)
+    for digits in [1, 2, -3, 10, 12]:
+        with pytest.raises(ValueError, match="fractional_digits"):
+            d.format_rfc3339(fractional_digits=digits)
+
+    with pytest.raises(TypeError, match="fractional_digits"):
+        d.format_rfc3339(fractional_digits="3")  # type: ignore[arg-type]
+
+    with pytest.raises(TypeError):
+        d.format_rfc3339(3)  # type: ignore[misc]
+
+    with pytest.raises(TypeError, match="foo"):
+        d.format_rfc3339(foo=3)  # type: ignore[call-arg]
+
+
 class TestParseRFC3339:
 
     @pytest.mark.parametrize(
@@ -968,6 +1237,23 @@ class TestParseRFC3339:
         ):
             Instant.parse_rfc3339(s)
 
+    @pytest.mark.parametrize(
+        "s",
+        [
+            "2020-08-16T01:42:09.5+02:30",
+            "2020-08-15T19:12:09.5-04:00",
+            "2020-08-15T23:12:09.5Z",
+        ],
+    )
+    def test_nonzero_offset_via_offset_datetime(self, s):
+        # Nonzero offsets aren't implicitly converted.
+        # Going through OffsetDateTime makes the conversion explicit.
+        expect = Instant.from_utc(
+            2020, 8, 15, 23, 12, 9, nanosecond=500_000_000
+        )
+        assert OffsetDateTime.parse_rfc3339(s).to_instant() == expect
+        assert Instant.parse_rfc3339(expect.format_rfc3339()) == expect
+
 
 @pytest.mark.parametrize(
     "d, expect",
@@ -1090,6 +1376,18 @@ class TestParseCommonIso:
         ):
             Instant.parse_common_iso(s)
 
+    @given(
+        integers(
+            min_value=Instant.MIN.timestamp_nanos(),
+            max_value=Instant.MAX.timestamp_nanos(),
+        )
+    )
+    def test_roundtrip(self, ns: int):
+        i = Instant.from_timestamp_nanos(ns)
+        s = i.format_common_iso()
+        assert s.endswith("Z")
+        assert Instant.parse_common_iso(s) == i
+
 
 class TestRound:
 
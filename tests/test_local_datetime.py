@@ -1,6 +1,7 @@
 import pickle
 import re
-from datetime import datetime as py_datetime, timezone
+from datetime import datetime as py_datetime, timedelta, timezone
+from zoneinfo import ZoneInfoNotFoundError
 
 import pytest
 from hypothesis import given
@@ -79,6 +80,27 @@ def test_assume_fixed_offset():
     )
 
 
+def test_to_instant():
+    d = LocalDateTime(2020, 8, 15, 23, 12, nanosecond=1)
+    assert d.to_instant(hours(5)) == Instant.from_utc(
+        2020, 8, 15, 18, 12, nanosecond=1
+    )
+    assert d.to_instant(-2) == d.assume_fixed_offset(-2).to_instant()
+    assert d.to_instant(0) == d.assume_utc()
+
+    with pytest.raises(ValueError, match="range"):
+        LocalDateTime(1, 1, 1).to_instant(1)
+
+    with pytest.raises(ValueError, match="range"):
+        LocalDateTime(9999, 12, 31, 23).to_instant(-2)
+
+    with pytest.raises(ValueError):
+        d.to_instant(25)
+
+    with pytest.raises(TypeError):
+        d.to_instant("2")  # type: ignore[arg-type]
+
+
 class TestAssumeTz:
     def test_typical(self):
         d = LocalDateTime(2020, 8, 15, 23)
@@ -92,9 +114,15 @@ class TestAssumeTz:
     def test_ambiguous(self):
         d = LocalDateTime(2023, 10, 29, 2, 15)
 
-        with pytest.raises(RepeatedTime, match="02:15.*Europe/Amsterdam"):
+        with pytest.raises(
+            RepeatedTime, match="02:15.*Europe/Amsterdam"
+        ) as exc_info:
             d.assume_tz("Europe/Amsterdam", disambiguate="raise")
 
+        assert exc_info.value.date == Date(2023, 10, 29)
+        assert exc_info.value.time == Time(2, 15)
+        assert exc_info.value.tz == "Europe/Amsterdam"
+
         assert d.assume_tz(
             "Europe/Amsterdam", disambiguate="earlier"
         ).exact_eq(
@@ -123,9 +151,15 @@ class TestAssumeTz:
     def test_nonexistent(self):
         d = LocalDateTime(2023, 3, 26, 2, 15)
 
-        with pytest.raises(SkippedTime, match="02:15.*Europe/Amsterdam"):
+        with pytest.raises(
+            SkippedTime, match="02:15.*Europe/Amsterdam"
+        ) as exc_info:
             d.assume_tz("Europe/Amsterdam", disambiguate="raise")
 
+        assert exc_info.value.date == Date(2023, 3, 26)
+        assert exc_info.value.time == Time(2, 15)
+        assert exc_info.value.tz == "Europe/Amsterdam"
+
         assert d.assume_tz(
             "Europe/Amsterdam", disambiguate="earlier"
         ).exact_eq(
@@ -140,6 +174,19 @@ class TestAssumeTz:
             )
         )
 
+    def test_error_keeps_nanoseconds(self):
+        d = LocalDateTime(2023, 3, 26, 2, 15, nanosecond=1)
+        with pytest.raises(
+            SkippedTime,
+            match=re.escape(
+                "2023-03-26 02:15:00.000000001 is skipped "
+                "in timezone 'Europe/Amsterdam'"
+            ),
+        ) as exc_info:
+            d.assume_tz("Europe/Amsterdam", disambiguate="raise")
+
+        assert exc_info.value.time == Time(2, 15, nanosecond=1)
+
 
 class TestAssumeSystemTz:
     @system_tz_ams()
@@ -154,9 +201,13 @@ class TestAssumeSystemTz:
     def test_ambiguous(self):
         d = LocalDateTime(2023, 10, 29, 2, 15)
 
-        with pytest.raises(RepeatedTime, match="02:15.*system"):
+        with pytest.raises(RepeatedTime, match="02:15.*system") as exc_info:
             d.assume_system_tz(disambiguate="raise")
 
+        assert exc_info.value.date == Date(2023, 10, 29)
+        assert exc_info.value.time == Time(2, 15)
+        assert exc_info.value.tz is None
+
         assert d.assume_system_tz(disambiguate="earlier").exact_eq(
             SystemDateTime(2023, 10, 29, 2, 15, disambiguate="earlier")
         )
@@ -171,9 +222,13 @@ class TestAssumeSystemTz:
     def test_nonexistent(self):
         d = LocalDateTime(2023, 3, 26, 2, 15)
 
-        with pytest.raises(SkippedTime, match="02:15.*system"):
+        with pytest.raises(SkippedTime, match="02:15.*system") as exc_info:
             d.assume_system_tz(disambiguate="raise")
 
+        assert exc_info.value.date == Date(2023, 3, 26)
+        assert exc_info.value.time == Time(2, 15)
+        assert exc_info.value.tz is None
+
         assert d.assume_system_tz(disambiguate="earlier").exact_eq(
             SystemDateTime(2023, 3, 26, 2, 15, disambiguate="earlier")
         )
@@ -184,6 +239,196 @@ class TestAssumeSystemTz:
             SystemDateTime(2023, 3, 26, 2, 15, disambiguate="compatible")
         )
 
+    @system_tz_ams()
+    def test_error_keeps_nanoseconds(self):
+        d = LocalDateTime(2023, 10, 29, 2, 15, nanosecond=120_000_000)
+        with pytest.raises(
+            RepeatedTime,
+            match=re.escape(
+                "2023-10-29 02:15:00.12 is repeated in the system timezone"
+            ),
+        ) as exc_info:
+            d.assume_system_tz(disambiguate="raise")
+
+        assert exc_info.value.time == Time(2, 15, nanosecond=120_000_000)
+
+
+class TestToSystemOffset:
+    @system_tz_ams()
+    def test_typical(self):
+        assert (
+            LocalDateTime(2020, 8, 15, 23, nanosecond=1)
+            .to_system_offset(disambiguate="raise")
+            .exact_eq(
+                OffsetDateTime(2020, 8, 15, 23, nanosecond=1, offset=2)
+            )
+        )
+        assert (
+            LocalDateTime(2020, 1, 15, 23)
+            .to_system_offset()
+            .exact_eq(OffsetDateTime(2020, 1, 15, 23, offset=1))
+        )
+
+    @system_tz_ams()
+    def test_fixed_offset_tzinfo(self):
+        # the result doesn't carry the system zone's named tzinfo
+        py_dt = LocalDateTime(2020, 8, 15, 23).to_system_offset().py_datetime()
+        assert py_dt.tzinfo == timezone(timedelta(hours=2))
+        assert py_dt.tzname() == "UTC+02:00"
+
+    @system_tz_ams()
+    @pytest.mark.parametrize(
+        "d",
+        [
+            # ambiguous
+            LocalDateTime(2023, 10, 29, 2, 15),
+            # nonexistent
+            LocalDateTime(2023, 3, 26, 2, 15),
+        ],
+    )
+    def test_same_as_assume_system_tz(self, d):
+        for dis in ["earlier", "later", "compatible"]:
+            assert d.to_system_offset(disambiguate=dis).exact_eq(
+                d.assume_system_tz(disambiguate=dis).to_fixed_offset()
+            )
+
+        with pytest.raises((RepeatedTime, SkippedTime), match="system"):
+            d.to_system_offset(disambiguate="raise")
+
+    def test_invalid(self):
+        d = LocalDateTime(2020, 8, 15, 23)
+        with pytest.raises(TypeError, match="positional"):
+            d.to_system_offset("raise")  # type: ignore[misc]
+
+        with pytest.raises(ValueError, match="disambiguate"):
+            d.to_system_offset(disambiguate="foo")  # type: ignore[arg-type]
+
+
+class TestIsValidInTz:
+    def test_typical(self):
+        assert LocalDateTime(2020, 8, 15, 23).is_valid_in_tz("Asia/Tokyo")
+
+    def test_gap(self):
+        d = LocalDateTime(2023, 3, 26, 2, 15)
+        assert not d.is_valid_in_tz("Europe/Amsterdam")
+        # the same time exists in other timezones
+        assert d.is_valid_in_tz("America/New_York")
+
+    def test_fold(self):
+        d = LocalDateTime(2023, 10, 29, 2, 15)
+        assert d.is_valid_in_tz("Europe/Amsterdam")
+
+    def test_invalid_tz(self):
+        d = LocalDateTime(2020, 8, 15, 23)
+        with pytest.raises(ZoneInfoNotFoundError):
+            d.is_valid_in_tz("America/Nowhere")
+
+        with pytest.raises(TypeError):
+            d.is_valid_in_tz(3)  # type: ignore[arg-type]
+
+
+class TestFromTimestamp:
+
+    def test_basics(self):
+        assert LocalDateTime.from_timestamp(0, tz="Iceland") == LocalDateTime(
+            1970, 1, 1
+        )
+        assert LocalDateTime.from_timestamp(
+            1_597_493_310, tz="America/Nuuk"
+        ) == LocalDateTime(2020, 8, 15, 10, 8, 30)
+        assert LocalDateTime.from_timestamp(
+            1_597_493_310.5, tz="Asia/Tokyo"
+        ) == LocalDateTime(2020, 8, 15, 21, 8, 30, nanosecond=500_000_000)
+
+    def test_nanosecond(self):
+        assert LocalDateTime.from_timestamp(
+            1_597_493_310, tz="Asia/Tokyo", nanosecond=987_654_321
+        ) == LocalDateTime(2020, 8, 15, 21, 8, 30, nanosecond=987_654_321)
+        assert LocalDateTime.from_timestamp(
+            -1, tz="UTC", nanosecond=1
+        ) == LocalDateTime(1969, 12, 31, 23, 59, 59, nanosecond=1)
+        assert LocalDateTime.from_timestamp(
+            0, tz="UTC", nanosecond=0
+        ) == LocalDateTime(1970, 1, 1)
+
+    @pytest.mark.parametrize("nanos", [-1, 1_000_000_000, 1 << 70])
+    def test_nanosecond_out_of_range(self, nanos):
+        with pytest.raises(ValueError, match="nanosecond"):
+            LocalDateTime.from_timestamp(0, tz="UTC", nanosecond=nanos)
+
+    def test_nanosecond_invalid_type(self):
+        with pytest.raises(TypeError, match="nanosecond"):
+            LocalDateTime.from_timestamp(
+                0, tz="UTC", nanosecond=1.0  # type: ignore[arg-type]
+            )
+
+    def test_dst(self):
+        # one hour apart, but the wall clock repeats during the fold
+        ts = 1_698_539_400  # 2023-10-29 00:30 UTC
+        assert LocalDateTime.from_timestamp(
+            ts, tz="Europe/Amsterdam"
+        ) == LocalDateTime(2023, 10, 29, 2, 30)
+        assert LocalDateTime.from_timestamp(
+            ts + 3600, tz="Europe/Amsterdam"
+        ) == LocalDateTime(2023, 10, 29, 2, 30)
+
+    def test_invalid(self):
+        with pytest.raises((OSError, OverflowError, ValueError)):
+            LocalDateTime.from_timestamp(1_000_000_000_000_000_000, tz="UTC")
+
+        with pytest.raises(TypeError):
+            LocalDateTime.from_timestamp("0", tz="UTC")  # type: ignore[arg-type]
+
+        with pytest.raises(TypeError):
+            LocalDateTime.from_timestamp(0)  # type: ignore[call-arg]
+
+        with pytest.raises(TypeError):
+            LocalDateTime.from_timestamp(0, foo="bar")  # type: ignore[call-arg]
+
+        with pytest.raises(ZoneInfoNotFoundError):
+            LocalDateTime.from_timestamp(0, tz="America/Nowhere")
+
+
+class TestFormat:
+
+    def test_default(self):
+        d = LocalDateTime(2020, 8, 15, 23, 12, 9, nanosecond=987_654_321)
+        assert f"{d}" == str(d)
+        assert format(d) == "2020-08-15T23:12:09.987654321"
+        assert format(d, "") == str(d)
+
+    def test_directives(self):
+        d = LocalDateTime(2020, 8, 15, 23, 12, 9, nanosecond=987_654_321)
+        assert f"{d:%Y-%m-%d %H:%M}" == "2020-08-15 23:12"
+        assert f"{d:%d/%m/%y %I:%M:%S %p}" == "15/08/20 11:12:09 PM"
+        assert f"{d:%A %B %j}" == "Saturday August 228"
+        assert f"{d:%S.%f}" == "09.987654"
+        assert f"{d:100%%}" == "100%"
+        assert f"{d:no directives}" == "no directives"
+
+    @pytest.mark.parametrize(
+        "spec",
+        [
+            "%Q",
+            "%Y-%m-%-d",
+            "%",
+            "%Y%",
+            "%:z",
+            "%Ey",
+            # locale-dependent full formats
+            "%c",
+            "%x",
+            "%X",
+            # a local datetime has no offset or timezone
+            "%z",
+            "%Z",
+        ],
+    )
+    def test_invalid(self, spec):
+        d = LocalDateTime(2020, 8, 15, 23, 12)
+        with pytest.raises(ValueError, match="directive"):
+            format(d, spec)
+
 
 def test_immutable():
     d = LocalDateTime(2020, 8, 15)
@@ -339,6 +584,31 @@ def test_from_py_datetime():
     ) == LocalDateTime(2020, 8, 15, 23, 12, 9, nanosecond=987_654_000)
 
 
+def test_combine():
+    d = Date(2020, 8, 15)
+    t = Time(23, 12, 9, nanosecond=987_654_321)
+    dt = LocalDateTime.combine(d, t)
+    assert dt == LocalDateTime(2020, 8, 15, 23, 12, 9, nanosecond=987_654_321)
+    assert dt == d.at(t)
+
+    with pytest.raises(TypeError, match="date"):
+        LocalDateTime.combine(t, t)  # type: ignore[arg-type]
+
+    with pytest.raises(TypeError, match="time"):
+        LocalDateTime.combine(d, d)  # type: ignore[arg-type]
+
+    with pytest.raises(TypeError, match="date"):
+        LocalDateTime.combine(
+            py_datetime(2020, 8, 15).date(), t  # type: ignore[arg-type]
+        )
+
+    with pytest.raises(TypeError):
+        LocalDateTime.combine(d)  # type: ignore[call-arg]
+
+    with pytest.raises(TypeError):
+        LocalDateTime.combine(d, t, t)  # type: ignore[call-arg]
+
+
 def test_min_max():
     assert LocalDateTime.MIN == LocalDateTime(1, 1, 1)
     assert LocalDateTime.MAX == LocalDateTime(
@@ -380,6 +650,49 @@ def test_replace():
         d.replace(tzinfo=timezone.utc)  # type: ignore[call-arg]
 
 
+@pytest.mark.parametrize(
+    "kwargs, msg",
+    [
+        ({"hour": 24}, "hour must be in 0..23"),
+        ({"hour": -1}, "hour must be in 0..23"),
+        ({"minute": 60}, "minute must be in 0..59"),
+        ({"second": 60}, "second must be in 0..59"),
+        ({"nanosecond": 1_000_000_000}, "nanosecond must be in 0..999999999"),
+        ({"nanosecond": -1}, "nanosecond must be in 0..999999999"),
+    ],
+)
+def test_invalid_time_component_names_field(kwargs, msg):
+    d = LocalDateTime(2020, 8, 15, 23, 12, 9)
+    with pytest.raises(ValueError, match=re.escape(msg)):
+        d.replace(**kwargs)
+
+    with pytest.raises(ValueError, match=re.escape(msg)):
+        LocalDateTime(2020, 8, 15, **kwargs)
+
+    with pytest.raises(ValueError, match=re.escape(msg)):
+        Time(**kwargs)
+
+
+@pytest.mark.parametrize(
+    "kwargs, field",
+    [
+        ({"month": 2, "day": 30}, "day"),
+        ({"day": 0}, "day"),
+        ({"day": 32}, "day"),
+        ({"month": 13}, "month"),
+        ({"month": 0}, "month"),
+        ({"year": 10_000}, "year"),
+    ],
+)
+def test_invalid_date_component_names_field(kwargs, field):
+    d = LocalDateTime(2020, 1, 31, 23, 12, 9)
+    with pytest.raises(ValueError, match=field):
+        d.replace(**kwargs)
+
+    with pytest.raises(ValueError, match=field):
+        LocalDateTime(**{"year": 2020, "month": 1, "day": 31, **kwargs})
+
+
 class TestShiftMethods:
 
     def test_valid(self):
@@ -474,6 +787,61 @@ class TestShiftMethods:
             pass
 
 
+class TestAddDaysMonths:
+
+    def test_add_days(self):
+        d = LocalDateTime(2020, 2, 28, 23, 12, 9, nanosecond=987_654)
+        assert d.add_days(0) == d
+        assert d.add_days(2) == LocalDateTime(
+            2020, 3, 1, 23, 12, 9, nanosecond=987_654
+        )
+        assert d.add_days(-59) == LocalDateTime(
+            2019, 12, 31, 23, 12, 9, nanosecond=987_654
+        )
+
+    def test_add_months_clamps(self):
+        d = LocalDateTime(2020, 1, 31, 12, nanosecond=1)
+        assert d.add_months(1) == LocalDateTime(2020, 2, 29, 12, nanosecond=1)
+        assert d.add_months(-2) == LocalDateTime(
+            2019, 11, 30, 12, nanosecond=1
+        )
+        assert d.add_months(12) == LocalDateTime(2021, 1, 31, 12, nanosecond=1)
+
+    def test_out_of_range(self):
+        d = LocalDateTime(2020, 1, 31, 12)
+        for n in [-10_000_000, 10_000_000, 1 << 40, 1 << 80]:
+            with pytest.raises((ValueError, OverflowError), match="range|big"):
+                d.add_days(n)
+            with pytest.raises((ValueError, OverflowError), match="range|big"):
+                d.add_months(n)
+        with pytest.raises(ValueError, match="range"):
+            LocalDateTime.MAX.add_days(1)
+        with pytest.raises(ValueError, match="range"):
+            LocalDateTime.MIN.add_months(-1)
+
+    def test_invalid(self):
+        d = LocalDateTime(2020, 1, 31, 12)
+        with pytest.raises(TypeError, match="days"):
+            d.add_days(1.5)  # type: ignore[arg-type]
+        with pytest.raises(TypeError, match="months"):
+            d.add_months(days(1))  # type: ignore[arg-type]
+
+    @given(integers(-5_000_000, 5_000_000), integers(-150_000, 150_000))
+    def test_same_as_add(self, n_days, n_months):
+        d = LocalDateTime(2020, 8, 31, 23, 12, 9, nanosecond=987_654_321)
+        for method, kwarg, n in [
+            (d.add_days, "days", n_days),
+            (d.add_months, "months", n_months),
+        ]:
+            try:
+                expected = d.add(**{kwarg: n})
+            except (ValueError, OverflowError):
+                with pytest.raises(ValueError):
+                    method(n)
+            else:
+                assert method(n) == expected
+
+
 class TestShiftOperators:
 
     def test_calendar_units(self):
@@ -673,6 +1041,99 @@ class TestRound:
             d.round("second", increment=5)
 
 
+@pytest.mark.parametrize(
+    "d, expected",
+    [
+        (
+            LocalDateTime(2020, 8, 15, 23, 12, 9, nanosecond=987_654_321),
+            (2020, 8, 15, 23, 12, 9, 987_654_321),
+        ),
+        (LocalDateTime.MIN, (1, 1, 1, 0, 0, 0, 0)),
+        (LocalDateTime.MAX, (9999, 12, 31, 23, 59, 59, 999_999_999)),
+    ],
+)
+def test_as_tuple(d, expected):
+    t = d.as_tuple()
+    assert t == expected
+    assert all(type(x) is int for x in t)
+    *fields, nanos = t
+    assert LocalDateTime(*fields, nanosecond=nanos) == d
+
+
+class TestIsoWeek:
+
+    @pytest.mark.parametrize(
+        "d, expected",
+        [
+            (
+                LocalDateTime(2020, 8, 15, 23, 12, 9, nanosecond=987_654_321),
+                (2020, 33, 6, 23, 12, 9, 987_654_321),
+            ),
+            # ISO year differs from calendar year
+            (LocalDateTime(2021, 1, 2, 12), (2020, 53, 6, 12, 0, 0, 0)),
+            (LocalDateTime(2024, 12, 30), (2025, 1, 1, 0, 0, 0, 0)),
+            (LocalDateTime.MIN, (1, 1, 1, 0, 0, 0, 0)),
+            (
+                LocalDateTime.MAX,
+                (9999, 52, 5, 23, 59, 59, 999_999_999),
+            ),
+        ],
+    )
+    def test_examples(self, d, expected):
+        t = d.iso_year_week_day()
+        assert t == expected
+        assert all(type(x) is int for x in t)
+        *fields, nanos = t
+        assert (
+            LocalDateTime.from_iso_week_datetime(*fields, nanosecond=nanos)
+            == d
+        )
+
+    @given(integers(1, 3_652_059), integers(0, 86_399))
+    def test_roundtrip(self, n, secs):
+        d = Date.from_ordinal(n).at(
+            Time(secs // 3600, secs // 60 % 60, secs % 60)
+        )
+        year, week, weekday, *time = d.iso_year_week_day()
+        assert (year, week, weekday) == tuple(d.py_datetime().isocalendar())
+        assert LocalDateTime.from_iso_week_datetime(
+            year, week, weekday, *time[:3], nanosecond=time[3]
+        ) == d
+
+    def test_defaults(self):
+        assert LocalDateTime.from_iso_week_datetime(2020, 1, 1) == (
+            LocalDateTime(2019, 12, 30)
+        )
+
+    def test_invalid(self):
+        with pytest.raises(ValueError, match="week"):
+            LocalDateTime.from_iso_week_datetime(2021, 53, 1)
+        with pytest.raises(ValueError, match="week"):
+            LocalDateTime.from_iso_week_datetime(2021, 0, 1)
+        with pytest.raises(ValueError, match="weekday"):
+            LocalDateTime.from_iso_week_datetime(2021, 1, 8)
+        with pytest.raises(ValueError, match="year"):
+            LocalDateTime.from_iso_week_datetime(0, 1, 1)
+        with pytest.raises(ValueError, match="year"):
+            LocalDateTime.from_iso_week_datetime(10_000, 1, 1)
+        with pytest.raises(ValueError, match="hour"):
+            LocalDateTime.from_iso_week_datetime(2021, 1, 1, 24)
+        with pytest.raises(ValueError, match="nanosecond"):
+            LocalDateTime.from_iso_week_datetime(
+                2021, 1, 1, nanosecond=1_000_000_000
+            )
+        # ISO week 9999-W52 ends after Date.MAX
+        assert LocalDateTime.from_iso_week_datetime(9999, 52, 5) == (
+            LocalDateTime(9999, 12, 31)
+        )
+        with pytest.raises(ValueError, match="range"):
+            LocalDateTime.from_iso_week_datetime(9999, 52, 6)
+        with pytest.raises(TypeError):
+            LocalDateTime.from_iso_week_datetime(2021, 1)  # type: ignore[call-arg]
+        with pytest.raises(TypeError):
+            LocalDateTime.from_iso_week_datetime(2021, 1, 1.5)  # type: ignore[arg-type]
+
+
 def test_replace_date():
     d = LocalDateTime(2020, 8, 15, 3, 12, 9)
     assert d.replace_date(Date(1996, 2, 19)) == LocalDateTime(
@@ -709,6 +1170,15 @@ def test_old_pickle_data_remains_unpicklable():
     )
 
 
+def test_unpickle_unknown_version():
+    d = LocalDateTime(2020, 8, 15, 23, 12, 9, nanosecond=987_654)
+    dumped = pickle.dumps(d)
+    payload = b"\x01\xe4\x07\x08\x0f\x17\x0c\t\x06\x12\x0f\x00"
+    assert payload in dumped
+    with pytest.raises(ValueError, match="pickle version"):
+        pickle.loads(dumped.replace(payload, b"\x09" + payload[1:]))
+
+
 def test_strptime():
     assert LocalDateTime.strptime(
         "2020-08-15 23:12", "%Y-%m-%d %H:%M"
@@ -14,10 +14,13 @@ from whenever import (
     Instant,
     InvalidOffset,
     LocalDateTime,
+    MonthDay,
     OffsetDateTime,
     SystemDateTime,
     Time,
     TimeDelta,
+    UnsupportedYear,
+    YearMonth,
     ZonedDateTime,
     hours,
     patch_current_time,
@@ -46,6 +49,7 @@ def test_multiple_interpreters():
 def test_exceptions():
     assert issubclass(ImplicitlyIgnoringDST, TypeError)
     assert issubclass(InvalidOffset, ValueError)
+    assert issubclass(UnsupportedYear, ValueError)
 
 
 def test_version():
@@ -54,6 +58,62 @@ def test_version():
     assert isinstance(__version__, str)
 
 
+@pytest.mark.parametrize(
+    "obj",
+    [
+        Date(2021, 1, 2),
+        YearMonth(2021, 1),
+        MonthDay(1, 2),
+        Time(3, 4, 5, nanosecond=6),
+        TimeDelta(hours=1, nanoseconds=2),
+        DateDelta(years=1, days=2),
+        DateTimeDelta(months=1, seconds=2),
+        Instant.from_utc(2021, 1, 2, 3, nanosecond=4),
+        LocalDateTime(2021, 1, 2, 3, nanosecond=4),
+        OffsetDateTime(2021, 1, 2, 3, nanosecond=4, offset=5),
+        ZonedDateTime(2021, 1, 2, 3, nanosecond=4, tz="Europe/Amsterdam"),
+    ],
+)
+def test_common_iso_method_names(obj):
+    # All types share the same method names for the common ISO format,
+    # so they can be (de)serialized uniformly.
+    parsed = type(obj).parse_common_iso(obj.format_common_iso())
+    assert parsed == obj
+    assert str(parsed) == str(obj)
+
+
+@pytest.mark.parametrize(
+    "create",
+    [
+        lambda y: Date(y, 1, 1),
+        lambda y: Date(2020, 1, 1).replace(year=y),
+        lambda y: MonthDay(1, 1).in_year(y),
+        lambda y: Instant.from_utc(y, 1, 1),
+        lambda y: LocalDateTime(y, 1, 1),
+        lambda y: LocalDateTime(2020, 1, 1).replace(year=y),
+        lambda y: LocalDateTime.from_iso_week_datetime(y, 1, 1),
+        lambda y: OffsetDateTime(y, 1, 1, offset=0),
+        lambda y: OffsetDateTime(2020, 1, 1, offset=0).replace(
+            year=y, ignore_dst=True
+        ),
+        lambda y: ZonedDateTime(y, 1, 1, tz="UTC"),
+        lambda y: ZonedDateTime(2020, 1, 1, tz="UTC").replace(
+            year=y, disambiguate="raise"
+        ),
+        lambda y: SystemDateTime(y, 1, 1),
+        lambda y: SystemDateTime(2020, 1, 1).replace(
+            year=y, disambiguate="raise"
+        ),
+    ],
+)
+def test_unsupported_year(create):
+    # All ways to create a date from its fields reject years
+    # before the common era with the same exception
+    for year in (0, -1):
+        with pytest.raises(UnsupportedYear, match="common era"):
+            create(year)
+
+
 @pytest.mark.skipif(
     sys.implementation.name == "pypy",
     reason="time-machine doesn't support PyPy",
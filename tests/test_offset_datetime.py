@@ -62,11 +62,40 @@ class TestInit:
         with pytest.raises(ValueError, match="offset.*24.*hours"):
             OffsetDateTime(2020, 8, 15, 5, 12, offset=34)
 
+        # 24 hours itself is excluded, like in the standard library
+        with pytest.raises(ValueError, match="offset.*24.*hours"):
+            OffsetDateTime(2020, 8, 15, 5, 12, offset=24)
+
+        with pytest.raises(ValueError, match="offset.*24.*hours"):
+            OffsetDateTime(2020, 8, 15, 5, 12, offset=-24)
+
+        with pytest.raises(ValueError, match="offset.*24.*hours"):
+            OffsetDateTime(2020, 8, 15, 5, 12, offset=-(2**63))
+
+        with pytest.raises((ValueError, OverflowError)):
+            OffsetDateTime(2020, 8, 15, 5, 12, offset=1 << 70)
+
+        assert OffsetDateTime(2020, 8, 15, offset=23).offset == hours(23)
+        assert OffsetDateTime(2020, 8, 15, offset=-23).offset == hours(-23)
+
     def test_invalid_offset_delta(self):
         # too large
         with pytest.raises(ValueError, match="offset.*24.*hours"):
             OffsetDateTime(2020, 8, 15, 5, 12, offset=hours(34))
 
+        with pytest.raises(ValueError, match="offset.*24.*hours"):
+            OffsetDateTime(2020, 8, 15, 5, 12, offset=hours(24))
+
+        with pytest.raises(ValueError, match="offset.*24.*hours"):
+            OffsetDateTime(2020, 8, 15, 5, 12, offset=hours(-24))
+
+        limit = hours(24) - seconds(1)
+        assert OffsetDateTime(2020, 8, 15, offset=limit).offset == limit
+        assert OffsetDateTime(2020, 8, 15, offset=-limit).offset == -limit
+        assert OffsetDateTime(
+            2020, 8, 15, offset=-limit
+        ).py_datetime().utcoffset() == (-limit).py_timedelta()
+
         # too precise
         with pytest.raises(ValueError, match="(o|O)ffset.*whole.*seconds"):
             OffsetDateTime(
@@ -98,6 +127,9 @@ class TestInit:
         with pytest.raises(ValueError, match="date|day"):
             OffsetDateTime(2020, 2, 30, 5, 12, offset=5)
 
+        with pytest.raises(ValueError, match="month"):
+            OffsetDateTime(2020, 13, 1, 5, 12, offset=5)
+
         with pytest.raises(ValueError, match="time|minute"):
             OffsetDateTime(2020, 2, 28, 5, 64, offset=5)
 
@@ -110,6 +142,19 @@ class TestInit:
         with pytest.raises(ValueError, match="range"):
             OffsetDateTime(1, 1, 1, 0, offset=1)
 
+    @pytest.mark.parametrize(
+        "offset", [hours(5), hours(-3) - minutes(30), hours(0), 9]
+    )
+    def test_same_as_assume_fixed_offset(self, offset):
+        d = OffsetDateTime(
+            2020, 8, 15, 5, 12, 30, nanosecond=450, offset=offset
+        )
+        assert d.exact_eq(
+            LocalDateTime(
+                2020, 8, 15, 5, 12, 30, nanosecond=450
+            ).assume_fixed_offset(offset)
+        )
+
 
 def test_immutable():
     d = OffsetDateTime(2020, 8, 15, offset=minutes(5))
@@ -392,6 +437,18 @@ class TestTimestamp:
             == 1_597_493_310_000_000_045
         )
 
+    def test_nanos_as_sort_key(self):
+        items = [
+            OffsetDateTime(2020, 8, 15, 12, offset=2),
+            OffsetDateTime(2020, 8, 15, 9, nanosecond=1, offset=-1),
+            OffsetDateTime(2020, 8, 15, 10, offset=0),
+            OffsetDateTime(2020, 8, 15, 10, offset=0, nanosecond=1),
+            OffsetDateTime(2020, 8, 15, 5, offset=-5),
+        ]
+        by_nanos = sorted(items, key=lambda d: d.timestamp_nanos())
+        assert by_nanos == sorted(items, key=lambda d: d.to_instant())
+        assert [d.offset.in_hours() for d in by_nanos] == [2, 0, -5, -1, 0]
+
 
 class TestFromTimestamp:
 
@@ -1032,6 +1089,17 @@ def test_old_pickle_data_remains_unpicklable():
     )
 
 
+def test_unpickle_unknown_version():
+    d = OffsetDateTime(
+        2020, 8, 15, 23, 12, 9, nanosecond=987_654_321, offset=3
+    )
+    dumped = pickle.dumps(d)
+    payload = b"\x01\xe4\x07\x08\x0f\x17\x0c\t\xb1h\xde:0*\x00\x00"
+    assert payload in dumped
+    with pytest.raises(ValueError, match="pickle version"):
+        pickle.loads(dumped.replace(payload, b"\x09" + payload[1:]))
+
+
 def test_instant():
     d = OffsetDateTime(
         2020, 8, 15, 23, 12, 9, nanosecond=987_654_321, offset=3
@@ -1041,6 +1109,16 @@ def test_instant():
     )
 
 
+def test_to_instant():
+    d = OffsetDateTime(
+        2020, 8, 15, 23, 12, 9, nanosecond=987_654_321, offset=3
+    )
+    assert d.to_instant().exact_eq(
+        Instant.from_utc(2020, 8, 15, 20, 12, 9, nanosecond=987_654_321)
+    )
+    assert d.to_instant().exact_eq(d.instant())
+
+
 def test_to_fixed_offset():
     d = OffsetDateTime(
         2020, 8, 15, 23, 12, 9, nanosecond=987_654_321, offset=3
@@ -1064,6 +1142,63 @@ def test_to_fixed_offset():
         OffsetDateTime(9999, 12, 31, hour=23, offset=0).to_fixed_offset(1)
 
 
+def test_to_fixed_offset_vs_replace_offset():
+    d = OffsetDateTime(2020, 8, 15, 12, 30, offset=2)
+
+    # to_fixed_offset() keeps the moment, and shifts the wall clock
+    converted = d.to_fixed_offset(hours(5))
+    assert converted == d
+    assert converted.to_instant() == d.to_instant()
+    assert (converted.hour, converted.offset) == (15, hours(5))
+
+    # replace() keeps the wall clock, and changes the moment
+    replaced = d.replace(offset=hours(5), ignore_dst=True)
+    assert replaced != d
+    assert (replaced.hour, replaced.offset) == (12, hours(5))
+    assert d - replaced == hours(3)
+
+
+class TestRoundOffsetToMinutes:
+    def test_historical_offset(self):
+        # Amsterdam's offset until 1937 was +00:19:32
+        d = OffsetDateTime(
+            1900,
+            1,
+            1,
+            12,
+            nanosecond=1,
+            offset=minutes(19) + seconds(32),
+        )
+        rounded = d.round_offset_to_minutes()
+        assert rounded.exact_eq(
+            OffsetDateTime(
+                1900, 1, 1, 12, 0, 28, nanosecond=1, offset=minutes(20)
+            )
+        )
+        assert rounded == d
+
+    def test_round_down(self):
+        d = OffsetDateTime(2020, 8, 15, 12, offset=-(hours(4) + seconds(29)))
+        assert d.round_offset_to_minutes().exact_eq(
+            OffsetDateTime(2020, 8, 15, 12, 0, 29, offset=-4)
+        )
+
+    def test_tie_away_from_zero(self):
+        d = OffsetDateTime(2020, 8, 15, 12, offset=seconds(30))
+        assert d.round_offset_to_minutes().offset == minutes(1)
+        d = OffsetDateTime(2020, 8, 15, 12, offset=-seconds(30))
+        assert d.round_offset_to_minutes().offset == -minutes(1)
+
+    def test_whole_minutes_unchanged(self):
+        d = OffsetDateTime(2020, 8, 15, 12, offset=minutes(330))
+        assert d.round_offset_to_minutes().exact_eq(d)
+
+    def test_out_of_range(self):
+        d = OffsetDateTime(9999, 12, 31, 23, 59, 59, offset=seconds(31))
+        with pytest.raises(ValueError, match="range"):
+            d.round_offset_to_minutes()
+
+
 def test_to_tz():
     d = OffsetDateTime(
         2020, 8, 15, 20, 12, 9, nanosecond=987_654_321, offset=3
@@ -1115,6 +1250,13 @@ def test_local():
     d = OffsetDateTime(2020, 8, 15, 20, nanosecond=1, offset=3)
     assert d.local() == LocalDateTime(2020, 8, 15, 20, nanosecond=1)
 
+    # the wall clock is kept as-is, even if it's on another day in UTC
+    d = OffsetDateTime(2020, 8, 15, 23, 30, offset=-5)
+    assert d.local() == LocalDateTime(2020, 8, 15, 23, 30)
+
+    # the inverse of assume_fixed_offset()
+    assert d.local().assume_fixed_offset(-5).exact_eq(d)
+
 
 @pytest.mark.parametrize(
     "string, fmt, expected",
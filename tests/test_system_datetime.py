@@ -112,7 +112,7 @@ class TestInit:
         )
 
     def test_invalid(self):
-        with pytest.raises(ValueError, match="range|time"):
+        with pytest.raises(ValueError, match="nanosecond"):
             SystemDateTime(2020, 1, 15, nanosecond=1_000_000_000)
 
         with pytest.raises(ValueError, match="disambiguate"):
@@ -145,6 +145,13 @@ class TestInstant:
             .exact_eq(Instant.from_utc(2023, 10, 29, 1, 15))
         )
 
+    @system_tz_ams()
+    def test_to_instant(self):
+        d = SystemDateTime(2020, 8, 15, 11, nanosecond=3)
+        assert d.to_instant().exact_eq(
+            Instant.from_utc(2020, 8, 15, 9, nanosecond=3)
+        )
+
 
 def test_local():
     d = SystemDateTime(2020, 8, 15, 12, 8, 30)
@@ -1788,6 +1795,16 @@ def test_old_pickle_data_remains_unpicklable():
     )
 
 
+@system_tz_ams()
+def test_unpickle_unknown_version():
+    d = SystemDateTime(2020, 8, 15, 23, 12, 9, nanosecond=987_654_321)
+    dumped = pickle.dumps(d)
+    payload = b"\x01\xe4\x07\x08\x0f\x17\x0c\t\xb1h\xde: \x1c\x00\x00"
+    assert payload in dumped
+    with pytest.raises(ValueError, match="pickle version"):
+        pickle.loads(dumped.replace(payload, b"\x09" + payload[1:]))
+
+
 def test_copy():
     d = SystemDateTime(2020, 8, 15, 23, 12, 9, nanosecond=987_654)
     assert copy(d) is d
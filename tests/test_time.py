@@ -5,12 +5,31 @@ from datetime import (
     timedelta as py_timedelta,
     timezone as py_timezone,
 )
+from zoneinfo import ZoneInfoNotFoundError
 
 import pytest
 
-from whenever import Date, LocalDateTime, Time
+from whenever import (
+    Date,
+    DateDelta,
+    LocalDateTime,
+    SystemDateTime,
+    Time,
+    TimeDelta,
+    ZonedDateTime,
+    days,
+    hours,
+    minutes,
+    nanoseconds,
+)
 
-from .common import AlwaysEqual, AlwaysLarger, AlwaysSmaller, NeverEqual
+from .common import (
+    AlwaysEqual,
+    AlwaysLarger,
+    AlwaysSmaller,
+    NeverEqual,
+    system_tz,
+)
 
 
 class TestInit:
@@ -62,6 +81,16 @@ def test_py_time():
     assert Time(nanosecond=999).py_time() == py_time(0)
 
 
+def test_total_seconds_float():
+    assert Time().total_seconds_float() == 0.0
+    t = Time(1, 2, 3, nanosecond=500_000_000)
+    assert t.total_seconds_float() == 3723.5
+    assert Time.MAX.total_seconds_float() == 86_399.999_999_999
+    # nanoseconds are (nearly) preserved even late in the day
+    t = t.replace(hour=23, minute=59, second=59, nanosecond=1)
+    assert round(t.total_seconds_float() * 1e9) == 86_399_000_000_001
+
+
 def test_repr():
     t = Time(1, 2, 3, nanosecond=40_000_000)
     assert repr(t) == "Time(01:02:03.04)"
@@ -81,9 +110,21 @@ def test_replace():
     with pytest.raises(TypeError):
         t.replace(tzinfo=None)  # type: ignore[call-arg]
 
-    with pytest.raises(TypeError):
+    with pytest.raises(TypeError, match="fold"):
         t.replace(fold=0)  # type: ignore[call-arg]
 
+    with pytest.raises(TypeError, match="foo"):
+        t.replace(foo=1)  # type: ignore[call-arg]
+
+    with pytest.raises(TypeError):
+        t.replace(5)  # type: ignore[misc]
+
+    with pytest.raises(TypeError):
+        t.replace(hour="5")  # type: ignore[arg-type]
+
+    with pytest.raises(ValueError):
+        t.replace(nanosecond=1_000_000_000)
+
 
 class TestParseCommonIso:
 
@@ -143,6 +184,29 @@ def test_eq():
     assert hash(t) != hash(different)
 
 
+def test_not_equal_to_py_time():
+    # Comparing with the standard library is deliberately not supported:
+    # equal objects must have equal hashes, which isn't feasible here.
+    # Use py_time() or from_py_time() to compare explicitly.
+    t = Time(1, 2, 3, nanosecond=4_000)
+    py_t = py_time(1, 2, 3, 4)
+    assert t != py_t
+    assert py_t != t
+    assert not t == py_t
+    assert t.py_time() == py_t
+    assert t == Time.from_py_time(py_t)
+
+    with pytest.raises(TypeError):
+        t < py_t  # type: ignore[operator]
+
+    with pytest.raises(TypeError):
+        py_t >= t  # type: ignore[operator]
+
+    # Since they're never equal, they're separate set and dict entries
+    assert len({t, py_t}) == 2
+    assert py_t not in {t: 1}
+
+
 class TestFromPyTime:
     def test_valid(self):
         assert Time.from_py_time(py_time(1, 2, 3, 4)) == Time(
@@ -173,6 +237,35 @@ class TestFromPyTime:
             Time.from_py_time(234)  # type: ignore[arg-type]
 
 
+class TestNow:
+    def test_in_tz(self):
+        before = ZonedDateTime.now("Asia/Kolkata")
+        t = Time.now_in_tz("Asia/Kolkata")
+        after = ZonedDateTime.now("Asia/Kolkata")
+        # the date may have rolled over in between
+        assert (
+            before.time() <= t <= after.time()
+            or before.date() != after.date()
+        )
+
+    def test_invalid_tz(self):
+        with pytest.raises(ZoneInfoNotFoundError):
+            Time.now_in_tz("America/Nowhere")
+
+        with pytest.raises(TypeError):
+            Time.now_in_tz(3)  # type: ignore[arg-type]
+
+    @system_tz("Asia/Kolkata")
+    def test_in_system_tz(self):
+        before = SystemDateTime.now()
+        t = Time.now_in_system_tz()
+        after = SystemDateTime.now()
+        assert (
+            before.time() <= t <= after.time()
+            or before.date() != after.date()
+        )
+
+
 def test_comparison():
     t = Time(1, 2, 3, nanosecond=4_000)
     same = Time(1, 2, 3, nanosecond=4_000)
@@ -208,6 +301,10 @@ def test_constants():
     assert Time.MIDNIGHT == Time()
     assert Time.NOON == Time(12)
     assert Time.MAX == Time(23, 59, 59, nanosecond=999_999_999)
+    # they're shared instances, usable anywhere a Time is expected
+    assert Time.MIDNIGHT is Time.MIDNIGHT
+    assert Date(2020, 8, 15).at(Time.MIDNIGHT) == LocalDateTime(2020, 8, 15)
+    assert Date(2020, 8, 15).at(Time.NOON) == LocalDateTime(2020, 8, 15, 12)
 
 
 def test_on():
@@ -416,6 +513,90 @@ class TestRound:
             t.round("day")  # type: ignore[arg-type]
 
 
+class TestRoundWithCarry:
+
+    def test_no_carry(self):
+        t = Time(12, 39, 59)
+        assert t.round_with_carry("minute", 15) == (Time(12, 45), 0)
+        assert Time.MIDNIGHT.round_with_carry() == (Time.MIDNIGHT, 0)
+
+    @pytest.mark.parametrize(
+        "args, kwargs",
+        [
+            ((), {}),
+            (("second",), {"mode": "ceil"}),
+            (("second", 2), {}),
+            (("hour",), {"mode": "half_floor"}),
+            (("minute", 30), {"mode": "ceil"}),
+        ],
+    )
+    def test_spill_into_next_day(self, args, kwargs):
+        t = Time(23, 59, 59, nanosecond=900_000_000)
+        assert t.round_with_carry(*args, **kwargs) == (Time.MIDNIGHT, 1)
+        assert t.round(*args, **kwargs) == Time.MIDNIGHT
+
+    def test_floor_never_carries(self):
+        assert Time.MAX.round_with_carry("hour", mode="floor") == (
+            Time(23),
+            0,
+        )
+
+    def test_invalid(self):
+        t = Time(1, 2, 3)
+        with pytest.raises(ValueError, match="day"):
+            t.round_with_carry("day")  # type: ignore[arg-type]
+        with pytest.raises(ValueError, match="[Ii]ncrement"):
+            t.round_with_carry("hour", 5)
+
+
+class TestShift:
+
+    @pytest.mark.parametrize(
+        "t, delta, expected, carry",
+        [
+            (Time(12), hours(0), Time(12), 0),
+            (Time(12), hours(2) + minutes(30), Time(14, 30), 0),
+            (Time(23, 30), hours(2), Time(1, 30), 1),
+            (Time(1, 30), hours(-2), Time(23, 30), -1),
+            (Time(1, 30), hours(-50), Time(23, 30), -3),
+            (Time(12), hours(24), Time(12), 1),
+            (Time(12), hours(-24), Time(12), -1),
+            (Time.MAX, nanoseconds(1), Time.MIDNIGHT, 1),
+            (Time.MIDNIGHT, nanoseconds(-1), Time.MAX, -1),
+            (Time(12), TimeDelta.MAX, Time(12), 3_659_634),
+            (Time(12), TimeDelta.MIN, Time(12), -3_659_634),
+        ],
+    )
+    def test_add_and_subtract(self, t, delta, expected, carry):
+        assert t + delta == expected
+        assert t - (-delta) == expected
+        assert t.add(delta) == (expected, carry)
+        assert t.subtract(-delta) == (expected, carry)
+        assert expected - delta == t
+        assert expected.subtract(delta) == (t, -carry)
+
+    def test_invalid(self):
+        t = Time(12)
+        for other in [1, None, days(1), DateDelta(days=1), hours(1) + days(1)]:
+            with pytest.raises(TypeError, match="unsupported operand"):
+                t + other  # type: ignore[operator]
+            with pytest.raises(TypeError, match="unsupported operand"):
+                t - other  # type: ignore[operator]
+            with pytest.raises(TypeError, match="TimeDelta"):
+                t.add(other)  # type: ignore[arg-type]
+            with pytest.raises(TypeError, match="TimeDelta"):
+                t.subtract(other)  # type: ignore[arg-type]
+
+        with pytest.raises(TypeError, match="unsupported operand"):
+            hours(1) + t  # type: ignore[operator]
+        with pytest.raises(TypeError, match="unsupported operand"):
+            hours(1) - t  # type: ignore[operator]
+        with pytest.raises(TypeError, match="unsupported operand"):
+            None + t  # type: ignore[operator]
+        with pytest.raises(TypeError, match="unsupported operand"):
+            t - t  # type: ignore[operator]
+
+
 def test_pickling():
     t = Time(1, 2, 3, nanosecond=4_000)
     dumped = pickle.dumps(t)
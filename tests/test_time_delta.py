@@ -198,6 +198,10 @@ def test_boolean():
     assert not TimeDelta(hours=0, minutes=0, seconds=0, microseconds=0)
     assert not TimeDelta(hours=1, minutes=-60)
     assert TimeDelta(microseconds=1)
+    assert TimeDelta(nanoseconds=1)
+    assert TimeDelta(nanoseconds=-1)
+    assert not TimeDelta.ZERO
+    assert TimeDelta.MIN
 
 
 def test_aggregations():
@@ -219,6 +223,27 @@ def test_aggregations():
     )
 
 
+@pytest.mark.parametrize(
+    "d, expected",
+    [
+        (TimeDelta.ZERO, 0),
+        (hours(23) + minutes(59), 0),
+        (hours(24), 1),
+        (hours(36), 1),
+        (hours(48) - nanoseconds(1), 1),
+        # negative deltas are truncated toward zero
+        (hours(-36), -1),
+        (-hours(24) + nanoseconds(1), 0),
+        (hours(-24), -1),
+        (TimeDelta.MAX, 3_659_634),
+        (TimeDelta.MIN, -3_659_634),
+    ],
+)
+def test_whole_days_of_24h(d, expected):
+    assert d.whole_days_of_24h() == expected
+    assert isinstance(d.whole_days_of_24h(), int)
+
+
 def test_equality():
     d = TimeDelta(hours=1, minutes=2, seconds=3, microseconds=4)
     same = TimeDelta(hours=1, minutes=2, seconds=3, microseconds=4)
@@ -240,6 +265,25 @@ def test_equality():
     assert hash(d) != hash(different)
 
 
+def test_not_comparable_with_py_timedelta():
+    # Mixing with the standard library is deliberately not supported:
+    # equal objects must have equal hashes, which isn't feasible here.
+    # Use py_timedelta() or from_py_timedelta() to compare explicitly.
+    d = TimeDelta(hours=1, minutes=2, seconds=3, microseconds=4)
+    py_d = py_timedelta(hours=1, minutes=2, seconds=3, microseconds=4)
+    assert d != py_d
+    assert py_d != d
+    assert not d == py_d
+    assert d.py_timedelta() == py_d
+    assert d == TimeDelta.from_py_timedelta(py_d)
+
+    with pytest.raises(TypeError):
+        d < py_d  # type: ignore[operator]
+
+    with pytest.raises(TypeError):
+        py_d >= d  # type: ignore[operator]
+
+
 def test_comparison():
     d = TimeDelta(hours=1, minutes=2, seconds=3, microseconds=4)
     same = TimeDelta(hours=1, minutes=2, seconds=3, microseconds=4)
@@ -304,6 +348,8 @@ def test_comparison():
 )
 def test_format_common_iso(d, expected):
     assert d.format_common_iso() == expected
+    assert str(d) == expected
+    assert f"{d}" == expected
 
 
 def test_repr():
@@ -590,6 +636,44 @@ class TestRemainder:
             5.9 % d  # type: ignore[operator]
 
 
+class TestDivmod:
+
+    def test_examples(self):
+        assert divmod(TimeDelta(hours=3, minutes=30), TimeDelta(hours=1)) == (
+            3,
+            TimeDelta(minutes=30),
+        )
+        d = TimeDelta(hours=3, minutes=40, seconds=3, microseconds=4)
+        for divisor in [
+            TimeDelta(minutes=5),
+            TimeDelta(minutes=-5),
+            TimeDelta(microseconds=9),
+            TimeDelta(microseconds=-9),
+        ]:
+            assert divmod(d, divisor) == (d // divisor, d % divisor)
+            assert divmod(-d, divisor) == (-d // divisor, -d % divisor)
+
+        # extreme cases
+        assert divmod(TimeDelta.MIN, TimeDelta.MAX) == (-1, TimeDelta.ZERO)
+        assert divmod(TimeDelta.MAX, TimeDelta(nanoseconds=1)) == (
+            316192377600000000000,
+            TimeDelta.ZERO,
+        )
+
+    def test_divide_by_zero(self):
+        d = TimeDelta(hours=1, minutes=2, seconds=3, microseconds=4)
+        with pytest.raises(ZeroDivisionError):
+            divmod(d, TimeDelta())
+
+    def test_invalid(self):
+        d = TimeDelta(hours=1, minutes=2, seconds=3, microseconds=4)
+        with pytest.raises(TypeError):
+            divmod(d, 5)  # type: ignore[operator]
+
+        with pytest.raises(TypeError):
+            divmod(5, d)  # type: ignore[operator]
+
+
 def test_negate():
     assert TimeDelta.ZERO == -TimeDelta.ZERO
     assert TimeDelta(
@@ -597,6 +681,20 @@ def test_negate():
     ) == -TimeDelta(hours=1, minutes=-2, seconds=3, microseconds=-4)
 
 
+@pytest.mark.parametrize(
+    "d",
+    [
+        TimeDelta(hours=1, minutes=-2, seconds=3, nanoseconds=4),
+        TimeDelta.ZERO,
+        TimeDelta.MAX,
+        TimeDelta.MIN,
+    ],
+)
+def test_double_negate(d):
+    assert -(-d) == d
+    assert -d == d * -1
+
+
 @pytest.mark.parametrize(
     "d",
     [
@@ -837,6 +935,87 @@ def test_from_py_timedelta():
         TimeDelta.from_py_timedelta(py_timedelta.max)
 
 
+class TestFromUnits:
+
+    @pytest.mark.parametrize(
+        "kwargs, expected_nanos",
+        [
+            (dict(), 0),
+            (dict(weeks=1), 7 * 24 * 3_600_000_000_000),
+            (dict(days=1), 24 * 3_600_000_000_000),
+            (dict(days=1.5, minutes=30), (36 * 60 + 30) * 60_000_000_000),
+            (dict(hours=0.1), 360_000_000_000),
+            (dict(seconds=0.3), 300_000_000),
+            (dict(seconds=1e-9), 1),
+            (dict(milliseconds=-1.25), -1_250_000),
+            (dict(microseconds=0.001), 1),
+            (dict(nanoseconds=4.0), 4),
+            (dict(weeks=-1, days=7), 0),
+            (
+                dict(
+                    weeks=1,
+                    days=2,
+                    hours=3,
+                    minutes=4,
+                    seconds=5,
+                    milliseconds=6,
+                    microseconds=7,
+                    nanoseconds=8,
+                ),
+                ((9 * 24 + 3) * 3600 + 4 * 60 + 5) * 1_000_000_000
+                + 6_007_008,
+            ),
+            (dict(hours=MAX_HOURS), MAX_HOURS * 3_600_000_000_000),
+            (
+                dict(nanoseconds=MAX_HOURS * 3_600_000_000_000),
+                MAX_HOURS * 3_600_000_000_000,
+            ),
+        ],
+    )
+    def test_valid(self, kwargs, expected_nanos):
+        assert TimeDelta.from_units(**kwargs) == TimeDelta(
+            nanoseconds=expected_nanos
+        )
+
+    @pytest.mark.parametrize(
+        "kwargs",
+        [
+            dict(nanoseconds=1.5),
+            dict(seconds=1e-10),
+            dict(microseconds=0.0001),
+            dict(hours=5e-324),
+        ],
+    )
+    def test_not_whole_nanoseconds(self, kwargs):
+        with pytest.raises(ValueError, match="whole number of nanoseconds"):
+            TimeDelta.from_units(**kwargs)
+
+    @pytest.mark.parametrize(
+        "kwargs",
+        [
+            dict(hours=MAX_HOURS + 1),
+            dict(weeks=MAX_HOURS // 168, days=7),
+            dict(days=-1e300),
+            dict(seconds=float("inf")),
+            dict(minutes=float("nan")),
+            dict(nanoseconds=1 << 130),
+        ],
+    )
+    def test_out_of_range(self, kwargs):
+        with pytest.raises((ValueError, OverflowError), match="range|large"):
+            TimeDelta.from_units(**kwargs)
+
+    def test_invalid(self):
+        with pytest.raises(TypeError, match="foo"):
+            TimeDelta.from_units(foo=1)  # type: ignore[call-arg]
+
+        with pytest.raises(TypeError):
+            TimeDelta.from_units(1)  # type: ignore[misc]
+
+        with pytest.raises(TypeError):
+            TimeDelta.from_units(hours="1")  # type: ignore[arg-type]
+
+
 def test_as_hrs_mins_secs_nanos():
     d = TimeDelta(hours=1, minutes=2, seconds=-3, microseconds=4_060_000)
     hms = d.in_hrs_mins_secs_nanos()
@@ -852,6 +1031,107 @@ def test_as_hrs_mins_secs_nanos():
     assert TimeDelta.ZERO.in_hrs_mins_secs_nanos() == (0, 0, 0, 0)
 
 
+@pytest.mark.parametrize(
+    "d, expected",
+    [
+        (TimeDelta(hours=2, minutes=30), (1, 2, 30, 0, 0)),
+        (TimeDelta(hours=-2, minutes=-30), (-1, 2, 30, 0, 0)),
+        (TimeDelta(hours=-1, minutes=30), (-1, 0, 30, 0, 0)),
+        (TimeDelta(seconds=-1, nanoseconds=1), (-1, 0, 0, 0, 999_999_999)),
+        (TimeDelta(nanoseconds=-4), (-1, 0, 0, 0, 4)),
+        (TimeDelta(hours=400, nanoseconds=1), (1, 400, 0, 0, 1)),
+        (TimeDelta.ZERO, (0, 0, 0, 0, 0)),
+    ],
+)
+def test_as_tuple(d, expected):
+    t = d.as_tuple()
+    assert t == expected
+    assert all(type(x) is int for x in t)
+
+
+def test_as_tuple_extremes():
+    sign, hrs, mins, secs, nanos = TimeDelta.MAX.as_tuple()
+    assert sign == 1
+    assert TimeDelta(
+        hours=hrs, minutes=mins, seconds=secs, nanoseconds=nanos
+    ) == TimeDelta.MAX
+    assert TimeDelta.MIN.as_tuple() == (-1, hrs, mins, secs, nanos)
+
+
+class TestIsClose:
+    def test_within_tolerance(self):
+        d = TimeDelta(seconds=1, nanoseconds=400)
+        assert d.isclose(seconds(1), abs_tol=microseconds(1))
+        assert seconds(1).isclose(d, abs_tol=microseconds(1))
+        assert d.isclose(d, abs_tol=TimeDelta.ZERO)
+        # the tolerance is inclusive
+        assert d.isclose(seconds(1), abs_tol=nanoseconds(400))
+        assert (-d).isclose(-seconds(1), abs_tol=nanoseconds(400))
+
+    def test_outside_tolerance(self):
+        d = TimeDelta(seconds=1, nanoseconds=400)
+        assert not d.isclose(seconds(1), abs_tol=nanoseconds(399))
+        assert not d.isclose(-d, abs_tol=seconds(2))
+        assert not TimeDelta.MAX.isclose(TimeDelta.MIN, abs_tol=hours(1))
+
+    def test_invalid(self):
+        d = seconds(1)
+        with pytest.raises(ValueError, match="abs_tol"):
+            d.isclose(d, abs_tol=-seconds(1))
+
+        with pytest.raises(TypeError):
+            d.isclose(d)  # type: ignore[call-arg]
+
+        with pytest.raises(TypeError):
+            d.isclose(d, seconds(1))  # type: ignore[misc]
+
+        with pytest.raises(TypeError):
+            d.isclose(1, abs_tol=d)  # type: ignore[arg-type]
+
+        with pytest.raises(TypeError):
+            d.isclose(d, abs_tol=1)  # type: ignore[arg-type]
+
+        with pytest.raises(TypeError):
+            d.isclose(d, abs_tol=d, foo=1)  # type: ignore[call-arg]
+
+
+class TestClamp:
+    def test_within_bounds(self):
+        d = minutes(30)
+        assert d.clamp(TimeDelta.ZERO, hours(1)) is d
+        # the bounds are inclusive
+        assert d.clamp(d, d) is d
+        assert d.clamp(minutes(30), hours(1)) is d
+
+    def test_outside_bounds(self):
+        lower = seconds(1)
+        upper = hours(1)
+        assert hours(3).clamp(lower, upper) is upper
+        assert seconds(-5).clamp(lower, upper) is lower
+        assert TimeDelta.MAX.clamp(lower, upper) == upper
+        assert TimeDelta.MIN.clamp(lower, upper) == lower
+        assert (
+            nanoseconds(1).clamp(-hours(1), TimeDelta.ZERO) == TimeDelta.ZERO
+        )
+
+    def test_invalid(self):
+        d = seconds(1)
+        with pytest.raises(ValueError, match="lower.*upper"):
+            d.clamp(hours(1), TimeDelta.ZERO)
+
+        with pytest.raises(TypeError):
+            d.clamp(d)  # type: ignore[call-arg]
+
+        with pytest.raises(TypeError):
+            d.clamp(d, d, d)  # type: ignore[call-arg]
+
+        with pytest.raises(TypeError):
+            d.clamp(d, 1)  # type: ignore[arg-type]
+
+        with pytest.raises(TypeError):
+            d.clamp(lower=d, upper=d)  # type: ignore[call-arg]
+
+
 def test_abs():
     assert abs(TimeDelta()) == TimeDelta()
     assert abs(
@@ -76,9 +76,13 @@ class TestInit:
         with pytest.raises(
             RepeatedTime,
             match="2023-10-29 02:15:30 is repeated in timezone 'Europe/Amsterdam'",
-        ):
+        ) as exc_info:
             ZonedDateTime(**kwargs, disambiguate="raise")
 
+        assert exc_info.value.date == Date(2023, 10, 29)
+        assert exc_info.value.time == Time(2, 15, 30)
+        assert exc_info.value.tz == "Europe/Amsterdam"
+
         assert (
             ZonedDateTime(**kwargs, disambiguate="earlier").offset
             > ZonedDateTime(**kwargs, disambiguate="later").offset
@@ -159,9 +163,13 @@ class TestInit:
         with pytest.raises(
             SkippedTime,
             match="2023-03-26 02:15:30 is skipped in timezone 'Europe/Amsterdam'",
-        ):
+        ) as exc_info:
             ZonedDateTime(**kwargs, disambiguate="raise")
 
+        assert exc_info.value.date == Date(2023, 3, 26)
+        assert exc_info.value.time == Time(2, 15, 30)
+        assert exc_info.value.tz == "Europe/Amsterdam"
+
         d1 = ZonedDateTime(**kwargs, disambiguate="compatible")
         assert d1.exact_eq(
             ZonedDateTime(2023, 3, 26, 3, 15, 30, tz="Europe/Amsterdam")
@@ -174,6 +182,41 @@ class TestInit:
             ZonedDateTime(2023, 3, 26, 1, 15, 30, tz="Europe/Amsterdam")
         )
 
+    @pytest.mark.parametrize(
+        "local",
+        [
+            LocalDateTime(2023, 3, 26, 2, 15, 30),  # gap
+            LocalDateTime(2023, 10, 29, 2, 15, 30),  # fold
+            LocalDateTime(2023, 7, 1, 12, nanosecond=9),  # unambiguous
+        ],
+    )
+    @pytest.mark.parametrize(
+        "disambiguate", ["compatible", "earlier", "later", "raise"]
+    )
+    def test_same_as_assume_tz(self, local, disambiguate):
+        tz = "Europe/Amsterdam"
+
+        def construct() -> ZonedDateTime:
+            return ZonedDateTime(
+                local.year,
+                local.month,
+                local.day,
+                local.hour,
+                local.minute,
+                local.second,
+                nanosecond=local.nanosecond,
+                tz=tz,
+                disambiguate=disambiguate,
+            )
+
+        try:
+            expected = local.assume_tz(tz, disambiguate=disambiguate)
+        except (SkippedTime, RepeatedTime) as e:
+            with pytest.raises(type(e), match=re.escape(str(e))):
+                construct()
+        else:
+            assert construct().exact_eq(expected)
+
 
 def test_offset():
     d = ZonedDateTime(
@@ -182,6 +225,26 @@ def test_offset():
     assert d.offset == hours(-4)
 
 
+@pytest.mark.parametrize(
+    "d, expected",
+    [
+        (ZonedDateTime(2020, 8, 15, tz="Asia/Kolkata"), hours(5.5)),
+        (ZonedDateTime(2020, 8, 15, tz="America/St_Johns"), -hours(2.5)),
+        (ZonedDateTime(2020, 1, 15, tz="America/St_Johns"), -hours(3.5)),
+        (ZonedDateTime(2020, 8, 15, tz="UTC"), hours(0)),
+        # historical offsets with seconds precision
+        (
+            ZonedDateTime(1900, 1, 1, tz="Europe/Amsterdam"),
+            TimeDelta(minutes=19, seconds=32),
+        ),
+    ],
+)
+def test_offset_sign_and_precision(d, expected):
+    assert d.offset == expected
+    assert isinstance(d.offset, TimeDelta)
+    assert d.to_fixed_offset().offset == expected
+
+
 def test_immutable():
     d = ZonedDateTime(2020, 8, 15, tz="Europe/Amsterdam")
     with pytest.raises(AttributeError):
@@ -205,6 +268,16 @@ def test_local():
         2020, 8, 15, 13
     )
 
+    # both sides of a fold have the same local time
+    fold = ZonedDateTime(
+        2023, 10, 29, 2, 30, tz="Europe/Amsterdam", disambiguate="earlier"
+    )
+    assert (
+        fold.local()
+        == fold.replace(disambiguate="later").local()
+        == LocalDateTime(2023, 10, 29, 2, 30)
+    )
+
 
 class TestReplaceDate:
     def test_unambiguous(self):
@@ -512,6 +585,17 @@ class TestEquality:
         assert hash(a) == hash(b)
         assert a == b
 
+    def test_dict_keys(self):
+        a = ZonedDateTime(
+            2023, 10, 29, 2, 15, tz="Europe/Amsterdam", disambiguate="earlier"
+        )
+        b = a.replace(disambiguate="later")
+        c = a.to_tz("America/New_York")
+        d = b.to_tz("Asia/Tokyo")
+        # the zone isn't part of the key, only the moment in time
+        assert {a: 1, b: 2, c: 3, d: 4} == {a: 3, b: 4}
+        assert set([a, b, c, d]) == {a, b}
+
     @system_tz_nyc()
     def test_other_aware(self):
         d: ZonedDateTime | OffsetDateTime | SystemDateTime = ZonedDateTime(
@@ -759,6 +843,59 @@ def test_start_of_day(d, expect):
         assert d_system.start_of_day() == expect_system
 
 
+class TestStartOfDayDisambiguate:
+
+    def test_skipped_midnight(self):
+        d = ZonedDateTime(2018, 11, 4, 12, tz="America/Sao_Paulo")
+        assert d.start_of_day().exact_eq(
+            ZonedDateTime(2018, 11, 4, 1, tz="America/Sao_Paulo")
+        )
+        assert d.start_of_day(disambiguate="compatible").exact_eq(
+            d.start_of_day()
+        )
+        assert d.start_of_day(disambiguate="later").exact_eq(
+            ZonedDateTime(2018, 11, 4, 1, tz="America/Sao_Paulo")
+        )
+        assert d.start_of_day(disambiguate="earlier").exact_eq(
+            ZonedDateTime(2018, 11, 3, 23, tz="America/Sao_Paulo")
+        )
+        with pytest.raises(SkippedTime, match="00:00:00"):
+            d.start_of_day(disambiguate="raise")
+
+    def test_repeated_midnight(self):
+        d = ZonedDateTime(2023, 11, 5, 12, tz="America/Havana")
+        assert d.start_of_day().exact_eq(
+            ZonedDateTime(
+                2023, 11, 5, tz="America/Havana", disambiguate="earlier"
+            )
+        )
+        assert d.start_of_day(disambiguate="later").exact_eq(
+            ZonedDateTime(
+                2023, 11, 5, tz="America/Havana", disambiguate="later"
+            )
+        )
+        with pytest.raises(RepeatedTime):
+            d.start_of_day(disambiguate="raise")
+
+    def test_unambiguous(self):
+        d = ZonedDateTime(2020, 8, 15, 23, 12, tz="Europe/Amsterdam")
+        expect = ZonedDateTime(2020, 8, 15, tz="Europe/Amsterdam")
+        assert d.start_of_day(disambiguate="earlier").exact_eq(expect)
+        assert d.start_of_day(disambiguate="later").exact_eq(expect)
+        assert d.start_of_day(disambiguate="raise").exact_eq(expect)
+
+    def test_invalid(self):
+        d = ZonedDateTime(2020, 8, 15, 23, 12, tz="Europe/Amsterdam")
+        with pytest.raises(ValueError, match="disambiguate"):
+            d.start_of_day(disambiguate="foo")  # type: ignore[arg-type]
+
+        with pytest.raises(TypeError):
+            d.start_of_day(foo="raise")  # type: ignore[call-arg]
+
+        with pytest.raises(TypeError):
+            d.start_of_day("raise")  # type: ignore[misc]
+
+
 def test_instant():
     assert (
         ZonedDateTime(2020, 8, 15, 12, 8, 30, tz="Europe/Amsterdam")
@@ -792,6 +929,24 @@ def test_instant():
     )
 
 
+def test_to_instant():
+    d = ZonedDateTime(
+        2023,
+        10,
+        29,
+        2,
+        15,
+        30,
+        nanosecond=1,
+        tz="Europe/Amsterdam",
+        disambiguate="later",
+    )
+    assert d.to_instant().exact_eq(
+        Instant.from_utc(2023, 10, 29, 1, 15, 30, nanosecond=1)
+    )
+    assert d.to_instant().exact_eq(d.instant())
+
+
 def test_to_tz():
     assert (
         ZonedDateTime(2020, 8, 15, 12, 8, 30, tz="Europe/Amsterdam")
@@ -831,6 +986,29 @@ def test_to_tz():
         big_zdt.to_tz("Asia/Tokyo")
 
 
+def test_in_utc():
+    d = ZonedDateTime(
+        2020, 8, 15, 12, 8, 30, nanosecond=1, tz="Europe/Amsterdam"
+    )
+    assert d.in_utc().exact_eq(
+        OffsetDateTime(2020, 8, 15, 10, 8, 30, nanosecond=1, offset=0)
+    )
+    assert d.in_utc().exact_eq(d.to_fixed_offset(0))
+
+    # both sides of a fold map to different UTC times
+    fold = ZonedDateTime(
+        2023, 10, 29, 2, 30, tz="Europe/Amsterdam", disambiguate="earlier"
+    )
+    assert fold.in_utc().exact_eq(
+        OffsetDateTime(2023, 10, 29, 0, 30, offset=0)
+    )
+    assert (
+        fold.replace(disambiguate="later")
+        .in_utc()
+        .exact_eq(OffsetDateTime(2023, 10, 29, 1, 30, offset=0))
+    )
+
+
 def test_to_fixed_offset():
     d = ZonedDateTime(2020, 8, 15, 12, 8, 30, tz="Europe/Amsterdam")
 
@@ -854,6 +1032,19 @@ def test_to_fixed_offset():
         OffsetDateTime(2020, 8, 15, 6, 8, 30, offset=hours(-4))
     )
 
+    # the offset at this exact moment is captured, also in a fold
+    fold = ZonedDateTime(
+        2023, 10, 29, 2, 30, tz="Europe/Amsterdam", disambiguate="earlier"
+    )
+    assert fold.to_fixed_offset().exact_eq(
+        OffsetDateTime(2023, 10, 29, 2, 30, offset=2)
+    )
+    assert (
+        fold.replace(disambiguate="later")
+        .to_fixed_offset()
+        .exact_eq(OffsetDateTime(2023, 10, 29, 2, 30, offset=1))
+    )
+
     # catch local datetimes sliding out of range
     small_zdt = ZonedDateTime(1, 1, 1, tz="Etc/UTC")
     with pytest.raises((ValueError, OverflowError), match="range|year"):
@@ -1048,6 +1239,33 @@ class TestParseCommonIso:
             ZonedDateTime.parse_common_iso(
                 "2020-08-15T12:08:30+01:00:01[Europe/Amsterdam]"
             )
+        with pytest.raises(
+            InvalidOffset, match="Invalid offset for timezone Europe/Amsterdam"
+        ):
+            # skipped time, with the offset from before the transition
+            ZonedDateTime.parse_common_iso(
+                "2023-03-26T02:15:30+01:00[Europe/Amsterdam]"
+            )
+        with pytest.raises(
+            InvalidOffset, match="Invalid offset for timezone Europe/Amsterdam"
+        ):
+            # skipped time, with the offset from after the transition
+            ZonedDateTime.parse_common_iso(
+                "2023-03-26T02:15:30+02:00[Europe/Amsterdam]"
+            )
+
+    @pytest.mark.parametrize(
+        "s",
+        [
+            "2023-10-29T02:15:30+02:00[Europe/Amsterdam]",
+            "2023-10-29T02:15:30+01:00[Europe/Amsterdam]",
+            "2023-03-26T01:59:59.999999999+01:00[Europe/Amsterdam]",
+            "2023-03-26T03:00:00+02:00[Europe/Amsterdam]",
+            "2020-08-15T12:08:30.000001+12:45[Pacific/Chatham]",
+        ],
+    )
+    def test_roundtrip(self, s):
+        assert ZonedDateTime.parse_common_iso(s).format_common_iso() == s
 
     @given(text())
     def test_fuzzing(self, s: str):
@@ -1202,6 +1420,38 @@ class TestFromTimestamp:
             -4, tz="America/Nuuk"
         ).instant() == Instant.from_timestamp(0).subtract(nanoseconds=4)
 
+    def test_fold(self):
+        # Both timestamps land on 02:15 local time, but with different offsets
+        ts = Instant.from_utc(2023, 10, 29, 0, 15).timestamp()
+        earlier = ZonedDateTime.from_timestamp(ts, tz="Europe/Amsterdam")
+        later = ZonedDateTime.from_timestamp(
+            ts + 3_600, tz="Europe/Amsterdam"
+        )
+        assert earlier.exact_eq(
+            ZonedDateTime(
+                2023,
+                10,
+                29,
+                2,
+                15,
+                tz="Europe/Amsterdam",
+                disambiguate="earlier",
+            )
+        )
+        assert later.exact_eq(
+            ZonedDateTime(
+                2023,
+                10,
+                29,
+                2,
+                15,
+                tz="Europe/Amsterdam",
+                disambiguate="later",
+            )
+        )
+        assert earlier.offset == hours(2)
+        assert later.offset == hours(1)
+
     def test_nanos(self):
         assert ZonedDateTime.from_timestamp_nanos(
             1_597_493_310_123_456_789, tz="America/Nuuk"
@@ -1303,6 +1553,21 @@ def test_repr():
     )
 
 
+def test_repr_shows_resolved_offset_in_fold():
+    earlier = ZonedDateTime(
+        2023, 11, 5, 1, 30, tz="America/New_York", disambiguate="earlier"
+    )
+    later = earlier.replace(disambiguate="later")
+    assert (
+        repr(earlier)
+        == "ZonedDateTime(2023-11-05 01:30:00-04:00[America/New_York])"
+    )
+    assert (
+        repr(later)
+        == "ZonedDateTime(2023-11-05 01:30:00-05:00[America/New_York])"
+    )
+
+
 class TestComparison:
     def test_different_timezones(self):
         d = ZonedDateTime(2020, 8, 15, 15, 12, 9, tz="Asia/Kolkata")
@@ -1623,6 +1888,15 @@ class TestExactEquality:
         assert a == b
         assert not a.exact_eq(b)
 
+    def test_different_zones_same_offset(self):
+        a = ZonedDateTime(2020, 8, 15, 12, 43, tz="Europe/Amsterdam")
+        b = a.to_tz("Europe/Paris")
+        # same wall clock, offset, and moment -- but not the same zone
+        assert a.local() == b.local()
+        assert a.offset == b.offset
+        assert a == b
+        assert not a.exact_eq(b)
+
     def test_same_timezone_ambiguity(self):
         a = ZonedDateTime(
             2023,
@@ -1637,6 +1911,9 @@ class TestExactEquality:
         b = a.replace(disambiguate="later")
         assert a != b
         assert not a.exact_eq(b)
+        # only the offset tells them apart
+        assert a.local() == b.local()
+        assert a.offset != b.offset
 
     def test_same_ambiguous(self):
         a = ZonedDateTime(
@@ -2076,6 +2353,17 @@ class TestShiftTimeUnits:
             .exact_eq(d.replace(disambiguate="later") + hours(24))
         )
 
+    def test_subtract_across_gap(self):
+        d = ZonedDateTime(2023, 3, 26, 3, 30, tz="Europe/Amsterdam")
+        shifted = d - hours(1)
+        # 02:30 doesn't exist, so the clock jumps back to 01:30
+        assert shifted.exact_eq(
+            ZonedDateTime(2023, 3, 26, 1, 30, tz="Europe/Amsterdam")
+        )
+        # the difference is in exact time, not wall clock time
+        assert d - shifted == hours(1)
+        assert shifted - d == hours(-1)
+
     @system_tz_ams()
     def test_out_of_range(self):
         d = ZonedDateTime(2020, 8, 15, tz="Africa/Abidjan")
@@ -2239,6 +2527,22 @@ class TestShiftDateUnits:
             d.replace(day=28, disambiguate="raise")
         )
 
+    def test_days_vs_hours(self):
+        # Calendar units keep the wall clock, time units are exact.
+        # No `ignore_dst` flag is needed to choose between the two.
+        d = ZonedDateTime(2023, 3, 25, 12, tz="Europe/Amsterdam")
+        assert d.add(days=1).exact_eq(d.replace(day=26))
+        assert d.add(hours=24).exact_eq(d.replace(day=26, hour=13))
+        assert d.add(days=1) - d == hours(23)
+        assert d.add(hours=24) - d == hours(24)
+
+        later = d.replace(day=26)
+        assert later.subtract(days=1).exact_eq(d)
+        assert later.subtract(hours=24).exact_eq(d.replace(hour=11))
+
+        with pytest.raises(TypeError):
+            d.add(days=1, ignore_dst=True)  # type: ignore[call-overload]
+
     def test_out_of_bounds_min(self):
         d = ZonedDateTime(2000, 1, 1, tz="Europe/Amsterdam")
         with pytest.raises((ValueError, OverflowError), match="range|year"):
@@ -2573,6 +2877,119 @@ class TestRound:
         with pytest.raises((ValueError, OverflowError), match="range"):
             d.round("hour", increment=4)
 
+    def test_disambiguate_gap(self):
+        # rounding happens on the local time, which then lands in the gap
+        d = ZonedDateTime(2023, 3, 26, 1, 50, tz="Europe/Amsterdam")
+        assert d.round("hour", disambiguate="compatible").exact_eq(
+            ZonedDateTime(2023, 3, 26, 3, tz="Europe/Amsterdam")
+        )
+        assert d.round("hour", disambiguate="earlier").exact_eq(
+            ZonedDateTime(2023, 3, 26, 1, tz="Europe/Amsterdam")
+        )
+        assert d.round("hour", disambiguate="later").exact_eq(
+            ZonedDateTime(2023, 3, 26, 3, tz="Europe/Amsterdam")
+        )
+        with pytest.raises(SkippedTime):
+            d.round("hour", disambiguate="raise")
+
+        # no effect if the result isn't in a gap or fold
+        assert d.round("hour", mode="floor", disambiguate="raise").exact_eq(
+            ZonedDateTime(2023, 3, 26, 1, tz="Europe/Amsterdam")
+        )
+
+    def test_disambiguate_fold(self):
+        d = ZonedDateTime(
+            2023,
+            10,
+            29,
+            2,
+            50,
+            tz="Europe/Amsterdam",
+            disambiguate="later",
+        )
+        # by default, the offset is preserved
+        assert d.round("hour").exact_eq(
+            ZonedDateTime(
+                2023, 10, 29, 3, tz="Europe/Amsterdam", disambiguate="later"
+            )
+        )
+        assert d.round("hour", mode="floor").exact_eq(
+            ZonedDateTime(
+                2023, 10, 29, 2, tz="Europe/Amsterdam", disambiguate="later"
+            )
+        )
+        assert d.round("hour", mode="floor", disambiguate="earlier").exact_eq(
+            ZonedDateTime(
+                2023, 10, 29, 2, tz="Europe/Amsterdam", disambiguate="earlier"
+            )
+        )
+        with pytest.raises(RepeatedTime):
+            d.round("hour", mode="floor", disambiguate="raise")
+
+    def test_disambiguate_day(self):
+        # Havana skips midnight on this day
+        d = ZonedDateTime(2023, 3, 12, 13, tz="America/Havana")
+        assert d.round("day", mode="floor", disambiguate="later").exact_eq(
+            ZonedDateTime(2023, 3, 12, 1, tz="America/Havana")
+        )
+        assert d.round("day", mode="floor", disambiguate="earlier").exact_eq(
+            ZonedDateTime(2023, 3, 11, 23, tz="America/Havana")
+        )
+        with pytest.raises(SkippedTime):
+            d.round("day", mode="floor", disambiguate="raise")
+
+    def test_disambiguate_day_only_applies_to_result(self):
+        # Havana skips midnight on 2024-03-10, making the day 23 hours long
+        d = ZonedDateTime(2024, 3, 9, 10, tz="America/Havana")
+        assert d.round("day", disambiguate="raise").exact_eq(
+            ZonedDateTime(2024, 3, 9, tz="America/Havana")
+        )
+        # the day length doesn't depend on the disambiguation
+        d = ZonedDateTime(2024, 3, 10, 12, tz="America/Havana")
+        assert d.round("day", disambiguate="earlier").exact_eq(
+            ZonedDateTime(2024, 3, 11, tz="America/Havana")
+        )
+        d = ZonedDateTime(2024, 3, 10, 11, tz="America/Havana")
+        assert d.round("day", disambiguate="earlier").exact_eq(
+            ZonedDateTime(2024, 3, 9, 23, tz="America/Havana")
+        )
+        with pytest.raises(SkippedTime):
+            d.round("day", disambiguate="raise")
+
+    def test_day_ambiguous_midnight_keeps_offset(self):
+        # Havana repeats the hour after midnight on 2023-11-05.
+        # Without disambiguate, the offset is kept if possible,
+        # like for the other units.
+        d = ZonedDateTime(2023, 11, 5, 10, tz="America/Havana")
+        assert d.round("day", mode="floor").exact_eq(
+            ZonedDateTime(
+                2023, 11, 5, tz="America/Havana", disambiguate="later"
+            )
+        )
+        assert d.round("day", mode="floor", disambiguate="earlier").exact_eq(
+            ZonedDateTime(
+                2023, 11, 5, tz="America/Havana", disambiguate="earlier"
+            )
+        )
+        # the offset before the fold is kept too
+        d = ZonedDateTime(2023, 11, 4, 20, tz="America/Havana")
+        assert d.round("day", mode="ceil").exact_eq(
+            ZonedDateTime(
+                2023, 11, 5, tz="America/Havana", disambiguate="earlier"
+            )
+        )
+
+    def test_invalid_disambiguate(self):
+        d = ZonedDateTime(2023, 7, 14, 1, 2, 3, tz="Europe/Paris")
+        with pytest.raises(ValueError, match="disambiguate"):
+            d.round("hour", disambiguate="foo")  # type: ignore[arg-type]
+
+        with pytest.raises((TypeError, ValueError), match="disambiguate"):
+            d.round("hour", disambiguate=1)  # type: ignore[arg-type]
+
+        with pytest.raises(TypeError):
+            d.round("hour", foo="earlier")  # type: ignore[call-arg]
+
 
 def test_pickle():
     d = ZonedDateTime(
@@ -2583,6 +3000,28 @@ def test_pickle():
     assert pickle.loads(pickle.dumps(d)).exact_eq(d)
 
 
+@pytest.mark.parametrize(
+    "d",
+    [
+        # fold
+        ZonedDateTime(
+            2023, 10, 29, 2, 15, tz="Europe/Amsterdam", disambiguate="earlier"
+        ),
+        ZonedDateTime(
+            2023, 10, 29, 2, 15, tz="Europe/Amsterdam", disambiguate="later"
+        ),
+        # around a gap
+        ZonedDateTime(
+            2023, 3, 26, 1, 59, 59, nanosecond=1, tz="Europe/Amsterdam"
+        ),
+        ZonedDateTime(2023, 3, 26, 3, tz="Europe/Amsterdam"),
+    ],
+)
+def test_pickle_around_transitions(d):
+    # the offset in the pickle determines the fold
+    assert pickle.loads(pickle.dumps(d)).exact_eq(d)
+
+
 def test_old_pickle_data_remains_unpicklable():
     # Don't update this value after 1.x release: the whole idea is that
     # it's a pickle at a specific version of the library,
@@ -2599,6 +3038,17 @@ def test_old_pickle_data_remains_unpicklable():
     )
 
 
+def test_unpickle_unknown_version():
+    d = ZonedDateTime(
+        2020, 8, 15, 23, 12, 9, nanosecond=987_654, tz="Europe/Amsterdam"
+    )
+    dumped = pickle.dumps(d)
+    payload = b"\x01\xe4\x07\x08\x0f\x17\x0c\t\x06\x12\x0f\x00 \x1c\x00\x00"
+    assert payload in dumped
+    with pytest.raises(ValueError, match="pickle version"):
+        pickle.loads(dumped.replace(payload, b"\x09" + payload[1:]))
+
+
 def test_copy():
     d = ZonedDateTime(
         2020, 8, 15, 23, 12, 9, nanosecond=987_654, tz="Europe/Amsterdam"